@@ -0,0 +1,142 @@
+// Copyright (c) FridayChain Arena Contributors
+// SPDX-License-Identifier: MIT
+
+//! Compact serde encodings for `PlayerGameState` boards.
+//!
+//! The GraphQL shape stays `Vec<Vec<_>>`; only the stored bytes change.
+//! Under BCS a nested 9×9 vector costs 91 bytes (a length prefix per row),
+//! so a board plus its given mask took 182 bytes. Stored as 81 flat cells
+//! plus an 11-byte bitset they take 94 bytes, a ~48% reduction per player.
+
+use serde::{de::Error, Deserializer, Serializer};
+
+const CELLS: usize = 81;
+const MASK_BYTES: usize = CELLS.div_ceil(8);
+
+/// A 9×9 board of values 0-9 stored as 81 bytes, row-major.
+pub mod board {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(board: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        let cells: Vec<u8> = board.iter().flatten().copied().collect();
+        serializer.serialize_bytes(&cells)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error> {
+        let cells: Vec<u8> = serde_bytes_compat::deserialize(deserializer)?;
+        if cells.len() != CELLS {
+            return Err(D::Error::invalid_length(cells.len(), &"81 cells"));
+        }
+        if cells.iter().any(|&v| v > 9) {
+            return Err(D::Error::custom("cell value out of range"));
+        }
+        Ok(cells.chunks(9).map(|row| row.to_vec()).collect())
+    }
+}
+
+/// A 9×9 boolean mask stored as an 11-byte bitset, row-major, LSB first.
+pub mod mask {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(mask: &[Vec<bool>], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bits = [0u8; MASK_BYTES];
+        for (i, &set) in mask.iter().flatten().enumerate() {
+            if set {
+                bits[i / 8] |= 1 << (i % 8);
+            }
+        }
+        serializer.serialize_bytes(&bits)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<bool>>, D::Error> {
+        let bits: Vec<u8> = serde_bytes_compat::deserialize(deserializer)?;
+        if bits.len() != MASK_BYTES {
+            return Err(D::Error::invalid_length(bits.len(), &"11 bytes"));
+        }
+        Ok((0..9)
+            .map(|r| (0..9).map(|c| bits[(r * 9 + c) / 8] & (1 << ((r * 9 + c) % 8)) != 0).collect())
+            .collect())
+    }
+}
+
+/// Accepts both byte strings (BCS, bincode) and integer sequences (JSON),
+/// matching whatever `serialize_bytes` produced.
+mod serde_bytes_compat {
+    use super::*;
+    use serde::de::{SeqAccess, Visitor};
+    use std::fmt;
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a byte string")
+        }
+
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(b) = seq.next_element()? {
+                bytes.push(b);
+            }
+            Ok(bytes)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PlayerGameState;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Nested {
+        board: Vec<Vec<u8>>,
+        given_mask: Vec<Vec<bool>>,
+    }
+
+    #[derive(Serialize)]
+    struct Compact {
+        #[serde(with = "super::board")]
+        board: Vec<Vec<u8>>,
+        #[serde(with = "super::mask")]
+        given_mask: Vec<Vec<bool>>,
+    }
+
+    #[test]
+    fn test_compact_round_trip_and_size() {
+        let mut puzzle = [[0u8; 9]; 9];
+        puzzle[0][0] = 5;
+        puzzle[8][8] = 9;
+        let mut game = PlayerGameState::new(&puzzle);
+        game.board[4][4] = 7;
+
+        let bytes = linera_sdk::bcs::to_bytes(&game).unwrap();
+        let decoded: PlayerGameState = linera_sdk::bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.board, game.board);
+        assert_eq!(decoded.given_mask, game.given_mask);
+
+        let json = linera_sdk::serde_json::to_string(&game).unwrap();
+        let decoded: PlayerGameState = linera_sdk::serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.board, game.board);
+        assert_eq!(decoded.given_mask, game.given_mask);
+
+        let nested = Nested { board: game.board.clone(), given_mask: game.given_mask.clone() };
+        let compact = Compact { board: game.board.clone(), given_mask: game.given_mask.clone() };
+        assert_eq!(linera_sdk::bcs::to_bytes(&nested).unwrap().len(), 182);
+        assert_eq!(linera_sdk::bcs::to_bytes(&compact).unwrap().len(), 94);
+    }
+}
@@ -4,12 +4,26 @@ mod state;
 
 use self::state::ArenaState;
 use fridaychain_arena::{
-    sudoku, ArenaEvent, ArenaParameters, ArenaResponse, BoardCompletedResponse,
-    CachedLeaderboard, CellClearedResponse, CellPlacedResponse, ErrorResponse,
-    FridayChainArenaAbi, InstantiationArgument, LeaderboardEntry, LeaderboardRequestedResponse,
-    Message, Operation, PlayerGameState, PlayerInfo, PlayerRegisteredResponse, SubscribedResponse,
-    Tournament, TournamentEndedResponse, TournamentStartedResponse, UsernameUpdatedResponse,
-    TOURNAMENT_STREAM,
+    check_min_players, check_seed_unused, completion_rank, is_admin_signer, is_authentic_sync,
+    is_first_completion, is_reset_confirmed, is_valid_admin, is_valid_date_ymd,
+    is_valid_points_table, player_count_after_sync, roll_back_username, seed_candidates, sudoku,
+    tournament_end_time, validate_announcement, validate_board_arg, validate_preferences,
+    validate_username, AdminTransferredResponse, AnnouncedResponse, ArenaEvent, ArenaParameters,
+    ArenaResetResponse, ArenaResponse, BoardRejectedResponse, BoardSize, CachedLeaderboard,
+    CellClearedResponse, CellPlacedResponse, CellPosition, CellsClearedResponse, CheckedInResponse,
+    ClearOutcome, DailyResultResponse, ErrorCode, ErrorResponse, FridayChainArenaAbi,
+    GameOverResponse, HeartbeatSentResponse, InstantiationArgument, LeaderboardEntry,
+    LeaderboardRequestedResponse, LogicHintResponse, Message, MoveInput, MoveRecord, Operation,
+    PlayerAllowedResponse, PlayerGameResetResponse, PlayerGameState, PlayerInfo,
+    PlayerRegisteredResponse, PreferencesUpdatedResponse, PuzzleLoadedResponse,
+    ResyncRequestedResponse, RewardClaimedResponse, ScoreAdjustment, Season, SeedStrategy,
+    StreamEvent, SubscribedResponse, SudokuBoard, SudokuVariant, SyncBatchSizeResponse, SyncedMove,
+    Tournament, TournamentEndedResponse, TournamentPausedResponse, TournamentResumedResponse,
+    TournamentScheduledResponse, TournamentStartedResponse, UnsubscribedResponse,
+    UsernameUpdatedResponse, WeeklySchedule, WeeklyScheduleResponse,
+    DEFAULT_MIN_MOVE_INTERVAL_MICROS, MAX_ADJUSTMENT_REASON_LEN, MAX_CLEAR_CELLS,
+    MAX_PLACEMENT_POINTS, MAX_PRIZE_PLACES, MAX_SYNC_BATCH, RESET_ARENA_CONFIRMATION,
+    SUSPICIOUS_PACE_SECS, TOURNAMENT_STREAM,
 };
 use linera_sdk::{
     linera_base_types::{AccountOwner, ChainId, StreamName, StreamUpdate, WithContractAbi},
@@ -32,12 +46,13 @@ impl Contract for FridayChainArenaContract {
     type Message = Message;
     type InstantiationArgument = InstantiationArgument;
     type Parameters = ArenaParameters;
-    type EventValue = ArenaEvent;
+    type EventValue = StreamEvent;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
-        let state = ArenaState::load(runtime.root_view_storage_context())
+        let mut state = ArenaState::load(runtime.root_view_storage_context())
             .await
             .expect("Failed to load state");
+        state.migrate().await;
         Self { state, runtime }
     }
 
@@ -53,9 +68,11 @@ impl Contract for FridayChainArenaContract {
     }
 
     async fn execute_operation(&mut self, operation: Operation) -> ArenaResponse {
+        self.maybe_auto_start().await;
+
         match operation {
-            Operation::RegisterPlayer { discord_username } => {
-                self.handle_register_player(discord_username).await
+            Operation::RegisterPlayer { discord_username, team } => {
+                self.handle_register_player(discord_username, team).await
             }
             Operation::UpdateUsername { new_discord_username } => {
                 self.handle_update_username(new_discord_username).await
@@ -64,41 +81,129 @@ impl Contract for FridayChainArenaContract {
                 self.handle_place_cell(row, col, value).await
             }
             Operation::ClearCell { row, col } => self.handle_clear_cell(row, col).await,
+            Operation::ClearCells { cells } => self.handle_clear_cells(cells).await,
+            Operation::SubmitBoard { board } => self.handle_submit_board(board).await,
+            Operation::CheckIn => self.handle_check_in().await,
+            Operation::Heartbeat => self.handle_heartbeat().await,
             Operation::SubscribeToHub => self.handle_subscribe_to_hub().await,
+            Operation::UnsubscribeFromHub => self.handle_unsubscribe_from_hub().await,
             Operation::RequestLeaderboard { limit } => {
                 self.handle_request_leaderboard(limit).await
             }
-            Operation::StartTournament { seed, duration_secs } => {
-                self.handle_start_tournament(seed, duration_secs).await
+            Operation::ResyncFromHub => self.handle_resync_from_hub().await,
+            Operation::LoadPuzzle => self.handle_load_puzzle().await,
+            Operation::ScheduleTournament { start_time_micros } => {
+                self.handle_schedule_tournament(start_time_micros).await
+            }
+            Operation::StartTournament {
+                seed, duration_secs, variant, restricted, scoring_mode, allow_bulk_submit,
+                free_mistakes, max_penalties, min_players, broadcast_top_n, box_bonus, board_size,
+                prizes, blind_mode,
+            } => {
+                self.handle_start_tournament(seed, duration_secs, min_players, Tournament {
+                    variant: variant.unwrap_or_default(),
+                    restricted: restricted.unwrap_or(false),
+                    scoring_mode: scoring_mode.unwrap_or_default(),
+                    allow_bulk_submit: allow_bulk_submit.unwrap_or(false),
+                    free_mistakes: free_mistakes.unwrap_or(0),
+                    max_penalties,
+                    broadcast_top_n,
+                    box_bonus_enabled: box_bonus.unwrap_or(false),
+                    board_size: board_size.unwrap_or_default(),
+                    prizes: prizes.unwrap_or_default(),
+                    blind_mode: blind_mode.unwrap_or(false),
+                    ..Tournament::default()
+                }).await
             }
+            Operation::StartTournamentAuto { duration_secs } => {
+                let seed = self.derive_seed();
+                self.handle_start_tournament(seed, duration_secs, None, Tournament::default()).await
+            }
+            Operation::AllowPlayer { wallet } => self.handle_allow_player(wallet).await,
             Operation::EndTournament => self.handle_end_tournament().await,
+            Operation::PauseTournament => self.handle_pause_tournament().await,
+            Operation::ResumeTournament => self.handle_resume_tournament().await,
+            Operation::EnableWeeklySchedule { weekday, hour_utc, duration_secs, seed_strategy } => {
+                self.handle_enable_weekly_schedule(WeeklySchedule {
+                    weekday, hour_utc, duration_secs, seed_strategy,
+                }).await
+            }
+            Operation::DisableWeeklySchedule => self.handle_disable_weekly_schedule().await,
+            Operation::ResetPlayerGame { wallet } => {
+                self.handle_reset_player_game(wallet).await
+            }
+            Operation::TransferAdmin { new_admin } => self.handle_transfer_admin(new_admin).await,
+            Operation::ResetArena { confirm } => self.handle_reset_arena(confirm).await,
+            Operation::SetPlayerScore { wallet, score, reason } => {
+                self.handle_set_player_score(wallet, score, reason).await
+            }
+            Operation::StartSeason { placement_points } => {
+                self.handle_start_season(placement_points).await
+            }
+            Operation::EndSeason => self.handle_end_season().await,
+            Operation::ValidatePuzzleString { puzzle_string, variant } => {
+                self.handle_validate_puzzle_string(puzzle_string, variant.unwrap_or_default())
+            }
+            Operation::SubmitDailyResult { date_ymd, solve_time_micros } => {
+                self.handle_submit_daily_result(date_ymd, solve_time_micros).await
+            }
+            Operation::RequestLogicHint { reveal_value } => {
+                self.handle_request_logic_hint(reveal_value).await
+            }
+            Operation::SetPreferences { preferences } => {
+                self.handle_set_preferences(preferences).await
+            }
+            Operation::ClaimReward { tournament_id } => {
+                self.handle_claim_reward(tournament_id).await
+            }
+            Operation::Announce { text } => self.handle_announce(text).await,
+            Operation::SetSyncBatchSize { moves } => self.handle_set_sync_batch_size(moves),
         }
     }
 
     async fn process_streams(&mut self, updates: Vec<StreamUpdate>) {
         for update in updates {
             for index in update.new_indices() {
-                let event = self.runtime.read_event(
+                let envelope = self.runtime.read_event(
                     update.chain_id,
                     StreamName(TOURNAMENT_STREAM.to_vec()),
                     index,
                 );
+                // Emitted by a newer contract. The Hub stays authoritative,
+                // and `ResyncFromHub` catches up on whatever this missed.
+                let Some(event) = envelope.decode() else {
+                    let skipped = *self.state.skipped_events.get() + 1;
+                    self.state.skipped_events.set(skipped);
+                    continue;
+                };
+                // Leaderboard snapshots are frequent and superseded by the
+                // next one; `cached_leaderboard` keeps the latest instead.
+                if !matches!(event, ArenaEvent::LeaderboardUpdated { .. }) {
+                    self.state.push_notification(event.clone());
+                }
                 match event {
                     ArenaEvent::TournamentStarted {
-                        tournament_id, seed, start_time_micros, end_time_micros,
+                        tournament_id, seed, variant, start_time_micros, end_time_micros,
+                        restricted, scoring_mode, allow_bulk_submit, free_mistakes, max_penalties,
+                        box_bonus_enabled, board_size, prizes, blind_mode, solution_commitment,
+                        unique_solution, ..
                     } => {
-                        self.handle_tournament_started_msg(
-                            tournament_id, seed, start_time_micros, end_time_micros,
-                        ).await;
+                        self.handle_tournament_started_msg(Tournament {
+                            id: tournament_id, seed, variant,
+                            start_time_micros, end_time_micros, restricted, scoring_mode,
+                            allow_bulk_submit, free_mistakes, max_penalties, box_bonus_enabled,
+                            board_size, prizes, blind_mode, solution_commitment, unique_solution,
+                            ..Tournament::default()
+                        }).await;
                     }
                     ArenaEvent::TournamentEnded {
-                        tournament_id, final_rankings,
+                        tournament_id, final_rankings, ..
                     } => {
                         self.handle_tournament_ended_msg(
                             tournament_id, final_rankings,
                         ).await;
                     }
-                    ArenaEvent::LeaderboardUpdated { entries } => {
+                    ArenaEvent::LeaderboardUpdated { entries, .. } => {
                         let tournament_id = self.state.active_tournament.get()
                             .as_ref().map(|t| t.id).unwrap_or(0);
                         let is_active = self.state.active_tournament.get()
@@ -108,8 +213,35 @@ impl Contract for FridayChainArenaContract {
                             entries, tournament_id, is_active, fetched_at_micros: now,
                         }));
                     }
-                    ArenaEvent::PlayerRegistered { .. } => {
-                        // Player registration events are informational; no action needed.
+                    ArenaEvent::PlayerRegistered { .. }
+                    | ArenaEvent::PlayerCompleted { .. }
+                    | ArenaEvent::AdminChanged { .. } => {
+                        // Informational events for overlays; no action needed.
+                    }
+                    ArenaEvent::TournamentScheduled { start_time_micros, .. } => {
+                        self.state.scheduled_start_micros.set(Some(start_time_micros));
+                    }
+                    ArenaEvent::PlayerAllowed { wallet, .. } => {
+                        self.state.allowed.insert(&wallet, ())
+                            .expect("Failed to mirror allowlist");
+                    }
+                    ArenaEvent::TournamentPaused { tournament_id, paused_at_micros, .. } => {
+                        self.mirror_clock(tournament_id, |t| t.pause(paused_at_micros));
+                    }
+                    ArenaEvent::TournamentResumed {
+                        tournament_id, paused_micros, end_time_micros, ..
+                    } => {
+                        self.mirror_clock(tournament_id, |t| {
+                            t.paused_since_micros = None;
+                            t.paused_micros = paused_micros;
+                            t.end_time_micros = end_time_micros;
+                        });
+                    }
+                    ArenaEvent::ArenaReset { .. } => {
+                        self.state.reset();
+                    }
+                    ArenaEvent::Announcement { text, .. } => {
+                        self.state.latest_announcement.set(Some(text));
                     }
                 }
             }
@@ -117,15 +249,57 @@ impl Contract for FridayChainArenaContract {
     }
 
     async fn execute_message(&mut self, message: Message) {
+        self.maybe_auto_start().await;
+
         match message {
             Message::SyncPlayer(player_info) => {
-                self.handle_sync_player(player_info).await;
+                if self.authenticate_sync(player_info.wallet).await {
+                    self.handle_sync_player(player_info).await;
+                }
+            }
+            Message::SyncCellPlacement {
+                wallet, row, col, value, timestamp_micros, penalty_count, cells_remaining,
+            } => {
+                if self.authenticate_sync(wallet).await {
+                    self.handle_sync_cell_placement(
+                        wallet, row, col, value, timestamp_micros, penalty_count, cells_remaining,
+                    ).await;
+                }
+            }
+            Message::SyncCellBatch { wallet, moves, cells_remaining } => {
+                if self.authenticate_sync(wallet).await {
+                    self.handle_sync_cell_batch(wallet, moves, cells_remaining).await;
+                }
+            }
+            Message::SyncBoardComplete {
+                wallet, completion_time_micros, penalty_count, move_count, final_board,
+            } => {
+                if self.authenticate_sync(wallet).await {
+                    self.handle_sync_board_complete(
+                        wallet, completion_time_micros, penalty_count, move_count, final_board,
+                    ).await;
+                }
+            }
+            Message::SyncGameOver { wallet, failed_at_micros, penalty_count, move_count } => {
+                if self.authenticate_sync(wallet).await {
+                    self.handle_sync_game_over(wallet, failed_at_micros, penalty_count, move_count).await;
+                }
+            }
+            Message::SyncCheckIn { wallet } => {
+                if self.authenticate_sync(wallet).await {
+                    self.handle_sync_check_in(wallet).await;
+                }
+            }
+            Message::Heartbeat { wallet, timestamp_micros } => {
+                if self.authenticate_sync(wallet).await {
+                    self.record_heartbeat(wallet, timestamp_micros);
+                }
             }
-            Message::SyncCellPlacement { wallet, row, col, value, timestamp_micros, penalty_count } => {
-                self.handle_sync_cell_placement(wallet, row, col, value, timestamp_micros, penalty_count).await;
+            Message::UsernameRejected { wallet, rejected_username, current_username } => {
+                self.handle_username_rejected(wallet, rejected_username, current_username).await;
             }
-            Message::SyncBoardComplete { wallet, completion_time_micros, penalty_count, move_count } => {
-                self.handle_sync_board_complete(wallet, completion_time_micros, penalty_count, move_count).await;
+            Message::ResetGame { wallet } => {
+                self.handle_reset_game(wallet).await;
             }
             Message::LeaderboardRequest { requester_chain, limit } => {
                 self.handle_leaderboard_request(requester_chain, limit).await;
@@ -133,8 +307,24 @@ impl Contract for FridayChainArenaContract {
             Message::LeaderboardResponse { entries, tournament_id, is_active } => {
                 self.handle_leaderboard_response(entries, tournament_id, is_active).await;
             }
-            Message::TournamentStarted { tournament_id, seed, start_time_micros, end_time_micros } => {
-                self.handle_tournament_started_msg(tournament_id, seed, start_time_micros, end_time_micros).await;
+            Message::FullStateRequest { requester_chain } => {
+                self.handle_full_state_request(requester_chain).await;
+            }
+            Message::FullStateResponse { tournament, entries } => {
+                self.handle_full_state_response(tournament, entries).await;
+            }
+            Message::TournamentStarted {
+                tournament_id, seed, variant, start_time_micros, end_time_micros, restricted,
+                scoring_mode, allow_bulk_submit, free_mistakes, max_penalties, box_bonus_enabled,
+                board_size, prizes, blind_mode, solution_commitment, unique_solution,
+            } => {
+                self.handle_tournament_started_msg(Tournament {
+                    id: tournament_id, seed, variant,
+                    start_time_micros, end_time_micros, restricted, scoring_mode,
+                    allow_bulk_submit, free_mistakes, max_penalties, box_bonus_enabled,
+                    board_size, prizes, blind_mode, solution_commitment, unique_solution,
+                    ..Tournament::default()
+                }).await;
             }
             Message::TournamentEnded { tournament_id, final_rankings } => {
                 self.handle_tournament_ended_msg(tournament_id, final_rankings).await;
@@ -157,18 +347,27 @@ impl FridayChainArenaContract {
         self.runtime.application_parameters().hub_chain_id
     }
 
+    fn min_move_interval_micros(&mut self) -> u64 {
+        self.runtime.application_parameters()
+            .min_move_interval_micros
+            .unwrap_or(DEFAULT_MIN_MOVE_INTERVAL_MICROS)
+    }
+
     fn is_hub(&mut self) -> bool {
         let hub = self.hub_chain_id();
         self.runtime.chain_id() == hub
     }
 
-    fn assert_admin(&mut self) {
-        let signer = self.runtime.authenticated_signer()
-            .expect("Operation must be authenticated");
-        match self.state.admin_owner.get() {
-            Some(admin) => assert_eq!(&signer, admin, "Only admin can perform this operation"),
-            None => panic!("Admin not set"),
-        }
+    /// Non-given cells in the current puzzle (0 if none is loaded).
+    fn open_cells(&self) -> u32 {
+        self.state.current_puzzle.get().as_ref()
+            .map(SudokuBoard::open_cells)
+            .unwrap_or(0)
+    }
+
+    fn is_admin(&mut self) -> bool {
+        let signer = self.signer();
+        is_admin_signer(self.state.admin_owner.get().as_ref(), &signer)
     }
 
     fn signer(&mut self) -> AccountOwner {
@@ -176,6 +375,28 @@ impl FridayChainArenaContract {
             .expect("Operation must be authenticated")
     }
 
+    /// Emit `event` on the tournament stream.
+    fn emit_event(&mut self, event: &ArenaEvent) {
+        self.runtime.emit(StreamName(TOURNAMENT_STREAM.to_vec()), &StreamEvent::new(event));
+    }
+
+    /// Send the game's unsynced placements to the Hub: a `SyncCellPlacement`
+    /// for a single move, otherwise one `SyncCellBatch`.
+    fn flush_pending_sync(&mut self, wallet: AccountOwner, game_state: &mut PlayerGameState) {
+        let cells_remaining = game_state.cells_remaining();
+        let moves = std::mem::take(&mut game_state.pending_sync);
+        if let [m] = moves.as_slice() {
+            self.send_to_hub(Message::SyncCellPlacement {
+                wallet, row: m.row, col: m.col, value: m.value,
+                timestamp_micros: m.timestamp_micros,
+                penalty_count: m.penalty_count,
+                cells_remaining,
+            });
+        } else if !moves.is_empty() {
+            self.send_to_hub(Message::SyncCellBatch { wallet, moves, cells_remaining });
+        }
+    }
+
     fn send_to_hub(&mut self, message: Message) {
         let hub = self.hub_chain_id();
         let current = self.runtime.chain_id();
@@ -187,9 +408,66 @@ impl FridayChainArenaContract {
         }
     }
 
+    /// Report a game that just ended at `max_penalties` to the Hub and
+    /// build the player's response.
+    fn game_over(
+        &mut self,
+        wallet: AccountOwner,
+        game_state: &PlayerGameState,
+        tournament: &Tournament,
+        now: u64,
+    ) -> ArenaResponse {
+        self.send_to_hub(Message::SyncGameOver {
+            wallet,
+            failed_at_micros: now,
+            penalty_count: game_state.penalty_count,
+            move_count: game_state.move_count,
+        });
+        ArenaResponse::GameOver(GameOverResponse {
+            penalty_count: game_state.penalty_count,
+            max_penalties: tournament.max_penalties.unwrap_or(0),
+            cells_remaining: game_state.cells_remaining(),
+        })
+    }
+
+    /// Whether the incoming player → Hub sync about `wallet` is genuine
+    /// (see `is_authentic_sync`). The first accepted one binds the wallet to
+    /// its origin chain. Forged syncs are dropped without a reply.
+    async fn authenticate_sync(&mut self, wallet: AccountOwner) -> bool {
+        let signer = self.runtime.authenticated_signer();
+        let origin = self.runtime.message_origin_chain_id();
+        let home_chain = self.state.home_chains.get(&wallet).await.unwrap_or(None);
+        if !is_authentic_sync(&wallet, signer, origin, home_chain) {
+            return false;
+        }
+        if let (None, Some(origin)) = (home_chain, origin) {
+            self.state.home_chains.insert(&wallet, origin)
+                .expect("Failed to bind home chain");
+        }
+        true
+    }
+
+    /// Apply a Hub pause/resume to the local copy of tournament
+    /// `tournament_id`, if that is the one this chain is running.
+    fn mirror_clock(&mut self, tournament_id: u64, update: impl FnOnce(&mut Tournament)) {
+        if let Some(mut tournament) = self.state.active_tournament.get().clone() {
+            if tournament.id == tournament_id {
+                update(&mut tournament);
+                self.state.active_tournament.set(Some(tournament));
+            }
+        }
+    }
+
     fn now_micros(&mut self) -> u64 {
         self.runtime.system_time().micros()
     }
+
+    /// A fresh seed from the block time mixed with the tournament counter,
+    /// so two starts in the same block still differ.
+    fn derive_seed(&mut self) -> u64 {
+        let counter = *self.state.tournament_counter.get();
+        self.now_micros() ^ counter.rotate_left(32)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -197,10 +475,18 @@ impl FridayChainArenaContract {
 // ---------------------------------------------------------------------------
 
 impl FridayChainArenaContract {
-    async fn handle_register_player(&mut self, discord_username: String) -> ArenaResponse {
-        if discord_username.is_empty() || discord_username.len() > 32 {
+    async fn handle_register_player(
+        &mut self,
+        discord_username: String,
+        team: Option<String>,
+    ) -> ArenaResponse {
+        if let Err(message) = validate_username(&discord_username) {
+            return ArenaResponse::Error(ErrorResponse { error_code: ErrorCode::InvalidUsername, message });
+        }
+        if let Some(Err(reason)) = team.as_deref().map(validate_username) {
             return ArenaResponse::Error(ErrorResponse {
-                message: "Discord username must be 1-32 characters".into(),
+                error_code: ErrorCode::InvalidTeam,
+                message: format!("Invalid team name ({})", reason),
             });
         }
 
@@ -209,53 +495,121 @@ impl FridayChainArenaContract {
 
         if self.state.players.contains_key(&wallet).await.unwrap_or(false) {
             return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::AlreadyRegistered,
                 message: "Player already registered. Use UpdateUsername to change.".into(),
             });
         }
 
+        // On the Hub the reverse index is authoritative, so check it directly.
+        // Player chains find out asynchronously via `Message::UsernameRejected`.
+        if self.is_hub() && !self.state.claim_username(wallet, None, &discord_username).await {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::UsernameTaken,
+                message: "Discord username is already taken".into(),
+            });
+        }
+
         let player_info = PlayerInfo {
             wallet,
             discord_username: discord_username.clone(),
             registered_at_micros: now,
+            team,
+            preferences: None,
         };
 
-        self.state.players.insert(&wallet, player_info.clone())
-            .expect("Failed to insert player");
-        self.send_to_hub(Message::SyncPlayer(player_info));
+        self.state.username_rejection.set(None);
+        if self.is_hub() {
+            // No sync message is sent to ourselves, so count the player here.
+            self.record_player(player_info, false);
+        } else {
+            self.state.players.insert(&wallet, player_info.clone())
+                .expect("Failed to insert player");
+            self.send_to_hub(Message::SyncPlayer(player_info));
+        }
 
         ArenaResponse::PlayerRegistered(PlayerRegisteredResponse { wallet, discord_username })
     }
 
     async fn handle_update_username(&mut self, new_discord_username: String) -> ArenaResponse {
-        if new_discord_username.is_empty() || new_discord_username.len() > 32 {
-            return ArenaResponse::Error(ErrorResponse {
-                message: "Discord username must be 1-32 characters".into(),
-            });
+        if let Err(message) = validate_username(&new_discord_username) {
+            return ArenaResponse::Error(ErrorResponse { error_code: ErrorCode::InvalidUsername, message });
         }
 
         let wallet = self.signer();
 
         match self.state.players.get(&wallet).await.unwrap_or(None) {
             Some(mut player) => {
+                if self.is_hub()
+                    && !self.state
+                        .claim_username(wallet, Some(&player.discord_username), &new_discord_username)
+                        .await
+                {
+                    return ArenaResponse::Error(ErrorResponse {
+                        error_code: ErrorCode::UsernameTaken,
+                        message: "Discord username is already taken".into(),
+                    });
+                }
+
                 player.discord_username = new_discord_username.clone();
                 self.state.players.insert(&wallet, player.clone())
                     .expect("Failed to update player");
+                self.state.username_rejection.set(None);
                 self.send_to_hub(Message::SyncPlayer(player));
                 ArenaResponse::UsernameUpdated(UsernameUpdatedResponse { wallet, new_discord_username })
             }
             None => ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotRegistered,
+                message: "Player not registered. Register first.".into(),
+            }),
+        }
+    }
+
+    async fn handle_set_preferences(&mut self, preferences: String) -> ArenaResponse {
+        if let Err(message) = validate_preferences(&preferences) {
+            return ArenaResponse::Error(ErrorResponse { error_code: ErrorCode::InvalidPreferences, message });
+        }
+
+        let wallet = self.signer();
+
+        match self.state.players.get(&wallet).await.unwrap_or(None) {
+            Some(mut player) => {
+                player.preferences = Some(preferences).filter(|p| !p.is_empty());
+                self.state.players.insert(&wallet, player.clone())
+                    .expect("Failed to update player");
+                let preferences = player.preferences.clone();
+                self.send_to_hub(Message::SyncPlayer(player));
+                ArenaResponse::PreferencesUpdated(PreferencesUpdatedResponse { wallet, preferences })
+            }
+            None => ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotRegistered,
                 message: "Player not registered. Register first.".into(),
             }),
         }
     }
 
+    fn handle_set_sync_batch_size(&mut self, moves: u32) -> ArenaResponse {
+        if moves == 0 || moves > MAX_SYNC_BATCH {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidBatchSize,
+                message: format!("Sync batch size must be 1-{}", MAX_SYNC_BATCH),
+            });
+        }
+
+        self.state.sync_batch_size.set(moves);
+        ArenaResponse::SyncBatchSizeUpdated(SyncBatchSizeResponse { moves })
+    }
+
     async fn handle_place_cell(&mut self, row: u8, col: u8, value: u8) -> ArenaResponse {
+        /// Maximum penalty timestamps kept per player; later ones still count.
+        const MAX_PENALTY_EVENTS: usize = 100;
+
         let wallet = self.signer();
         let now = self.now_micros();
 
         // Check registered
         if self.state.players.get(&wallet).await.unwrap_or(None).is_none() {
             return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotRegistered,
                 message: "Player not registered".into(),
             });
         }
@@ -265,19 +619,36 @@ impl FridayChainArenaContract {
             Some(t) => t.clone(),
             None => {
                 return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveTournament,
                     message: "No active tournament".into(),
                 });
             }
         };
 
-        if now < tournament.start_time_micros || now > tournament.end_time_micros {
+        if !tournament.in_window(now) {
             return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentExpired,
                 message: "Tournament time window has expired".into(),
             });
         }
 
+        if tournament.is_paused() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentPaused,
+                message: "Tournament is paused".into(),
+            });
+        }
+
+        if !self.state.may_play(&tournament, &wallet).await {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAllowed,
+                message: "This tournament is invite-only".into(),
+            });
+        }
+
         if row > 8 || col > 8 || value < 1 || value > 9 {
             return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidCell,
                 message: "Invalid cell coordinates or value".into(),
             });
         }
@@ -289,15 +660,31 @@ impl FridayChainArenaContract {
             Some(board) => board.clone(),
             None => {
                 return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::PuzzleNotLoaded,
                     message: "Puzzle not loaded for this tournament".into(),
                 });
             }
         };
 
+        if !tournament.matches_puzzle(&puzzle) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::PuzzleMismatch,
+                message: "Loaded puzzle does not match the active tournament; resync from the Hub".into(),
+            });
+        }
+
+        let side = puzzle.size.side();
+        if !puzzle.size.contains(r, c) || value as usize > side {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidCell,
+                message: format!("Cell and value must be within the {}×{} board", side, side),
+            });
+        }
+
         let mut game_state = match self.state.player_games.get(&wallet).await.unwrap_or(None) {
             Some(gs) => gs,
             None => {
-                let mut gs = PlayerGameState::new(&puzzle.puzzle);
+                let mut gs = PlayerGameState::for_puzzle(&puzzle);
                 gs.start_time_micros = now;
                 gs
             }
@@ -305,59 +692,84 @@ impl FridayChainArenaContract {
 
         if game_state.completed {
             return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::AlreadyCompleted,
                 message: "Board already completed".into(),
             });
         }
 
+        let min_interval = self.min_move_interval_micros();
+        if game_state.is_rate_limited(now, min_interval) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::RateLimited,
+                message: "Placing cells too quickly. Slow down.".into(),
+            });
+        }
+
         if game_state.given_mask[r][c] {
             return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::GivenCell,
                 message: "Cannot modify a given cell".into(),
             });
         }
 
-        let valid = sudoku::validate_placement(&game_state.board, r, c, value);
-        if !valid {
-            game_state.penalty_count += 1;
+        let valid = game_state.allows_placement(r, c, value, puzzle.variant, puzzle.size);
+        if !valid && game_state.record_mistake(tournament.free_mistakes) {
+            let mut timeline = self.state.penalty_events.get(&wallet).await
+                .unwrap_or(None).unwrap_or_default();
+            if timeline.len() < MAX_PENALTY_EVENTS {
+                timeline.push(now);
+                self.state.penalty_events.insert(&wallet, timeline)
+                    .expect("Failed to record penalty event");
+            }
         }
 
-        game_state.board[r][c] = value;
+        game_state.set_cell(r, c, value);
         game_state.move_count += 1;
+        game_state.last_move_time_micros = now;
+        game_state.record_move(MoveRecord { row, col, value }, tournament.elapsed_micros(now));
+        game_state.pending_sync.push(SyncedMove {
+            row, col, value, timestamp_micros: now,
+            penalty_count: game_state.penalty_count,
+        });
 
         let board_complete = game_state.check_complete(&puzzle.solution);
+        let game_over = !board_complete && tournament.penalties_exhausted(game_state.penalty_count);
+
+        // Flushed ahead of the completion sync so the Hub's sequence hash
+        // and pace check cover every move.
+        let batch_size = (*self.state.sync_batch_size.get()).max(1) as usize;
+        if board_complete || game_over || game_state.pending_sync.len() >= batch_size {
+            self.flush_pending_sync(wallet, &mut game_state);
+        }
 
         if board_complete {
             game_state.completed = true;
             game_state.completion_time_micros = Some(now);
-            game_state.score = game_state.calculate_score(tournament.start_time_micros, now);
+            game_state.score = game_state.calculate_score(&tournament, now);
 
             self.send_to_hub(Message::SyncBoardComplete {
                 wallet,
                 completion_time_micros: now,
                 penalty_count: game_state.penalty_count,
                 move_count: game_state.move_count,
+                final_board: game_state.board.clone(),
             });
+        } else if game_over {
+            game_state.fail(now);
         }
 
         self.state.player_games.insert(&wallet, game_state.clone())
             .expect("Failed to save game state");
 
-        self.send_to_hub(Message::SyncCellPlacement {
-            wallet, row, col, value, timestamp_micros: now,
-            penalty_count: game_state.penalty_count,
-        });
-
         if board_complete {
-            ArenaResponse::BoardCompleted(BoardCompletedResponse {
-                completion_time_micros: now,
-                penalty_count: game_state.penalty_count,
-                score: game_state.score,
-            })
+            ArenaResponse::BoardCompleted(game_state.completion_summary())
+        } else if game_over {
+            // After the placement sync, so the Hub's entry is final.
+            self.game_over(wallet, &game_state, &tournament, now)
         } else {
-            ArenaResponse::CellPlaced(CellPlacedResponse {
-                row, col, value, valid,
-                penalty_count: game_state.penalty_count,
-                board_complete: false,
-            })
+            ArenaResponse::CellPlaced(CellPlacedResponse::new(
+                row, col, value, valid, game_state.penalty_count, tournament.blind_mode,
+            ))
         }
     }
 
@@ -369,19 +781,29 @@ impl FridayChainArenaContract {
             Some(t) => t.clone(),
             None => {
                 return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveTournament,
                     message: "No active tournament".into(),
                 });
             }
         };
 
-        if now < tournament.start_time_micros || now > tournament.end_time_micros {
+        if !tournament.in_window(now) {
             return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentExpired,
                 message: "Tournament time window has expired".into(),
             });
         }
 
+        if tournament.is_paused() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentPaused,
+                message: "Tournament is paused".into(),
+            });
+        }
+
         if row > 8 || col > 8 {
             return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidCell,
                 message: "Invalid cell coordinates".into(),
             });
         }
@@ -393,147 +815,1385 @@ impl FridayChainArenaContract {
             Some(gs) => gs,
             None => {
                 return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoGameInProgress,
                     message: "No game in progress".into(),
                 });
             }
         };
 
         if game_state.completed {
-            return ArenaResponse::Error(ErrorResponse { message: "Board already completed".into() });
+            return ArenaResponse::Error(ErrorResponse { error_code: ErrorCode::AlreadyCompleted, message: "Board already completed".into() });
         }
         if game_state.given_mask[r][c] {
-            return ArenaResponse::Error(ErrorResponse { message: "Cannot clear a given cell".into() });
+            return ArenaResponse::Error(ErrorResponse { error_code: ErrorCode::GivenCell, message: "Cannot clear a given cell".into() });
         }
 
-        game_state.board[r][c] = 0;
+        game_state.set_cell(r, c, 0);
+        game_state.record_move(MoveRecord { row, col, value: 0 }, tournament.elapsed_micros(now));
         self.state.player_games.insert(&wallet, game_state)
             .expect("Failed to save game state");
 
         ArenaResponse::CellCleared(CellClearedResponse { row, col })
     }
 
-    async fn handle_subscribe_to_hub(&mut self) -> ArenaResponse {
-        let hub = self.hub_chain_id();
-        let app_id = self.runtime.application_id().forget_abi();
-        self.runtime.subscribe_to_events(
-            hub, app_id, StreamName(TOURNAMENT_STREAM.to_vec()),
-        );
-        ArenaResponse::Subscribed(SubscribedResponse { hub_chain_id: hub })
-    }
-
-    async fn handle_request_leaderboard(&mut self, limit: Option<u32>) -> ArenaResponse {
-        let hub = self.hub_chain_id();
-        let requester_chain = self.runtime.chain_id();
-        let limit = limit.unwrap_or(50).min(200);
+    async fn handle_clear_cells(&mut self, cells: Vec<MoveInput>) -> ArenaResponse {
+        let wallet = self.signer();
+        let now = self.now_micros();
 
-        self.runtime
-            .prepare_message(Message::LeaderboardRequest { requester_chain, limit })
-            .with_authentication()
-            .send_to(hub);
+        let tournament = match self.state.get_active_tournament() {
+            Some(t) => t.clone(),
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveTournament,
+                    message: "No active tournament".into(),
+                });
+            }
+        };
 
-        ArenaResponse::LeaderboardRequested(LeaderboardRequestedResponse {
-            message: "Leaderboard request sent to Hub. Query cachedLeaderboard shortly.".into(),
-        })
-    }
+        if !tournament.in_window(now) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentExpired,
+                message: "Tournament time window has expired".into(),
+            });
+        }
 
-    async fn handle_start_tournament(&mut self, seed: u64, duration_secs: u64) -> ArenaResponse {
-        self.assert_admin();
+        if tournament.is_paused() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentPaused,
+                message: "Tournament is paused".into(),
+            });
+        }
 
-        if !self.is_hub() {
+        if cells.len() > MAX_CLEAR_CELLS {
             return ArenaResponse::Error(ErrorResponse {
-                message: "StartTournament can only be called on the Hub chain".into(),
+                error_code: ErrorCode::InvalidCell,
+                message: format!("At most {} cells can be cleared at once", MAX_CLEAR_CELLS),
             });
         }
 
-        if let Some(t) = self.state.active_tournament.get() {
-            if t.active {
+        let mut game_state = match self.state.player_games.get(&wallet).await.unwrap_or(None) {
+            Some(gs) => gs,
+            None => {
                 return ArenaResponse::Error(ErrorResponse {
-                    message: "A tournament is already active. End it first.".into(),
+                    error_code: ErrorCode::NoGameInProgress,
+                    message: "No game in progress".into(),
                 });
             }
-        }
+        };
 
-        let now = self.now_micros();
-        let counter = *self.state.tournament_counter.get() + 1;
-        self.state.tournament_counter.set(counter);
+        if game_state.completed {
+            return ArenaResponse::Error(ErrorResponse { error_code: ErrorCode::AlreadyCompleted, message: "Board already completed".into() });
+        }
 
-        let start_time = now;
-        let end_time = now + (duration_secs * 1_000_000);
+        let coords: Vec<(u8, u8)> = cells.iter().map(|cell| (cell.row, cell.col)).collect();
+        let results = game_state.clear_cells(&coords, tournament.elapsed_micros(now));
+        self.state.player_games.insert(&wallet, game_state)
+            .expect("Failed to save game state");
 
-        let puzzle = sudoku::generate_puzzle(seed).expect("Failed to generate Sudoku puzzle");
-        self.state.current_puzzle.set(Some(puzzle));
+        let cleared = results.iter()
+            .filter(|r| r.outcome == ClearOutcome::Cleared)
+            .map(|r| CellPosition { row: r.row, col: r.col })
+            .collect();
+        ArenaResponse::CellsCleared(CellsClearedResponse { cleared, results })
+    }
 
-        let tournament = Tournament {
-            id: counter, seed,
-            start_time_micros: start_time,
-            end_time_micros: end_time,
-            active: true,
-            total_players: 0,
-            total_completions: 0,
-        };
-        self.state.active_tournament.set(Some(tournament));
+    async fn handle_submit_board(&mut self, board: Vec<Vec<u8>>) -> ArenaResponse {
+        let wallet = self.signer();
+        let now = self.now_micros();
 
-        // Clear previous leaderboard
-        self.state.leaderboard.clear();
+        if self.state.players.get(&wallet).await.unwrap_or(None).is_none() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotRegistered,
+                message: "Player not registered".into(),
+            });
+        }
 
-        let event = ArenaEvent::TournamentStarted {
-            tournament_id: counter, seed,
-            start_time_micros: start_time,
-            end_time_micros: end_time,
+        let tournament = match self.state.get_active_tournament() {
+            Some(t) => t.clone(),
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveTournament,
+                    message: "No active tournament".into(),
+                });
+            }
         };
-        self.runtime.emit(StreamName(TOURNAMENT_STREAM.to_vec()), &event);
 
-        self.state.event_log.push(event);
-        let ec = *self.state.event_counter.get() + 1;
-        self.state.event_counter.set(ec);
+        if !tournament.in_window(now) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentExpired,
+                message: "Tournament time window has expired".into(),
+            });
+        }
 
-        ArenaResponse::TournamentStarted(TournamentStartedResponse {
-            tournament_id: counter, seed,
-            start_time_micros: start_time,
-            end_time_micros: end_time,
-        })
-    }
+        if tournament.is_paused() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentPaused,
+                message: "Tournament is paused".into(),
+            });
+        }
 
-    async fn handle_end_tournament(&mut self) -> ArenaResponse {
-        self.assert_admin();
+        if !self.state.may_play(&tournament, &wallet).await {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAllowed,
+                message: "This tournament is invite-only".into(),
+            });
+        }
 
-        if !self.is_hub() {
+        if !tournament.allow_bulk_submit {
             return ArenaResponse::Error(ErrorResponse {
-                message: "EndTournament can only be called on the Hub chain".into(),
+                error_code: ErrorCode::BulkSubmitDisabled,
+                message: "This tournament does not accept whole-board submissions".into(),
             });
         }
 
-        let mut tournament = match self.state.active_tournament.get().clone() {
-            Some(t) if t.active => t,
-            _ => {
+        let puzzle = match self.state.current_puzzle.get() {
+            Some(board) => board.clone(),
+            None => {
                 return ArenaResponse::Error(ErrorResponse {
-                    message: "No active tournament to end".into(),
+                    error_code: ErrorCode::PuzzleNotLoaded,
+                    message: "Puzzle not loaded for this tournament".into(),
                 });
             }
         };
 
-        tournament.active = false;
+        if !tournament.matches_puzzle(&puzzle) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::PuzzleMismatch,
+                message: "Loaded puzzle does not match the active tournament; resync from the Hub".into(),
+            });
+        }
 
-        let final_rankings = self.state.get_sorted_leaderboard(200).await;
-        let total_players = tournament.total_players;
-        let total_completions = tournament.total_completions;
-        let tournament_id = tournament.id;
+        let board = match validate_board_arg(&board) {
+            Ok(board) => board,
+            Err(error_code) => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code,
+                    message: "Board must be 9 rows of 9 values from 0 to 9".into(),
+                });
+            }
+        };
+
+        let wrong_cells = match sudoku::check_submission(&puzzle, &board) {
+            Ok(wrong) => wrong,
+            Err(message) => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::InvalidBoard,
+                    message,
+                });
+            }
+        };
+
+        let mut game_state = match self.state.player_games.get(&wallet).await.unwrap_or(None) {
+            Some(gs) => gs,
+            None => {
+                let mut gs = PlayerGameState::for_puzzle(&puzzle);
+                gs.start_time_micros = now;
+                gs
+            }
+        };
+
+        if game_state.completed {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::AlreadyCompleted,
+                message: "Board already completed".into(),
+            });
+        }
+
+        let min_interval = self.min_move_interval_micros();
+        if game_state.is_rate_limited(now, min_interval) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::RateLimited,
+                message: "Submitting too quickly. Slow down.".into(),
+            });
+        }
+
+        // Every rejected submission is charged, or resubmitting would be a
+        // free check of how many cells are wrong.
+        for _ in 0..wrong_cells {
+            game_state.record_mistake(tournament.free_mistakes);
+        }
+        game_state.board_submissions += 1;
+        game_state.move_count += 1;
+        game_state.last_move_time_micros = now;
+
+        if tournament.penalties_exhausted(game_state.penalty_count) {
+            game_state.fail(now);
+            self.state.player_games.insert(&wallet, game_state.clone())
+                .expect("Failed to save game state");
+            return self.game_over(wallet, &game_state, &tournament, now);
+        }
+
+        if wrong_cells > 0 {
+            let penalty_count = game_state.penalty_count;
+            self.state.player_games.insert(&wallet, game_state)
+                .expect("Failed to save game state");
+            return ArenaResponse::BoardRejected(BoardRejectedResponse::new(
+                wrong_cells, penalty_count, tournament.blind_mode,
+            ));
+        }
+
+        game_state.board = board.iter().map(|row| row.to_vec()).collect();
+        game_state.tracker = None;
+        game_state.completed = true;
+        game_state.completion_time_micros = Some(now);
+        game_state.score = game_state.calculate_score(&tournament, now);
+
+        self.flush_pending_sync(wallet, &mut game_state);
+        self.send_to_hub(Message::SyncBoardComplete {
+            wallet,
+            completion_time_micros: now,
+            penalty_count: game_state.penalty_count,
+            move_count: game_state.move_count,
+            final_board: game_state.board.clone(),
+        });
+
+        self.state.player_games.insert(&wallet, game_state.clone())
+            .expect("Failed to save game state");
+
+        ArenaResponse::BoardCompleted(game_state.completion_summary())
+    }
+
+    async fn handle_check_in(&mut self) -> ArenaResponse {
+        let wallet = self.signer();
+
+        if self.state.players.get(&wallet).await.unwrap_or(None).is_none() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotRegistered,
+                message: "Player not registered".into(),
+            });
+        }
+
+        if self.state.get_active_tournament().is_some() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::CheckInClosed,
+                message: "Tournament already started; check-in is closed".into(),
+            });
+        }
+
+        let scheduled_start_micros = match *self.state.scheduled_start_micros.get() {
+            Some(start) => start,
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NotScheduled,
+                    message: "No tournament is scheduled".into(),
+                });
+            }
+        };
+
+        if self.is_hub() {
+            self.handle_sync_check_in(wallet).await;
+        } else {
+            self.send_to_hub(Message::SyncCheckIn { wallet });
+        }
+
+        ArenaResponse::CheckedIn(CheckedInResponse { wallet, scheduled_start_micros })
+    }
+
+    async fn handle_heartbeat(&mut self) -> ArenaResponse {
+        let wallet = self.signer();
+
+        if self.state.players.get(&wallet).await.unwrap_or(None).is_none() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotRegistered,
+                message: "Player not registered".into(),
+            });
+        }
+
+        let timestamp_micros = self.now_micros();
+        if self.is_hub() {
+            self.record_heartbeat(wallet, timestamp_micros);
+        } else {
+            self.send_to_hub(Message::Heartbeat { wallet, timestamp_micros });
+        }
+
+        ArenaResponse::HeartbeatSent(HeartbeatSentResponse { wallet, timestamp_micros })
+    }
+
+    async fn handle_submit_daily_result(
+        &mut self,
+        date_ymd: u32,
+        solve_time_micros: u64,
+    ) -> ArenaResponse {
+        let wallet = self.signer();
+
+        if self.state.players.get(&wallet).await.unwrap_or(None).is_none() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotRegistered,
+                message: "Player not registered".into(),
+            });
+        }
+
+        if !is_valid_date_ymd(date_ymd) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidDate,
+                message: format!("{} is not a YYYYMMDD date", date_ymd),
+            });
+        }
+
+        if solve_time_micros == 0 {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidDuration,
+                message: "Solve time must be positive".into(),
+            });
+        }
+
+        let key = (wallet, date_ymd);
+        let best_time_micros = self.state.daily_results.get(&key).await
+            .unwrap_or(None)
+            .map_or(solve_time_micros, |best| best.min(solve_time_micros));
+        self.state.daily_results.insert(&key, best_time_micros)
+            .expect("Failed to record daily result");
+
+        ArenaResponse::DailyResultRecorded(DailyResultResponse {
+            date_ymd, solve_time_micros, best_time_micros,
+        })
+    }
+
+    async fn handle_subscribe_to_hub(&mut self) -> ArenaResponse {
+        let hub = self.hub_chain_id();
+        if !*self.state.subscribed.get() {
+            let app_id = self.runtime.application_id().forget_abi();
+            self.runtime.subscribe_to_events(
+                hub, app_id, StreamName(TOURNAMENT_STREAM.to_vec()),
+            );
+            self.state.subscribed.set(true);
+        }
+        ArenaResponse::Subscribed(SubscribedResponse { hub_chain_id: hub })
+    }
+
+    async fn handle_unsubscribe_from_hub(&mut self) -> ArenaResponse {
+        let hub = self.hub_chain_id();
+        if *self.state.subscribed.get() {
+            let app_id = self.runtime.application_id().forget_abi();
+            self.runtime.unsubscribe_from_events(
+                hub, app_id, StreamName(TOURNAMENT_STREAM.to_vec()),
+            );
+            self.state.subscribed.set(false);
+        }
+        ArenaResponse::Unsubscribed(UnsubscribedResponse { hub_chain_id: hub })
+    }
+
+    async fn handle_request_leaderboard(&mut self, limit: Option<u32>) -> ArenaResponse {
+        let hub = self.hub_chain_id();
+        let requester_chain = self.runtime.chain_id();
+        let limit = limit.unwrap_or(50).min(200);
+        let now = self.now_micros();
+        self.state.leaderboard_request_pending.set(Some(now));
+
+        self.runtime
+            .prepare_message(Message::LeaderboardRequest { requester_chain, limit })
+            .with_authentication()
+            .send_to(hub);
+
+        ArenaResponse::LeaderboardRequested(LeaderboardRequestedResponse {
+            message: "Leaderboard request sent to Hub. Poll leaderboardRequestStatus.".into(),
+        })
+    }
+
+    async fn handle_resync_from_hub(&mut self) -> ArenaResponse {
+        let hub = self.hub_chain_id();
+        let requester_chain = self.runtime.chain_id();
+        let now = self.now_micros();
+        self.state.leaderboard_request_pending.set(Some(now));
+
+        self.runtime
+            .prepare_message(Message::FullStateRequest { requester_chain })
+            .with_authentication()
+            .send_to(hub);
+
+        ArenaResponse::ResyncRequested(ResyncRequestedResponse { hub_chain_id: hub })
+    }
+
+    async fn handle_request_logic_hint(&mut self, reveal_value: bool) -> ArenaResponse {
+        let wallet = self.signer();
+        let now = self.now_micros();
+
+        if self.state.players.get(&wallet).await.unwrap_or(None).is_none() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotRegistered,
+                message: "Player not registered".into(),
+            });
+        }
+
+        let tournament = match self.state.get_active_tournament() {
+            Some(t) => t.clone(),
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveTournament,
+                    message: "No active tournament".into(),
+                });
+            }
+        };
+
+        if !tournament.in_window(now) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentExpired,
+                message: "Tournament time window has expired".into(),
+            });
+        }
+
+        if tournament.is_paused() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentPaused,
+                message: "Tournament is paused".into(),
+            });
+        }
+
+        if !self.state.may_play(&tournament, &wallet).await {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAllowed,
+                message: "This tournament is invite-only".into(),
+            });
+        }
+
+        let (variant, size) = match self.state.current_puzzle.get() {
+            Some(puzzle) if tournament.matches_puzzle(puzzle) => (puzzle.variant, puzzle.size),
+            Some(_) => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::PuzzleMismatch,
+                    message: "Loaded puzzle does not match the active tournament; resync from the Hub".into(),
+                });
+            }
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::PuzzleNotLoaded,
+                    message: "Puzzle not loaded".into(),
+                });
+            }
+        };
+
+        let mut game_state = match self.state.player_games.get(&wallet).await.unwrap_or(None) {
+            Some(gs) => gs,
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoGameInProgress,
+                    message: "No game in progress".into(),
+                });
+            }
+        };
+
+        if game_state.completed {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::AlreadyCompleted,
+                message: "Board already completed".into(),
+            });
+        }
+
+        let hint = game_state.logic_hint(variant, size);
+        if hint.is_some() {
+            game_state.logic_hints_used += 1;
+            self.state.player_games.insert(&wallet, game_state.clone())
+                .expect("Failed to save game state");
+        }
+
+        ArenaResponse::LogicHint(LogicHintResponse {
+            deducible: hint.is_some(),
+            cell: hint.map(|(r, c, _)| CellPosition { row: r as u8, col: c as u8 }),
+            value: hint.filter(|_| reveal_value).map(|(_, _, v)| v),
+            logic_hints_used: game_state.logic_hints_used,
+        })
+    }
+
+    async fn handle_load_puzzle(&mut self) -> ArenaResponse {
+        let tournament = match self.state.get_active_tournament() {
+            Some(t) => t.clone(),
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveTournament,
+                    message: "No active tournament".into(),
+                });
+            }
+        };
+
+        let Some(puzzle) = tournament.regenerate_puzzle() else {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::PuzzleMismatch,
+                message: "Seed does not reproduce the tournament's puzzle; resync from the Hub".into(),
+            });
+        };
+        self.state.current_puzzle.set(Some(puzzle));
+
+        ArenaResponse::PuzzleLoaded(PuzzleLoadedResponse {
+            tournament_id: tournament.id,
+            variant: tournament.variant,
+        })
+    }
+
+    async fn handle_schedule_tournament(&mut self, start_time_micros: u64) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "ScheduleTournament can only be called on the Hub chain".into(),
+            });
+        }
+
+        if self.state.get_active_tournament().is_some() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentAlreadyActive,
+                message: "A tournament is already active. End it first.".into(),
+            });
+        }
+
+        if start_time_micros <= self.now_micros() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidSchedule,
+                message: "Scheduled start time must be in the future".into(),
+            });
+        }
+
+        self.state.scheduled_start_micros.set(Some(start_time_micros));
+        self.state.checked_in.clear();
+
+        let event = ArenaEvent::TournamentScheduled {
+            start_time_micros,
+            emitted_at_micros: self.now_micros(),
+        };
+        self.emit_event(&event);
+
+        self.state.event_log.push(event);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+
+        ArenaResponse::TournamentScheduled(TournamentScheduledResponse { start_time_micros })
+    }
+
+    async fn handle_start_tournament(
+        &mut self,
+        seed: u64,
+        duration_secs: u64,
+        min_players: Option<u32>,
+        rules: Tournament,
+    ) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "StartTournament can only be called on the Hub chain".into(),
+            });
+        }
+
+        if let Some(t) = self.state.active_tournament.get() {
+            if t.active {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::TournamentAlreadyActive,
+                    message: "A tournament is already active. End it first.".into(),
+                });
+            }
+        }
+
+        if let Err(message) = tournament_end_time(self.now_micros(), duration_secs) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidDuration,
+                message,
+            });
+        }
+
+        if rules.board_size != BoardSize::Nine
+            && (!rules.board_size.supports(rules.variant) || rules.box_bonus_enabled)
+        {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::UnsupportedBoardSize,
+                message: "Mini boards support only the Classic variant without box bonus".into(),
+            });
+        }
+
+        if rules.prizes.len() > MAX_PRIZE_PLACES {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidPrizes,
+                message: format!("Prizes can cover at most {} places", MAX_PRIZE_PLACES),
+            });
+        }
+
+        if let Err(message) = check_min_players(*self.state.player_count.get(), min_players) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotEnoughPlayers,
+                message,
+            });
+        }
+
+        let previous = self.state.used_seeds.get(&seed).await.unwrap_or(None);
+        if let Err(message) = check_seed_unused(seed, previous) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::SeedReused,
+                message,
+            });
+        }
+
+        match self.start_tournament(seed, duration_secs, rules).await {
+            Some(started) => ArenaResponse::TournamentStarted(started),
+            None => ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::GenerationFailed,
+                message: format!(
+                    "Puzzle generation for seed {} exceeded its step budget; pick another seed",
+                    seed,
+                ),
+            }),
+        }
+    }
+
+    /// Open a new tournament now. Callers check permissions, the duration,
+    /// and that no tournament is active. Only the rule fields of `rules`
+    /// (variant, restriction, scoring, bulk submit, free mistakes, size,
+    /// prizes, blind mode) are used. Returns `None`, changing nothing, if
+    /// the puzzle can't be generated.
+    async fn start_tournament(
+        &mut self,
+        seed: u64,
+        duration_secs: u64,
+        rules: Tournament,
+    ) -> Option<TournamentStartedResponse> {
+        let variant = rules.variant;
+        let puzzle = sudoku::generate_puzzle_sized(seed, variant, rules.board_size)?;
+
+        let now = self.now_micros();
+        let counter = *self.state.tournament_counter.get() + 1;
+        self.state.tournament_counter.set(counter);
+        // A seed keeps the tournament that first used it.
+        if !self.state.used_seeds.contains_key(&seed).await.unwrap_or(false) {
+            self.state.used_seeds.insert(&seed, counter)
+                .expect("Failed to record seed");
+        }
+
+        let start_time = now;
+        let end_time = tournament_end_time(now, duration_secs)
+            .expect("Tournament duration validated by caller");
+
+        let solution_commitment = sudoku::grid_commitment(&puzzle.solution);
+        let unique_solution = sudoku::has_unique_solution(&puzzle.puzzle, variant, puzzle.size);
+        self.state.current_puzzle.set(Some(puzzle));
+
+        let tournament = Tournament {
+            id: counter, seed,
+            start_time_micros: start_time,
+            end_time_micros: end_time,
+            active: true,
+            total_players: 0,
+            total_completions: 0,
+            solution_commitment,
+            unique_solution,
+            ..rules
+        };
+        self.state.active_tournament.set(Some(tournament.clone()));
+
+        // Clear previous leaderboard and box claims, and close the lobby
+        self.state.leaderboard.clear();
+        self.state.box_first_completers.clear();
+        self.state.correct_cells.clear();
+        self.state.scheduled_start_micros.set(None);
+        self.state.checked_in.clear();
+
+        let event = ArenaEvent::TournamentStarted {
+            tournament_id: counter, seed, variant,
+            start_time_micros: start_time,
+            end_time_micros: end_time,
+            restricted: tournament.restricted,
+            scoring_mode: tournament.scoring_mode,
+            allow_bulk_submit: tournament.allow_bulk_submit,
+            free_mistakes: tournament.free_mistakes,
+            max_penalties: tournament.max_penalties,
+            box_bonus_enabled: tournament.box_bonus_enabled,
+            board_size: tournament.board_size,
+            prizes: tournament.prizes.clone(),
+            blind_mode: tournament.blind_mode,
+            solution_commitment,
+            unique_solution,
+            emitted_at_micros: now,
+        };
+        self.emit_event(&event);
+
+        self.state.event_log.push(event);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+
+        Some(TournamentStartedResponse {
+            tournament_id: counter, seed, variant,
+            start_time_micros: start_time,
+            end_time_micros: end_time,
+            restricted: tournament.restricted,
+            scoring_mode: tournament.scoring_mode,
+            allow_bulk_submit: tournament.allow_bulk_submit,
+            free_mistakes: tournament.free_mistakes,
+            max_penalties: tournament.max_penalties,
+            box_bonus_enabled: tournament.box_bonus_enabled,
+            board_size: tournament.board_size,
+            prizes: tournament.prizes,
+            blind_mode: tournament.blind_mode,
+        })
+    }
+
+    /// Lazily start this week's tournament: called before every operation
+    /// and message, since the Hub can't schedule its own execution.
+    async fn maybe_auto_start(&mut self) {
+        let Some(schedule) = *self.state.weekly_schedule.get() else {
+            return;
+        };
+        if !self.is_hub() || self.state.get_active_tournament().is_some() {
+            return;
+        }
+
+        let now = self.now_micros();
+        let Some(slot) = schedule.slot_at_or_before(now) else {
+            return;
+        };
+        // A slot is used once, and a missed slot isn't started late.
+        let slot_end = slot + schedule.duration_secs * 1_000_000;
+        if *self.state.last_auto_slot_micros.get() == Some(slot) || now >= slot_end {
+            return;
+        }
+
+        let seed = match schedule.seed_strategy {
+            SeedStrategy::WeekNumber => schedule.week_number(slot),
+            SeedStrategy::ActivationTime => self.derive_seed(),
+        };
+        // The slot is spent even if generation fails, so a bad seed isn't
+        // retried on every operation for the rest of the slot.
+        self.state.last_auto_slot_micros.set(Some(slot));
+        // Nobody is there to pick a fresh seed, so a used one is stepped
+        // past rather than repeating an old puzzle.
+        for seed in seed_candidates(seed) {
+            if !self.state.used_seeds.contains_key(&seed).await.unwrap_or(true) {
+                self.start_tournament(seed, schedule.duration_secs, Tournament::default()).await;
+                return;
+            }
+        }
+    }
+
+    async fn handle_allow_player(&mut self, wallet: AccountOwner) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "AllowPlayer can only be called on the Hub chain".into(),
+            });
+        }
+
+        self.state.allowed.insert(&wallet, ())
+            .expect("Failed to update allowlist");
+
+        // Subscribed player chains mirror the allowlist from the stream.
+        let event = ArenaEvent::PlayerAllowed { wallet, emitted_at_micros: self.now_micros() };
+        self.emit_event(&event);
+
+        self.state.event_log.push(event);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+
+        ArenaResponse::PlayerAllowed(PlayerAllowedResponse { wallet })
+    }
+
+    async fn handle_enable_weekly_schedule(&mut self, schedule: WeeklySchedule) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "EnableWeeklySchedule can only be called on the Hub chain".into(),
+            });
+        }
+
+        if schedule.hour_utc > 23 {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidSchedule,
+                message: "hour_utc must be 0-23".into(),
+            });
+        }
+
+        if let Err(message) = tournament_end_time(0, schedule.duration_secs) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidDuration,
+                message,
+            });
+        }
+
+        self.state.weekly_schedule.set(Some(schedule));
+        let now = self.now_micros();
+        ArenaResponse::WeeklyScheduleUpdated(WeeklyScheduleResponse {
+            schedule: Some(schedule),
+            next_start_micros: Some(schedule.next_start_at_or_after(now)),
+        })
+    }
+
+    async fn handle_disable_weekly_schedule(&mut self) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "DisableWeeklySchedule can only be called on the Hub chain".into(),
+            });
+        }
+
+        self.state.weekly_schedule.set(None);
+        ArenaResponse::WeeklyScheduleUpdated(WeeklyScheduleResponse {
+            schedule: None,
+            next_start_micros: None,
+        })
+    }
+
+    async fn handle_start_season(&mut self, placement_points: Option<Vec<u64>>) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "StartSeason can only be called on the Hub chain".into(),
+            });
+        }
+
+        let previous = self.state.current_season.get().clone();
+        if previous.as_ref().is_some_and(Season::is_active) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::SeasonAlreadyActive,
+                message: "End the current season before starting another".into(),
+            });
+        }
+
+        if placement_points.as_deref().is_some_and(|table| !is_valid_points_table(table)) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidPointsTable,
+                message: format!(
+                    "Placement points must award between 1 and {} places",
+                    MAX_PLACEMENT_POINTS,
+                ),
+            });
+        }
+
+        let season = Season {
+            id: previous.map(|s| s.id + 1).unwrap_or(1),
+            started_at_micros: self.now_micros(),
+            ended_at_micros: None,
+            placement_points: placement_points.unwrap_or_default(),
+        };
+        self.state.season_points.clear();
+        self.state.current_season.set(Some(season.clone()));
+        ArenaResponse::SeasonUpdated(season)
+    }
+
+    async fn handle_end_season(&mut self) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "EndSeason can only be called on the Hub chain".into(),
+            });
+        }
+
+        let mut season = match self.state.current_season.get().clone() {
+            Some(s) if s.is_active() => s,
+            _ => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveSeason,
+                    message: "No season is running".into(),
+                });
+            }
+        };
+
+        season.ended_at_micros = Some(self.now_micros());
+        self.state.current_season.set(Some(season.clone()));
+        ArenaResponse::SeasonUpdated(season)
+    }
+
+    fn handle_validate_puzzle_string(
+        &mut self,
+        puzzle_string: String,
+        variant: SudokuVariant,
+    ) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "ValidatePuzzleString can only be called on the Hub chain".into(),
+            });
+        }
+
+        ArenaResponse::PuzzleValidated(sudoku::validate_puzzle_string(&puzzle_string, variant))
+    }
+
+    /// Admin reset of a single player's game. The reset goes to the wallet's
+    /// home chain (bound by its first accepted sync), so it can't be aimed
+    /// at another chain; the player chain accepts `ResetGame` only from the
+    /// Hub.
+    async fn handle_reset_player_game(&mut self, wallet: AccountOwner) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "ResetPlayerGame can only be called on the Hub chain".into(),
+            });
+        }
+
+        let player_chain_id = match self.state.home_chains.get(&wallet).await.unwrap_or(None) {
+            Some(chain_id) => chain_id,
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NotRegistered,
+                    message: "Player has never synced with the Hub".into(),
+                });
+            }
+        };
+        self.state.correct_cells.remove(&wallet).expect("Failed to clear correct cells");
+
+        let entry = self.state.leaderboard.get(&wallet).await.unwrap_or(None);
+        let leaderboard_entry_removed = entry.is_some();
+        if let Some(entry) = entry {
+            self.state.leaderboard.remove(&wallet)
+                .expect("Failed to remove leaderboard entry");
+            // The entry is recreated (and recounted) on the player's next move.
+            if let Some(mut tournament) = self.state.active_tournament.get().clone() {
+                tournament.total_players = tournament.total_players.saturating_sub(1);
+                if entry.completed {
+                    tournament.total_completions = tournament.total_completions.saturating_sub(1);
+                }
+                self.state.active_tournament.set(Some(tournament));
+            }
+        }
+
+        self.runtime
+            .prepare_message(Message::ResetGame { wallet })
+            .with_authentication()
+            .send_to(player_chain_id);
+
+        ArenaResponse::PlayerGameReset(PlayerGameResetResponse {
+            wallet,
+            player_chain_id,
+            leaderboard_entry_removed,
+        })
+    }
+
+    async fn handle_set_player_score(
+        &mut self,
+        wallet: AccountOwner,
+        score: u64,
+        reason: String,
+    ) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "SetPlayerScore can only be called on the Hub chain".into(),
+            });
+        }
+
+        let reason = reason.trim().to_string();
+        if reason.is_empty() || reason.len() > MAX_ADJUSTMENT_REASON_LEN {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidReason,
+                message: format!("Reason must be 1-{} characters", MAX_ADJUSTMENT_REASON_LEN),
+            });
+        }
+
+        let mut entry = match self.state.leaderboard.get(&wallet).await.unwrap_or(None) {
+            Some(entry) => entry,
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoLeaderboardEntry,
+                    message: "Player has no leaderboard entry in this tournament".into(),
+                });
+            }
+        };
+
+        let admin = self.signer();
+        let now = self.now_micros();
+        let tournament_id = self.state.active_tournament.get()
+            .as_ref().map(|t| t.id).unwrap_or(0);
+        let adjustment = ScoreAdjustment::apply(
+            &mut entry, tournament_id, score, reason, admin, now,
+        );
+        self.state.leaderboard.insert(&wallet, entry)
+            .expect("Failed to update leaderboard entry");
+        self.state.score_adjustments.push(adjustment.clone());
+
+        self.state.last_broadcast_micros.set(now);
+        let rules = self.state.active_tournament.get().clone().unwrap_or_default();
+        let entries = self.state.get_sorted_leaderboard(rules.broadcast_size()).await;
+        let event = rules.leaderboard_event(entries, now);
+        self.emit_event(&event);
+
+        ArenaResponse::ScoreAdjusted(adjustment)
+    }
+
+    async fn handle_end_tournament(&mut self) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "EndTournament can only be called on the Hub chain".into(),
+            });
+        }
+
+        let mut tournament = match self.state.active_tournament.get().clone() {
+            Some(t) if t.active => t,
+            _ => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveTournament,
+                    message: "No active tournament to end".into(),
+                });
+            }
+        };
+
+        tournament.active = false;
+
+        let all_rankings = self.state.get_sorted_leaderboard(u32::MAX).await;
+        let final_rankings: Vec<LeaderboardEntry> = all_rankings.iter().take(200).cloned().collect();
+        let total_players = tournament.total_players;
+        let total_completions = tournament.total_completions;
+        let tournament_id = tournament.id;
+
+        self.state.past_tournaments.push(tournament.clone());
+        self.state.tournaments_by_id.insert(&tournament_id, tournament.clone())
+            .expect("Failed to index tournament");
+        if let Some(season) = self.state.current_season.get().clone() {
+            for (wallet, awarded) in season.placement_awards(&all_rankings) {
+                let points = self.state.season_points.get(&wallet).await
+                    .unwrap_or(None)
+                    .unwrap_or(0);
+                self.state.season_points.insert(&wallet, points.saturating_add(awarded))
+                    .expect("Failed to update season points");
+            }
+        }
+        self.state.break_streaks(&all_rankings).await;
+        self.state.final_rankings_by_id.insert(&tournament_id, all_rankings)
+            .expect("Failed to store final rankings");
+        self.state.active_tournament.set(Some(tournament));
+
+        let event = ArenaEvent::TournamentEnded {
+            tournament_id,
+            final_rankings: final_rankings.clone(),
+            emitted_at_micros: self.now_micros(),
+        };
+        self.emit_event(&event);
+
+        self.state.event_log.push(event);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+
+        ArenaResponse::TournamentEnded(TournamentEndedResponse {
+            tournament_id, total_players, total_completions,
+        })
+    }
+
+    async fn handle_claim_reward(&mut self, tournament_id: u64) -> ArenaResponse {
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "ClaimReward can only be called on the Hub chain".into(),
+            });
+        }
+
+        let wallet = self.signer();
+        let ended = self.state.tournaments_by_id.get(&tournament_id).await.unwrap_or(None);
+        let rankings = self.state.final_rankings_by_id.get(&tournament_id).await.unwrap_or(None);
+        let (Some(tournament), Some(rankings)) = (ended, rankings) else {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentNotEnded,
+                message: format!("Tournament #{} has not ended", tournament_id),
+            });
+        };
+
+        let Some((rank, amount)) = tournament.reward_for(&rankings, &wallet) else {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NoReward,
+                message: "No prize for this wallet in that tournament".into(),
+            });
+        };
+
+        let key = (tournament_id, wallet);
+        if self.state.reward_claims.contains_key(&key).await.unwrap_or(false) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::RewardAlreadyClaimed,
+                message: "Prize already claimed".into(),
+            });
+        }
+        self.state.reward_claims.insert(&key, amount)
+            .expect("Failed to record reward claim");
+
+        ArenaResponse::RewardClaimed(RewardClaimedResponse { tournament_id, wallet, rank, amount })
+    }
+
+    async fn handle_transfer_admin(&mut self, new_admin: AccountOwner) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "TransferAdmin can only be called on the Hub chain".into(),
+            });
+        }
 
-        self.state.past_tournaments.push(tournament.clone());
+        if !is_valid_admin(&new_admin) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidAdmin,
+                message: "New admin must be a real account owner".into(),
+            });
+        }
+
+        let old_admin = self.signer();
+        self.state.admin_owner.set(Some(new_admin));
+
+        let event = ArenaEvent::AdminChanged {
+            old: old_admin,
+            new: new_admin,
+            emitted_at_micros: self.now_micros(),
+        };
+        self.emit_event(&event);
+
+        self.state.event_log.push(event);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+
+        ArenaResponse::AdminTransferred(AdminTransferredResponse { old_admin, new_admin })
+    }
+
+    async fn handle_reset_arena(&mut self, confirm: String) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "ResetArena can only be called on the Hub chain".into(),
+            });
+        }
+
+        if !is_reset_confirmed(&confirm) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::ConfirmationRequired,
+                message: format!(
+                    "ResetArena is irreversible; pass confirm: \"{}\" to proceed",
+                    RESET_ARENA_CONFIRMATION,
+                ),
+            });
+        }
+
+        let players_cleared = *self.state.player_count.get();
+        let tournaments_cleared = *self.state.tournament_counter.get();
+        self.state.reset();
+
+        let event = ArenaEvent::ArenaReset { emitted_at_micros: self.now_micros() };
+        self.emit_event(&event);
+
+        self.state.event_log.push(event);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+
+        ArenaResponse::ArenaReset(ArenaResetResponse { players_cleared, tournaments_cleared })
+    }
+
+    async fn handle_announce(&mut self, text: String) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "Announce can only be called on the Hub chain".into(),
+            });
+        }
+
+        if let Err(message) = validate_announcement(&text) {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::InvalidAnnouncement,
+                message,
+            });
+        }
+
+        let now = self.now_micros();
+        self.state.latest_announcement.set(Some(text.clone()));
+
+        let event = ArenaEvent::Announcement { text: text.clone(), emitted_at_micros: now };
+        self.emit_event(&event);
+
+        self.state.event_log.push(event);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+
+        ArenaResponse::Announced(AnnouncedResponse { text, emitted_at_micros: now })
+    }
+
+    async fn handle_pause_tournament(&mut self) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "PauseTournament can only be called on the Hub chain".into(),
+            });
+        }
+
+        let mut tournament = match self.state.get_active_tournament() {
+            Some(t) => t.clone(),
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveTournament,
+                    message: "No active tournament to pause".into(),
+                });
+            }
+        };
+
+        if tournament.is_paused() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentPaused,
+                message: "Tournament is already paused".into(),
+            });
+        }
+
+        let now = self.now_micros();
+        tournament.pause(now);
+        let tournament_id = tournament.id;
         self.state.active_tournament.set(Some(tournament));
 
-        let event = ArenaEvent::TournamentEnded {
+        let event = ArenaEvent::TournamentPaused {
             tournament_id,
-            final_rankings: final_rankings.clone(),
+            paused_at_micros: now,
+            emitted_at_micros: now,
         };
-        self.runtime.emit(StreamName(TOURNAMENT_STREAM.to_vec()), &event);
+        self.emit_event(&event);
 
         self.state.event_log.push(event);
         let ec = *self.state.event_counter.get() + 1;
         self.state.event_counter.set(ec);
 
-        ArenaResponse::TournamentEnded(TournamentEndedResponse {
-            tournament_id, total_players, total_completions,
+        ArenaResponse::TournamentPaused(TournamentPausedResponse {
+            tournament_id, paused_at_micros: now,
+        })
+    }
+
+    async fn handle_resume_tournament(&mut self) -> ArenaResponse {
+        if !self.is_admin() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotAdmin,
+                message: "Only admin can perform this operation".into(),
+            });
+        }
+
+        if !self.is_hub() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::NotHub,
+                message: "ResumeTournament can only be called on the Hub chain".into(),
+            });
+        }
+
+        let mut tournament = match self.state.get_active_tournament() {
+            Some(t) => t.clone(),
+            None => {
+                return ArenaResponse::Error(ErrorResponse {
+                    error_code: ErrorCode::NoActiveTournament,
+                    message: "No active tournament to resume".into(),
+                });
+            }
+        };
+
+        if !tournament.is_paused() {
+            return ArenaResponse::Error(ErrorResponse {
+                error_code: ErrorCode::TournamentNotPaused,
+                message: "Tournament is not paused".into(),
+            });
+        }
+
+        let now = self.now_micros();
+        tournament.resume(now);
+        let (tournament_id, paused_micros, end_time_micros) =
+            (tournament.id, tournament.paused_micros, tournament.end_time_micros);
+        self.state.active_tournament.set(Some(tournament));
+
+        // Player chains copy the Hub's totals rather than timing the pause
+        // themselves, so every chain agrees on the new window.
+        let event = ArenaEvent::TournamentResumed {
+            tournament_id,
+            paused_micros,
+            end_time_micros,
+            emitted_at_micros: now,
+        };
+        self.emit_event(&event);
+
+        self.state.event_log.push(event);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+
+        ArenaResponse::TournamentResumed(TournamentResumedResponse {
+            tournament_id, paused_micros, end_time_micros,
         })
     }
 }
@@ -545,91 +2205,199 @@ impl FridayChainArenaContract {
 impl FridayChainArenaContract {
     async fn handle_sync_player(&mut self, player_info: PlayerInfo) {
         let wallet = player_info.wallet;
-        let is_new = !self.state.players.contains_key(&wallet).await.unwrap_or(false);
+        let existing = self.state.players.get(&wallet).await.unwrap_or(None);
+        let previous_username = existing.as_ref().map(|p| p.discord_username.clone());
+
+        let claimed = self.state
+            .claim_username(wallet, previous_username.as_deref(), &player_info.discord_username)
+            .await;
+        if !claimed {
+            // The Hub is authoritative: tell the player chain to roll back.
+            if let Some(origin) = self.runtime.message_origin_chain_id() {
+                self.runtime
+                    .prepare_message(Message::UsernameRejected {
+                        wallet,
+                        rejected_username: player_info.discord_username,
+                        current_username: previous_username,
+                    })
+                    .with_authentication()
+                    .send_to(origin);
+            }
+            return;
+        }
+
+        self.record_player(player_info, existing.is_some());
+    }
 
+    /// Store a player's profile on the Hub; `known` says whether `players`
+    /// already had the wallet. `player_count` moves only here, through
+    /// `player_count_after_sync`, and only a new wallet is counted and its
+    /// registration logged, so a repeated or reordered `SyncPlayer` (e.g.
+    /// an `UpdateUsername` arriving first) counts each wallet exactly once.
+    fn record_player(&mut self, player_info: PlayerInfo, known: bool) {
+        let wallet = player_info.wallet;
         self.state.players.insert(&wallet, player_info.clone())
-            .expect("Failed to sync player");
+            .expect("Failed to record player");
 
-        if is_new {
-            let count = *self.state.player_count.get() + 1;
-            self.state.player_count.set(count);
+        let count = player_count_after_sync(*self.state.player_count.get(), known);
+        self.state.player_count.set(count);
+        if known {
+            return;
+        }
 
-            let event = ArenaEvent::PlayerRegistered {
-                wallet,
-                discord_username: player_info.discord_username,
-            };
-            self.state.event_log.push(event);
-            let ec = *self.state.event_counter.get() + 1;
-            self.state.event_counter.set(ec);
+        let event = ArenaEvent::PlayerRegistered {
+            wallet,
+            discord_username: player_info.discord_username,
+            emitted_at_micros: self.now_micros(),
+        };
+        self.state.event_log.push(event);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+    }
+
+    async fn handle_sync_check_in(&mut self, wallet: AccountOwner) {
+        // Late check-ins (tournament already live or nothing scheduled) are ignored.
+        if self.state.scheduled_start_micros.get().is_none()
+            || self.state.get_active_tournament().is_some()
+        {
+            return;
+        }
+        let now = self.now_micros();
+        self.state.checked_in.insert(&wallet, now)
+            .expect("Failed to record check-in");
+    }
+
+    /// Record a heartbeat. A player chain's clock can't put the wallet in
+    /// the Hub's future, so the time is capped at the Hub's own.
+    fn record_heartbeat(&mut self, wallet: AccountOwner, timestamp_micros: u64) {
+        let seen = timestamp_micros.min(self.now_micros());
+        self.state.last_seen.insert(&wallet, seen)
+            .expect("Failed to record heartbeat");
+    }
+
+    async fn handle_username_rejected(
+        &mut self,
+        wallet: AccountOwner,
+        rejected_username: String,
+        current_username: Option<String>,
+    ) {
+        // Only the Hub holds the username registry.
+        let hub = self.hub_chain_id();
+        if self.runtime.message_origin_chain_id() != Some(hub) {
+            return;
         }
+
+        let local = self.state.players.get(&wallet).await.unwrap_or(None);
+        match roll_back_username(local, &rejected_username, current_username) {
+            Some(player) => {
+                self.state.players.insert(&wallet, player)
+                    .expect("Failed to restore player");
+            }
+            None => {
+                self.state.players.remove(&wallet)
+                    .expect("Failed to remove rejected player");
+            }
+        }
+        self.state.username_rejection.set(Some(rejected_username));
+    }
+
+    async fn handle_reset_game(&mut self, wallet: AccountOwner) {
+        // Only the Hub (where the admin check happened) may wipe a game.
+        let hub = self.hub_chain_id();
+        if self.runtime.message_origin_chain_id() != Some(hub) {
+            return;
+        }
+
+        match self.state.current_puzzle.get().clone() {
+            Some(puzzle) => {
+                let mut game = PlayerGameState::for_puzzle(&puzzle);
+                game.start_time_micros = self.now_micros();
+                self.state.player_games.insert(&wallet, game)
+                    .expect("Failed to reset player game");
+            }
+            None => {
+                self.state.player_games.remove(&wallet)
+                    .expect("Failed to remove player game");
+            }
+        }
+        self.state.penalty_events.remove(&wallet)
+            .expect("Failed to clear penalty timeline");
     }
 
     async fn handle_sync_cell_placement(
         &mut self,
         wallet: AccountOwner,
-        _row: u8, _col: u8, _value: u8, timestamp_micros: u64,
+        row: u8, col: u8, value: u8, timestamp_micros: u64,
         penalty_count: u32,
+        cells_remaining: u32,
     ) {
-        /// Minimum average seconds per move before a player is flagged.
-        const SUSPICIOUS_PACE_SECS: u64 = 6;
-
-        if let Some(mut tournament) = self.state.active_tournament.get().clone() {
-            if tournament.active {
-                // Compute estimated live score for in-progress players
-                let elapsed_secs = timestamp_micros.saturating_sub(tournament.start_time_micros) / 1_000_000;
-                let time_pen = elapsed_secs.saturating_mul(2);
-                let move_pen = (penalty_count as u64).saturating_mul(100);
-                let estimated_score = 10_000u64.saturating_sub(time_pen).saturating_sub(move_pen);
+        let placement = SyncedMove { row, col, value, timestamp_micros, penalty_count };
+        self.handle_sync_cell_batch(wallet, vec![placement], cells_remaining).await;
+    }
 
-                let has_entry = self.state.leaderboard.contains_key(&wallet).await.unwrap_or(false);
+    async fn handle_sync_cell_batch(
+        &mut self,
+        wallet: AccountOwner,
+        moves: Vec<SyncedMove>,
+        cells_remaining: u32,
+    ) {
+        /// Minimum interval between `LeaderboardUpdated` snapshots emitted
+        /// from in-progress placements (completions always emit).
+        const LEADERBOARD_BROADCAST_INTERVAL_MICROS: u64 = 10_000_000;
 
-                if !has_entry {
-                    let username = self.state.players.get(&wallet).await
-                        .unwrap_or(None)
-                        .map(|p| p.discord_username.clone())
-                        .unwrap_or_else(|| "Unknown".to_string());
+        let (Some(first), Some(last)) = (moves.first(), moves.last()) else {
+            return;
+        };
+        if let Some(mut tournament) = self.state.active_tournament.get().clone() {
+            if tournament.active && self.state.may_play(&tournament, &wallet).await {
+                let existing = self.state.leaderboard.get(&wallet).await.unwrap_or(None);
+                let finished = existing.as_ref().is_some_and(|e| e.completed || e.failed);
+                if tournament.box_bonus_enabled && !finished {
+                    self.claim_filled_boxes(wallet, &moves).await;
+                }
 
-                    let entry = LeaderboardEntry {
-                        wallet,
-                        discord_username: username,
-                        score: estimated_score,
-                        completion_time_micros: 0,
-                        penalty_count,
-                        move_count: 1,
-                        completed: false,
-                        first_move_time_micros: timestamp_micros,
-                        last_move_time_micros: timestamp_micros,
-                        is_suspicious: false,
-                    };
-                    self.state.leaderboard.insert(&wallet, entry)
-                        .expect("Failed to create leaderboard entry");
-
-                    tournament.total_players += 1;
-                    self.state.active_tournament.set(Some(tournament));
-                } else {
-                    if let Some(mut entry) = self.state.leaderboard.get(&wallet).await.unwrap_or(None) {
-                        if !entry.completed {
-                            entry.move_count += 1;
-                            entry.penalty_count = penalty_count;
-                            entry.score = estimated_score;
-                            entry.last_move_time_micros = timestamp_micros;
-
-                            // Detect suspicious pace: avg interval between moves
-                            if entry.move_count >= 5 && entry.first_move_time_micros > 0 {
-                                let solve_secs = timestamp_micros
-                                    .saturating_sub(entry.first_move_time_micros) / 1_000_000;
-                                // N moves → N-1 intervals
-                                let intervals = (entry.move_count - 1) as u64;
-                                let avg_pace = if intervals > 0 { solve_secs / intervals } else { u64::MAX };
-                                if avg_pace < SUSPICIOUS_PACE_SECS {
-                                    entry.is_suspicious = true;
-                                }
-                            }
-
-                            self.state.leaderboard.insert(&wallet, entry)
-                                .expect("Failed to update leaderboard entry");
-                        }
+                // Compute estimated live score for in-progress players
+                let blind = tournament.blind_mode;
+                let estimated_score =
+                    tournament.live_score(last.timestamp_micros, last.penalty_count)
+                    + tournament.box_bonus(self.state.boxes_won(&wallet).await);
+                let cells_filled = self.open_cells().saturating_sub(cells_remaining);
+
+                let entry = match existing {
+                    Some(entry) => Some(entry).filter(|e| !e.completed && !e.failed),
+                    None => {
+                        let username = self.state.players.get(&wallet).await
+                            .unwrap_or(None)
+                            .map(|p| p.discord_username.clone())
+                            .unwrap_or_else(|| "Unknown".to_string());
+                        self.state.record_participation(&wallet, tournament.id).await;
+
+                        tournament.total_players += 1;
+                        self.state.active_tournament.set(Some(tournament));
+                        let first_move = first.timestamp_micros;
+                        Some(LeaderboardEntry::in_progress(wallet, username, first_move))
                     }
+                };
+
+                // One write per batch, however many moves it carries
+                if let Some(mut entry) = entry {
+                    entry.record_placements(&moves, blind);
+                    entry.score = estimated_score;
+                    entry.cells_filled = cells_filled;
+                    entry.cells_remaining = cells_remaining;
+                    self.state.leaderboard.insert(&wallet, entry)
+                        .expect("Failed to update leaderboard entry");
+                }
+
+                // Throttled live snapshot so spectators see in-progress rank changes
+                let now = self.now_micros();
+                let last = *self.state.last_broadcast_micros.get();
+                if now.saturating_sub(last) >= LEADERBOARD_BROADCAST_INTERVAL_MICROS {
+                    self.state.last_broadcast_micros.set(now);
+                    let rules = self.state.active_tournament.get().clone().unwrap_or_default();
+                    let entries = self.state.get_sorted_leaderboard(rules.broadcast_size()).await;
+                    let event = rules.leaderboard_event(entries, now);
+                    self.emit_event(&event);
                 }
             }
         }
@@ -641,10 +2409,8 @@ impl FridayChainArenaContract {
         completion_time_micros: u64,
         penalty_count: u32,
         move_count: u32,
+        final_board: Vec<Vec<u8>>,
     ) {
-        /// Minimum average seconds per move before a player is flagged.
-        const SUSPICIOUS_PACE_SECS: u64 = 6;
-
         let username = self.state.players.get(&wallet).await
             .unwrap_or(None)
             .map(|p| p.discord_username.clone())
@@ -654,11 +2420,30 @@ impl FridayChainArenaContract {
             Some(t) => t,
             None => return,
         };
+        if !self.state.may_play(&tournament, &wallet).await {
+            return;
+        }
+        // The Hub's end time is authoritative: a player chain whose clock
+        // runs behind can't finish after the deadline.
+        if !tournament.accepts_completion(completion_time_micros, self.now_micros()) {
+            return;
+        }
+        // A modified player chain could skip `check_complete`, so the Hub
+        // re-checks the final board against the puzzle it generated itself.
+        let verified = self.state.current_puzzle.get().as_ref()
+            .filter(|puzzle| tournament.matches_puzzle(puzzle))
+            .is_some_and(|puzzle| sudoku::is_solved_by(puzzle, &final_board));
+        if !verified {
+            return;
+        }
+
+        // The verified board fills every box still unclaimed.
+        if tournament.box_bonus_enabled {
+            self.claim_boxes(wallet, [true; 9]).await;
+        }
 
-        let elapsed_secs = completion_time_micros.saturating_sub(tournament.start_time_micros) / 1_000_000;
-        let time_penalty = elapsed_secs.saturating_mul(2);
-        let move_pen = (penalty_count as u64).saturating_mul(100);
-        let score = 10_000u64.saturating_sub(time_penalty).saturating_sub(move_pen);
+        let score = tournament.score_at(completion_time_micros, penalty_count)
+            + tournament.box_bonus(self.state.boxes_won(&wallet).await);
 
         // Preserve first_move_time_micros and is_suspicious from the
         // in-progress entry (if one exists). Fall back to tournament start.
@@ -668,6 +2453,7 @@ impl FridayChainArenaContract {
             .filter(|&t| t > 0)
             .unwrap_or(tournament.start_time_micros);
         let mut suspicious = existing.as_ref().map(|e| e.is_suspicious).unwrap_or(false);
+        let move_sequence_hash = existing.as_ref().map(|e| e.move_sequence_hash).unwrap_or(0);
 
         // Final suspicious check using actual solve time (first move → completion)
         if move_count >= 5 {
@@ -679,6 +2465,18 @@ impl FridayChainArenaContract {
             }
         }
 
+        // Another finisher with the exact same move sequence: flag both.
+        if move_sequence_hash != 0 {
+            for mut twin in self.state.finishers_with_sequence(move_sequence_hash, &wallet).await {
+                suspicious = true;
+                if !twin.is_suspicious {
+                    twin.is_suspicious = true;
+                    self.state.leaderboard.insert(&twin.wallet, twin.clone())
+                        .expect("Failed to flag duplicate sequence");
+                }
+            }
+        }
+
         let entry = LeaderboardEntry {
             wallet,
             discord_username: username,
@@ -690,19 +2488,151 @@ impl FridayChainArenaContract {
             first_move_time_micros: first_move,
             last_move_time_micros: completion_time_micros,
             is_suspicious: suspicious,
+            cells_filled: self.open_cells(),
+            cells_remaining: 0,
+            move_sequence_hash,
+            failed: false,
         };
 
+        let counted_for = self.state.completion_counted_for.get(&wallet).await.unwrap_or(None);
+        let first_completion = is_first_completion(existing.as_ref(), counted_for, tournament.id);
+        if first_completion {
+            self.state.completion_counted_for.insert(&wallet, tournament.id)
+                .expect("Failed to record counted completion");
+        }
+
+        if let Some(season) = self.state.current_season.get().clone() {
+            let earned = season.points_for(first_completion, score);
+            if earned > 0 {
+                let points = self.state.season_points.get(&wallet).await
+                    .unwrap_or(None)
+                    .unwrap_or(0);
+                self.state.season_points.insert(&wallet, points.saturating_add(earned))
+                    .expect("Failed to update season points");
+            }
+        }
+
+        if first_completion {
+            let streak = self.state.completion_streaks.get(&wallet).await
+                .unwrap_or(None)
+                .unwrap_or(0);
+            self.state.completion_streaks.insert(&wallet, streak.saturating_add(1))
+                .expect("Failed to update completion streak");
+        }
+
         self.state.leaderboard.insert(&wallet, entry.clone())
             .expect("Failed to update leaderboard");
-        self.state.leaderboard_log.push(entry);
+        self.state.leaderboard_log.push(entry.clone());
+        self.state.record_participation(&wallet, tournament.id).await;
 
         let mut tournament = tournament;
         tournament.total_completions += 1;
-        self.state.active_tournament.set(Some(tournament));
+        self.state.active_tournament.set(Some(tournament.clone()));
 
-        let entries = self.state.get_sorted_leaderboard(50).await;
-        let event = ArenaEvent::LeaderboardUpdated { entries };
-        self.runtime.emit(StreamName(TOURNAMENT_STREAM.to_vec()), &event);
+        let now = self.now_micros();
+        let entries = self.state.get_sorted_leaderboard(u32::MAX).await;
+
+        let completed = ArenaEvent::PlayerCompleted {
+            wallet,
+            discord_username: entry.discord_username.clone(),
+            score,
+            rank: completion_rank(&entries, &wallet).unwrap_or(0),
+            emitted_at_micros: now,
+        };
+        self.emit_event(&completed);
+        self.state.event_log.push(completed);
+        let ec = *self.state.event_counter.get() + 1;
+        self.state.event_counter.set(ec);
+
+        self.state.last_broadcast_micros.set(now);
+        let event = tournament.leaderboard_event(entries, now);
+        self.emit_event(&event);
+    }
+
+    /// Fold `moves` into the Hub's record of `wallet`'s correct cells and
+    /// claim the boxes they now fill. Derived from the Hub's own solution,
+    /// so a player chain can't claim a box it hasn't filled.
+    async fn claim_filled_boxes(&mut self, wallet: AccountOwner, moves: &[SyncedMove]) {
+        let Some(puzzle) = self.state.current_puzzle.get().clone() else {
+            return;
+        };
+        let correct = self.state.correct_cells.get(&wallet).await.unwrap_or(None).unwrap_or(0);
+        let correct = puzzle.mark_correct(correct, moves);
+        self.state.correct_cells.insert(&wallet, correct)
+            .expect("Failed to record correct cells");
+        self.claim_boxes(wallet, puzzle.filled_boxes(correct)).await;
+    }
+
+    /// Record `wallet` as the first to fill each `filled` box nobody has
+    /// filled yet. The bonus is added to the player's score by the
+    /// placement or completion sync that called this.
+    async fn claim_boxes(&mut self, wallet: AccountOwner, filled: [bool; 9]) {
+        for box_index in (0..9u8).filter(|&b| filled[b as usize]) {
+            if !self.state.box_first_completers.contains_key(&box_index).await.unwrap_or(true) {
+                self.state.box_first_completers.insert(&box_index, wallet)
+                    .expect("Failed to record box completion");
+            }
+        }
+    }
+
+    async fn handle_sync_game_over(
+        &mut self,
+        wallet: AccountOwner,
+        failed_at_micros: u64,
+        penalty_count: u32,
+        move_count: u32,
+    ) {
+        let Some(tournament) = self.state.get_active_tournament().cloned() else {
+            return;
+        };
+        if !self.state.may_play(&tournament, &wallet).await {
+            return;
+        }
+
+        let existing = self.state.leaderboard.get(&wallet).await.unwrap_or(None);
+        if existing.as_ref().is_some_and(|e| e.completed) {
+            return;
+        }
+        let is_new = existing.is_none();
+        let mut entry = match existing {
+            Some(entry) => entry,
+            None => {
+                let username = self.state.players.get(&wallet).await
+                    .unwrap_or(None)
+                    .map(|p| p.discord_username.clone())
+                    .unwrap_or_else(|| "Unknown".to_string());
+                LeaderboardEntry {
+                    wallet,
+                    discord_username: username,
+                    score: 0,
+                    completion_time_micros: 0,
+                    penalty_count,
+                    move_count,
+                    completed: false,
+                    first_move_time_micros: failed_at_micros,
+                    last_move_time_micros: failed_at_micros,
+                    is_suspicious: false,
+                    cells_filled: 0,
+                    cells_remaining: self.open_cells(),
+                    move_sequence_hash: 0,
+                    failed: false,
+                }
+            }
+        };
+        entry.failed = true;
+        entry.score = 0;
+        entry.penalty_count = penalty_count;
+        entry.move_count = move_count;
+        entry.last_move_time_micros = failed_at_micros;
+        self.state.leaderboard.insert(&wallet, entry)
+            .expect("Failed to record game over");
+
+        if is_new {
+            self.state.record_participation(&wallet, tournament.id).await;
+            let mut tournament = tournament;
+            tournament.total_players += 1;
+            self.state.active_tournament.set(Some(tournament));
+        }
     }
 
     async fn handle_leaderboard_request(&mut self, requester_chain: ChainId, limit: u32) {
@@ -729,27 +2659,72 @@ impl FridayChainArenaContract {
         self.state.cached_leaderboard.set(Some(CachedLeaderboard {
             entries, tournament_id, is_active, fetched_at_micros: now,
         }));
+        self.state.leaderboard_request_pending.set(None);
+    }
+
+    async fn handle_full_state_request(&mut self, requester_chain: ChainId) {
+        let tournament = self.state.active_tournament.get().clone();
+        let entries = self.state.get_sorted_leaderboard(50).await;
+
+        self.runtime
+            .prepare_message(Message::FullStateResponse { tournament, entries })
+            .with_authentication()
+            .send_to(requester_chain);
     }
 
-    async fn handle_tournament_started_msg(
+    /// Adopt the Hub's view wholesale. Local boards survive only if the Hub
+    /// is still on the tournament this chain already knew about.
+    async fn handle_full_state_response(
         &mut self,
-        tournament_id: u64, seed: u64,
-        start_time_micros: u64, end_time_micros: u64,
+        tournament: Option<Tournament>,
+        entries: Vec<LeaderboardEntry>,
     ) {
-        let tournament = Tournament {
-            id: tournament_id, seed,
-            start_time_micros, end_time_micros,
-            active: true,
-            total_players: 0,
-            total_completions: 0,
-        };
-        self.state.active_tournament.set(Some(tournament));
+        // Anyone can send this message; only the Hub's view is adopted.
+        let hub = self.hub_chain_id();
+        if self.runtime.message_origin_chain_id() != Some(hub) {
+            return;
+        }
+
+        let (tournament_id, is_active) = tournament.as_ref()
+            .map(|t| (t.id, t.active)).unwrap_or((0, false));
+
+        if let Some(tournament) = tournament {
+            let known = self.state.active_tournament.get()
+                .as_ref().map(|t| t.id) == Some(tournament.id);
+            if !known || self.state.current_puzzle.get().is_none() {
+                let puzzle = tournament.generate_puzzle()
+                    .expect("Failed to generate puzzle from seed");
+                self.state.current_puzzle.set(Some(puzzle));
+            }
+            if !known {
+                self.state.scheduled_start_micros.set(None);
+                self.state.player_games.clear();
+                self.state.penalty_events.clear();
+            }
+            self.state.active_tournament.set(Some(tournament));
+        }
+
+        let now = self.now_micros();
+        self.state.cached_leaderboard.set(Some(CachedLeaderboard {
+            entries, tournament_id, is_active, fetched_at_micros: now,
+        }));
+        self.state.leaderboard_request_pending.set(None);
+    }
 
-        let puzzle = sudoku::generate_puzzle(seed).expect("Failed to generate puzzle from seed");
+    /// `tournament` carries the Hub's `solution_commitment` and
+    /// `unique_solution`, so only the puzzle is regenerated here; one that
+    /// doesn't match the commitment is refused at play time.
+    async fn handle_tournament_started_msg(&mut self, mut tournament: Tournament) {
+        let puzzle = tournament.generate_puzzle()
+            .expect("Failed to generate puzzle from seed");
+        tournament.active = true;
         self.state.current_puzzle.set(Some(puzzle));
+        self.state.active_tournament.set(Some(tournament));
 
-        // Clear previous game states
+        // Clear previous game states and the lobby
+        self.state.scheduled_start_micros.set(None);
         self.state.player_games.clear();
+        self.state.penalty_events.clear();
         self.state.cached_leaderboard.set(None);
     }
 
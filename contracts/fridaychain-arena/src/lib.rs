@@ -7,7 +7,7 @@
 
 #![allow(clippy::large_enum_variant)]
 
-use async_graphql::{InputObject, SimpleObject, Union};
+use async_graphql::{ComplexObject, Enum, InputObject, SimpleObject, Union};
 use linera_sdk::{
     linera_base_types::{AccountOwner, ChainId},
     graphql::GraphQLMutationRoot,
@@ -15,8 +15,14 @@ use linera_sdk::{
 use serde::{Deserialize, Serialize};
 use linera_sdk::linera_base_types::{ContractAbi, ServiceAbi};
 
+mod compact;
+mod migration;
 pub mod sudoku;
 
+/// Layout version of `ArenaState`, bumped whenever stored types change in a
+/// way old blobs can't decode. See `migration` for the history.
+pub const STATE_SCHEMA_VERSION: u32 = 8;
+
 /// The Application Binary Interface marker.
 pub struct FridayChainArenaAbi;
 
@@ -25,8 +31,87 @@ pub struct FridayChainArenaAbi;
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ArenaParameters {
     pub hub_chain_id: ChainId,
+    /// Minimum time between two placements by the same player.
+    /// Defaults to `DEFAULT_MIN_MOVE_INTERVAL_MICROS` when omitted.
+    #[serde(default)]
+    pub min_move_interval_micros: Option<u64>,
+}
+
+/// Public identity of an arena deployment, as seen from one chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct ArenaConfig {
+    pub hub_chain_id: ChainId,
+    /// The admin set at instantiation. Only the Hub chain stores it, so
+    /// this is `None` on player chains.
+    pub admin_owner: Option<AccountOwner>,
+    /// Whether the queried chain is the Hub.
+    pub is_hub: bool,
+}
+
+impl ArenaConfig {
+    pub fn new(
+        parameters: &ArenaParameters,
+        admin_owner: Option<AccountOwner>,
+        chain_id: ChainId,
+    ) -> Self {
+        ArenaConfig {
+            hub_chain_id: parameters.hub_chain_id,
+            admin_owner,
+            is_hub: chain_id == parameters.hub_chain_id,
+        }
+    }
+}
+
+/// What this deployment supports, so one frontend can adapt to builds and
+/// configurations with different feature sets. Fields are only ever added.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct Capabilities {
+    /// The contract crate's version.
+    pub contract_version: String,
+    /// The schema version of the queried chain's stored state.
+    pub schema_version: u32,
+    /// Version of the `StreamEvent` envelope this build emits.
+    pub event_version: u32,
+    pub variants: Vec<SudokuVariant>,
+    pub scoring_modes: Vec<ScoringMode>,
+    pub board_sizes: Vec<BoardSize>,
+    pub teams: bool,
+    pub seasons: bool,
+    pub bulk_submit: bool,
+    pub box_bonus: bool,
+    /// Whether a weekly auto-start schedule is configured (Hub).
+    pub weekly_schedule_enabled: bool,
+    /// Whether a season is running (Hub).
+    pub season_active: bool,
+}
+
+impl Capabilities {
+    pub fn new(schema_version: u32, weekly_schedule_enabled: bool, season_active: bool) -> Self {
+        Capabilities {
+            contract_version: env!("CARGO_PKG_VERSION").to_string(),
+            schema_version,
+            event_version: EVENT_VERSION,
+            variants: vec![
+                SudokuVariant::Classic,
+                SudokuVariant::XSudoku,
+                SudokuVariant::HyperSudoku,
+            ],
+            scoring_modes: vec![ScoringMode::Balanced, ScoringMode::Speedrun, ScoringMode::Accuracy],
+            board_sizes: vec![BoardSize::Nine, BoardSize::Six, BoardSize::Four],
+            teams: true,
+            seasons: true,
+            bulk_submit: true,
+            box_bonus: true,
+            weekly_schedule_enabled,
+            season_active,
+        }
+    }
 }
 
+/// Default placement throttle (200 ms). Well below human speed, so it only
+/// stops scripts that hammer `PlaceCell`.
+pub const DEFAULT_MIN_MOVE_INTERVAL_MICROS: u64 = 200_000;
+
 impl ContractAbi for FridayChainArenaAbi {
     type Operation = Operation;
     type Response = ArenaResponse;
@@ -62,6 +147,8 @@ pub enum Operation {
     /// Links the authenticated MetaMask signer to the username on-chain.
     RegisterPlayer {
         discord_username: String,
+        /// Optional team/guild to compete for (same rules as usernames).
+        team: Option<String>,
     },
 
     /// Update the Discord username (costs an on-chain transaction).
@@ -85,29 +172,230 @@ pub enum Operation {
         col: u8,
     },
 
+    /// Clear up to `MAX_CLEAR_CELLS` non-given cells at once ("erase
+    /// region"). Values are ignored; givens and empty cells are skipped and
+    /// reported per cell.
+    ClearCells {
+        cells: Vec<MoveInput>,
+    },
+
+    /// Submit a finished 9×9 board in one transaction, for clients that
+    /// solve locally. Only allowed when the tournament enables bulk submit.
+    /// Wrong cells in every rejected submission count as penalties.
+    SubmitBoard {
+        board: Vec<Vec<u8>>,
+    },
+
+    /// Check in for the scheduled tournament (lobby phase).
+    /// Only valid after `ScheduleTournament` and before the tournament starts.
+    CheckIn,
+
+    /// Tell the Hub this player's chain is live. Independent of gameplay;
+    /// clients may send it periodically (see `onlinePlayers`).
+    Heartbeat,
+
     // ── Cross-chain ──────────────────────────────────────────────────────
 
     /// Subscribe this player's chain to the Hub's tournament event stream.
     SubscribeToHub,
 
+    /// Stop receiving the Hub's tournament event stream.
+    UnsubscribeFromHub,
+
     /// Request the current leaderboard from the Hub chain.
     /// Result is delivered asynchronously via cross-chain message.
     RequestLeaderboard {
         limit: Option<u32>,
     },
 
+    /// Recover a player chain that fell behind on the event stream: fetch
+    /// the Hub's tournament and leaderboard in one round trip.
+    ResyncFromHub,
+
+    /// Regenerate the active tournament's puzzle locally from its seed,
+    /// for a chain that missed `TournamentStarted`. Needs no Hub round trip.
+    LoadPuzzle,
+
     // ── Admin (Hub chain only) ───────────────────────────────────────────
 
+    /// Announce an upcoming tournament and open check-in. Admin only.
+    /// The tournament itself still begins with `StartTournament`.
+    ScheduleTournament {
+        start_time_micros: u64,
+    },
+
     /// Start a new tournament. Admin only.
-    /// `seed` determines the Sudoku puzzle deterministically.
+    /// `seed` determines the Sudoku puzzle deterministically and may not
+    /// repeat an earlier tournament's seed.
     /// `duration_secs` is the tournament length (typically 3600 for 1 hour).
     StartTournament {
         seed: u64,
         duration_secs: u64,
+        /// Puzzle rules; defaults to `Classic` when omitted.
+        variant: Option<SudokuVariant>,
+        /// Limit play to allowlisted wallets (see `AllowPlayer`).
+        restricted: Option<bool>,
+        /// Scoring preset; defaults to `Balanced`.
+        scoring_mode: Option<ScoringMode>,
+        /// Accept whole-board `SubmitBoard` operations; defaults to false.
+        allow_bulk_submit: Option<bool>,
+        /// Invalid placements each player may make before penalties start;
+        /// defaults to 0.
+        free_mistakes: Option<u32>,
+        /// Penalties that end a player's game ("three strikes"); unlimited
+        /// when omitted.
+        max_penalties: Option<u32>,
+        /// Refuse to start until at least this many players have registered
+        /// with the Hub.
+        min_players: Option<u32>,
+        /// Entries carried by live `LeaderboardUpdated` broadcasts; defaults
+        /// to 50, capped at `MAX_BROADCAST_TOP_N`.
+        broadcast_top_n: Option<u32>,
+        /// Award `BOX_BONUS_POINTS` to the first player to correctly fill
+        /// each 3×3 box; defaults to false. 9×9 boards only.
+        box_bonus: Option<bool>,
+        /// Grid size; defaults to 9×9. Mini boards are `Classic` only.
+        board_size: Option<BoardSize>,
+        /// Prize for each finishing place (winner first), claimable with
+        /// `ClaimReward` once the tournament ends. At most
+        /// `MAX_PRIZE_PLACES`; defaults to none.
+        prizes: Option<Vec<u64>>,
+        /// Hide whether placements are valid until the board is done;
+        /// penalties still count. Defaults to false.
+        blind_mode: Option<bool>,
+    },
+
+    /// Start a Classic, unrestricted tournament with a seed derived from
+    /// the Hub's block time and tournament counter. Admin only. The seed is
+    /// returned and stored on the `Tournament`, so the puzzle still replays
+    /// deterministically.
+    StartTournamentAuto {
+        duration_secs: u64,
     },
 
     /// End the current tournament and finalize rankings. Admin only.
     EndTournament,
+
+    /// Freeze the tournament clock (e.g. during a broadcast outage). Moves
+    /// are rejected and paused time doesn't count towards anyone's score.
+    /// Admin only.
+    PauseTournament,
+
+    /// Restart a paused tournament clock, pushing the end time back by the
+    /// length of the pause. Admin only.
+    ResumeTournament,
+
+    /// Add a wallet to the allowlist for restricted tournaments. Admin only.
+    AllowPlayer {
+        wallet: AccountOwner,
+    },
+
+    /// Start a tournament automatically every week. Admin only.
+    /// Activation is lazy: contracts can't wake themselves, so the Hub starts
+    /// the tournament on the first operation or message it executes after the
+    /// slot begins (and before the slot's `duration_secs` have passed).
+    EnableWeeklySchedule {
+        weekday: Weekday,
+        hour_utc: u8,
+        duration_secs: u64,
+        seed_strategy: SeedStrategy,
+    },
+
+    /// Stop weekly auto-starts. Admin only.
+    DisableWeeklySchedule,
+
+    /// Wipe one player's progress in the current tournament. Admin only.
+    /// The Hub drops the player's leaderboard entry and sends `ResetGame` to
+    /// the chain the player syncs from, which restarts their board from the
+    /// puzzle.
+    ResetPlayerGame {
+        wallet: AccountOwner,
+    },
+
+    /// Override a player's leaderboard score to settle a dispute. Admin only.
+    /// Every override is kept in the public `scoreAdjustments` audit log.
+    SetPlayerScore {
+        wallet: AccountOwner,
+        score: u64,
+        reason: String,
+    },
+
+    /// Hand the admin role to `new_admin`. Admin only; the caller loses
+    /// admin rights immediately.
+    TransferAdmin {
+        new_admin: AccountOwner,
+    },
+
+    /// Wipe players, tournaments, leaderboards, and logs for a clean slate
+    /// (test networks, new seasons). Admin only, and `confirm` must equal
+    /// `RESET_ARENA_CONFIRMATION`. Irreversible: nothing cleared can be
+    /// recovered. Parameters, the admin, and the weekly schedule are kept.
+    ResetArena {
+        confirm: String,
+    },
+
+    /// Open a season. Admin only. Until `EndSeason`, every completion adds
+    /// the player's tournament score to their season points; with
+    /// `placement_points` (e.g. `F1_PLACEMENT_POINTS`), finishers are instead
+    /// awarded `placement_points[rank - 1]` when each tournament ends.
+    StartSeason {
+        placement_points: Option<Vec<u64>>,
+    },
+
+    /// Close the current season, freezing its standings. Admin only.
+    EndSeason,
+
+    /// Check a puzzle string (81 cells, `0` or `.` for blanks) for a unique
+    /// solution and rate it, without starting a tournament. Admin only.
+    ValidatePuzzleString {
+        puzzle_string: String,
+        variant: Option<SudokuVariant>,
+    },
+
+    /// Record how long the player took on the daily puzzle for `date_ymd`
+    /// (`YYYYMMDD`, see the `dailyPuzzle` query). Stored on the player's own
+    /// chain, keeping the best time; not ranked or checked.
+    SubmitDailyResult {
+        date_ymd: u32,
+        solve_time_micros: u64,
+    },
+
+    /// Point out an empty cell with only one legal value left on the
+    /// player's board (a naked single), worked out from the rules alone —
+    /// never the solution. The value is included only with `reveal_value`.
+    /// Costs no penalty; counted in `logic_hints_used`. Only while the
+    /// player may place cells in the active tournament.
+    RequestLogicHint {
+        reveal_value: bool,
+    },
+
+    /// Store a frontend preferences blob (e.g. JSON with theme and
+    /// highlight style) on the player's profile, synced to the Hub like
+    /// username changes. An empty string clears it.
+    SetPreferences {
+        preferences: String,
+    },
+
+    /// Claim the prize for the caller's place in an ended tournament (Hub
+    /// only). Records the claim; paying it out happens off-chain.
+    ClaimReward {
+        tournament_id: u64,
+    },
+
+    /// Broadcast a short message (e.g. "10 minutes left!") to every
+    /// subscribed player chain. Admin only, Hub only; at most
+    /// `MAX_ANNOUNCEMENT_LEN` bytes.
+    Announce {
+        text: String,
+    },
+
+    /// Sync placements to the Hub in batches of `moves` (1-`MAX_SYNC_BATCH`)
+    /// instead of one message per move. Pending moves are always flushed
+    /// when the game completes or fails; until then the Hub's live entry
+    /// lags by up to `moves - 1` placements.
+    SetSyncBatchSize {
+        moves: u32,
+    },
 }
 
 // ---------------------------------------------------------------------------
@@ -118,6 +406,11 @@ pub enum Operation {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Message {
     // ── Player chain → Hub ───────────────────────────────────────────────
+    //
+    // Sent with authentication, so they carry the signer of the operation
+    // that produced them. The Hub only accepts one about `wallet` if that
+    // wallet signed it and it comes from the wallet's home chain (see
+    // `is_authentic_sync`).
 
     /// Sync a player registration to the Hub.
     SyncPlayer(PlayerInfo),
@@ -130,6 +423,8 @@ pub enum Message {
         value: u8,
         timestamp_micros: u64,
         penalty_count: u32,
+        /// Non-given cells still empty after this move.
+        cells_remaining: u32,
     },
 
     /// Notify the Hub that a player completed the board.
@@ -138,6 +433,49 @@ pub enum Message {
         completion_time_micros: u64,
         penalty_count: u32,
         move_count: u32,
+        /// The finished board, which the Hub checks against its own copy
+        /// of the puzzle rather than trusting the player chain.
+        #[serde(with = "compact::board")]
+        final_board: Vec<Vec<u8>>,
+    },
+
+    /// Notify the Hub that a player's game ended by reaching the
+    /// tournament's `max_penalties`.
+    SyncGameOver {
+        wallet: AccountOwner,
+        failed_at_micros: u64,
+        penalty_count: u32,
+        move_count: u32,
+    },
+
+    /// Notify the Hub that a player checked in for the scheduled tournament.
+    SyncCheckIn {
+        wallet: AccountOwner,
+    },
+
+    /// Liveness ping from a player's chain (`Heartbeat`).
+    Heartbeat {
+        wallet: AccountOwner,
+        timestamp_micros: u64,
+    },
+
+    // ── Hub → player chain ───────────────────────────────────────────────
+
+    /// The Hub refused a `SyncPlayer` because the username is held by another
+    /// wallet. The player chain restores `current_username` (or drops the
+    /// local registration if the Hub has never seen this wallet). Only
+    /// honoured when it comes from the Hub chain.
+    UsernameRejected {
+        wallet: AccountOwner,
+        rejected_username: String,
+        current_username: Option<String>,
+    },
+
+    /// Admin-ordered reset of a player's game. Only honoured when it comes
+    /// from the Hub chain; the player chain restarts the board from the
+    /// current puzzle and forgets the player's penalties.
+    ResetGame {
+        wallet: AccountOwner,
     },
 
     // ── Leaderboard cross-chain ──────────────────────────────────────────
@@ -155,14 +493,40 @@ pub enum Message {
         is_active: bool,
     },
 
+    /// Ask the Hub for its tournament descriptor and leaderboard
+    /// (`ResyncFromHub`).
+    FullStateRequest {
+        requester_chain: ChainId,
+    },
+
+    /// Hub's answer to `FullStateRequest`. The player chain replaces its
+    /// tournament, puzzle (regenerated from the seed), and cached
+    /// leaderboard in a single message execution.
+    FullStateResponse {
+        tournament: Option<Tournament>,
+        entries: Vec<LeaderboardEntry>,
+    },
+
     // ── Hub → player chains (via event stream subscription) ──────────────
 
     /// Broadcast: a tournament has started.
     TournamentStarted {
         tournament_id: u64,
         seed: u64,
+        variant: SudokuVariant,
         start_time_micros: u64,
         end_time_micros: u64,
+        restricted: bool,
+        scoring_mode: ScoringMode,
+        allow_bulk_submit: bool,
+        free_mistakes: u32,
+        max_penalties: Option<u32>,
+        box_bonus_enabled: bool,
+        board_size: BoardSize,
+        prizes: Vec<u64>,
+        blind_mode: bool,
+        solution_commitment: [u8; 32],
+        unique_solution: bool,
     },
 
     /// Broadcast: a tournament has ended.
@@ -170,41 +534,427 @@ pub enum Message {
         tournament_id: u64,
         final_rankings: Vec<LeaderboardEntry>,
     },
+
+    // ── Player chain → Hub (appended) ────────────────────────────────────
+
+    /// Several placements at once, in order (see `SetSyncBatchSize`). The
+    /// Hub applies them as consecutive `SyncCellPlacement`s but writes the
+    /// leaderboard entry once.
+    SyncCellBatch {
+        wallet: AccountOwner,
+        moves: Vec<SyncedMove>,
+        /// Non-given cells still empty after the last move.
+        cells_remaining: u32,
+    },
 }
 
 // ---------------------------------------------------------------------------
 // Event Values (emitted on streams for subscriber chains)
 // ---------------------------------------------------------------------------
 
-/// Events emitted on the Hub's "tournament" stream.
+/// Events emitted on the Hub's "tournament" stream, wrapped in a
+/// `StreamEvent`.
+///
+/// BCS encodes a variant by its index, so new variants go at the end.
+/// Changing an existing variant's fields bumps `EVENT_VERSION` once a
+/// release has shipped the old layout.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ArenaEvent {
     /// A tournament has started.
     TournamentStarted {
         tournament_id: u64,
         seed: u64,
+        variant: SudokuVariant,
         start_time_micros: u64,
         end_time_micros: u64,
+        restricted: bool,
+        scoring_mode: ScoringMode,
+        allow_bulk_submit: bool,
+        free_mistakes: u32,
+        max_penalties: Option<u32>,
+        box_bonus_enabled: bool,
+        board_size: BoardSize,
+        prizes: Vec<u64>,
+        blind_mode: bool,
+        /// The Hub's `Tournament::solution_commitment` and `unique_solution`,
+        /// so player chains needn't recount the puzzle's solutions.
+        solution_commitment: [u8; 32],
+        unique_solution: bool,
+        emitted_at_micros: u64,
     },
 
     /// A tournament has ended with final rankings.
     TournamentEnded {
         tournament_id: u64,
         final_rankings: Vec<LeaderboardEntry>,
+        emitted_at_micros: u64,
     },
 
     /// A player registered.
     PlayerRegistered {
         wallet: AccountOwner,
         discord_username: String,
+        emitted_at_micros: u64,
     },
 
-    /// Leaderboard updated (emitted after each board completion).
+    /// Leaderboard updated (after each board completion, and throttled
+    /// during play).
     LeaderboardUpdated {
         entries: Vec<LeaderboardEntry>,
+        emitted_at_micros: u64,
+    },
+
+    /// An upcoming tournament was announced; check-in is open.
+    TournamentScheduled {
+        start_time_micros: u64,
+        emitted_at_micros: u64,
+    },
+
+    /// A player finished the board; `rank` is their position among players
+    /// who had completed at that moment (1 = first).
+    PlayerCompleted {
+        wallet: AccountOwner,
+        discord_username: String,
+        score: u64,
+        rank: u32,
+        emitted_at_micros: u64,
+    },
+
+    /// A wallet was added to the restricted-tournament allowlist.
+    PlayerAllowed {
+        wallet: AccountOwner,
+        emitted_at_micros: u64,
+    },
+
+    /// The admin role was transferred.
+    AdminChanged {
+        old: AccountOwner,
+        new: AccountOwner,
+        emitted_at_micros: u64,
+    },
+
+    /// The tournament clock was frozen at `paused_at_micros`.
+    TournamentPaused {
+        tournament_id: u64,
+        paused_at_micros: u64,
+        emitted_at_micros: u64,
+    },
+
+    /// The tournament clock restarted. `paused_micros` is the total paused
+    /// so far and `end_time_micros` the end time it pushed back to.
+    TournamentResumed {
+        tournament_id: u64,
+        paused_micros: u64,
+        end_time_micros: u64,
+        emitted_at_micros: u64,
+    },
+
+    /// The admin wiped the arena (`ResetArena`); subscribers drop their
+    /// tournament and local games too.
+    ArenaReset {
+        emitted_at_micros: u64,
+    },
+
+    /// An organizer message from `Announce`.
+    Announcement {
+        text: String,
+        emitted_at_micros: u64,
+    },
+}
+
+impl ArenaEvent {
+    /// Hub time (micros) at which the event was emitted.
+    pub fn emitted_at_micros(&self) -> u64 {
+        match self {
+            ArenaEvent::TournamentStarted { emitted_at_micros, .. }
+            | ArenaEvent::TournamentEnded { emitted_at_micros, .. }
+            | ArenaEvent::PlayerRegistered { emitted_at_micros, .. }
+            | ArenaEvent::LeaderboardUpdated { emitted_at_micros, .. }
+            | ArenaEvent::TournamentScheduled { emitted_at_micros, .. }
+            | ArenaEvent::PlayerCompleted { emitted_at_micros, .. }
+            | ArenaEvent::PlayerAllowed { emitted_at_micros, .. }
+            | ArenaEvent::AdminChanged { emitted_at_micros, .. }
+            | ArenaEvent::TournamentPaused { emitted_at_micros, .. }
+            | ArenaEvent::TournamentResumed { emitted_at_micros, .. }
+            | ArenaEvent::ArenaReset { emitted_at_micros }
+            | ArenaEvent::Announcement { emitted_at_micros, .. } => *emitted_at_micros,
+        }
+    }
+}
+
+/// Encoding version of the `ArenaEvent`s this build emits. Subscribers skip
+/// events with a newer version rather than misread them. A bump must teach
+/// `StreamEvent::decode` the versions before it, not drop them.
+pub const EVENT_VERSION: u32 = 1;
+
+/// What actually goes on the stream. This build emits `Versioned`: an
+/// `ArenaEvent`, BCS-encoded behind a length prefix, so a subscriber built
+/// before a variant existed can still read the entry and skip the event,
+/// instead of panicking in `read_event`.
+///
+/// The original contract emitted bare `ArenaEvent`s. The `Legacy` variants
+/// are its four events, at their indices and in their layout, so the
+/// stream entries it left behind still decode.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum StreamEvent {
+    LegacyTournamentStarted {
+        tournament_id: u64,
+        seed: u64,
+        start_time_micros: u64,
+        end_time_micros: u64,
+    },
+    LegacyTournamentEnded {
+        tournament_id: u64,
+        final_rankings: Vec<LegacyLeaderboardEntry>,
+    },
+    LegacyPlayerRegistered {
+        wallet: AccountOwner,
+        discord_username: String,
+    },
+    LegacyLeaderboardUpdated {
+        entries: Vec<LegacyLeaderboardEntry>,
+    },
+    Versioned {
+        version: u32,
+        payload: Vec<u8>,
     },
 }
 
+/// A `LeaderboardEntry` as the original contract's stream events carried it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LegacyLeaderboardEntry {
+    pub wallet: AccountOwner,
+    pub discord_username: String,
+    pub score: u64,
+    pub completion_time_micros: u64,
+    pub penalty_count: u32,
+    pub move_count: u32,
+    pub completed: bool,
+    pub first_move_time_micros: u64,
+    pub last_move_time_micros: u64,
+    pub is_suspicious: bool,
+}
+
+impl From<LegacyLeaderboardEntry> for LeaderboardEntry {
+    fn from(e: LegacyLeaderboardEntry) -> Self {
+        LeaderboardEntry {
+            wallet: e.wallet,
+            discord_username: e.discord_username,
+            score: e.score,
+            completion_time_micros: e.completion_time_micros,
+            penalty_count: e.penalty_count,
+            move_count: e.move_count,
+            completed: e.completed,
+            first_move_time_micros: e.first_move_time_micros,
+            last_move_time_micros: e.last_move_time_micros,
+            is_suspicious: e.is_suspicious,
+            cells_filled: 0,
+            cells_remaining: 0,
+            move_sequence_hash: 0,
+            failed: false,
+        }
+    }
+}
+
+impl StreamEvent {
+    pub fn new(event: &ArenaEvent) -> Self {
+        StreamEvent::Versioned {
+            version: EVENT_VERSION,
+            payload: linera_sdk::bcs::to_bytes(event).expect("Failed to encode event"),
+        }
+    }
+
+    /// The event this entry carries, or `None` if it comes from a newer
+    /// contract: a newer `version`, an unknown variant, or fields this
+    /// build lacks. Legacy events get the fields added since at their
+    /// defaults (a `Tournament::default()` rule set, `emitted_at_micros` 0).
+    pub fn decode(&self) -> Option<ArenaEvent> {
+        let legacy = |entries: &[LegacyLeaderboardEntry]| {
+            entries.iter().cloned().map(LeaderboardEntry::from).collect()
+        };
+        match self {
+            // Version 1 is the only envelope layout so far.
+            StreamEvent::Versioned { version, payload } => (*version == EVENT_VERSION)
+                .then(|| linera_sdk::bcs::from_bytes(payload).ok())
+                .flatten(),
+            StreamEvent::LegacyTournamentStarted {
+                tournament_id, seed, start_time_micros, end_time_micros,
+            } => {
+                let rules = Tournament::default();
+                Some(ArenaEvent::TournamentStarted {
+                    tournament_id: *tournament_id,
+                    seed: *seed,
+                    variant: rules.variant,
+                    start_time_micros: *start_time_micros,
+                    end_time_micros: *end_time_micros,
+                    restricted: rules.restricted,
+                    scoring_mode: rules.scoring_mode,
+                    allow_bulk_submit: rules.allow_bulk_submit,
+                    free_mistakes: rules.free_mistakes,
+                    max_penalties: rules.max_penalties,
+                    box_bonus_enabled: rules.box_bonus_enabled,
+                    board_size: rules.board_size,
+                    prizes: rules.prizes,
+                    blind_mode: rules.blind_mode,
+                    solution_commitment: rules.solution_commitment,
+                    unique_solution: rules.unique_solution,
+                    emitted_at_micros: 0,
+                })
+            }
+            StreamEvent::LegacyTournamentEnded { tournament_id, final_rankings } => {
+                Some(ArenaEvent::TournamentEnded {
+                    tournament_id: *tournament_id,
+                    final_rankings: legacy(final_rankings),
+                    emitted_at_micros: 0,
+                })
+            }
+            StreamEvent::LegacyPlayerRegistered { wallet, discord_username } => {
+                Some(ArenaEvent::PlayerRegistered {
+                    wallet: *wallet,
+                    discord_username: discord_username.clone(),
+                    emitted_at_micros: 0,
+                })
+            }
+            StreamEvent::LegacyLeaderboardUpdated { entries } => {
+                Some(ArenaEvent::LeaderboardUpdated {
+                    entries: legacy(entries),
+                    emitted_at_micros: 0,
+                })
+            }
+        }
+    }
+}
+
+/// Most stream events a player chain keeps in its notification inbox;
+/// older ones are dropped first.
+pub const MAX_NOTIFICATIONS: usize = 200;
+
+/// Positions in the notification inbox to return for `notifications(since)`.
+/// The inbox holds the newest `held` of `total` events ever received, so
+/// event index `i` sits at position `i - (total - held)`. Events already
+/// dropped are skipped; `since` past the end yields an empty range.
+pub fn inbox_range(total: u64, held: usize, since: u64) -> std::ops::Range<usize> {
+    let first = total.saturating_sub(held as u64);
+    let start = since.saturating_sub(first).min(held as u64) as usize;
+    start..held
+}
+
+/// Most entries one page of an audit log query returns.
+pub const MAX_LOG_PAGE: u32 = 200;
+
+/// Positions `offset..offset + limit` of a log holding `count` entries,
+/// with `limit` capped at `MAX_LOG_PAGE` and the range clipped to the log.
+pub fn log_page(count: usize, offset: u32, limit: u32) -> std::ops::Range<usize> {
+    let start = (offset as usize).min(count);
+    let end = start.saturating_add(limit.min(MAX_LOG_PAGE) as usize).min(count);
+    start..end
+}
+
+/// A page of a player chain's notification inbox.
+#[derive(Clone, Debug, SimpleObject)]
+pub struct NotificationPage {
+    /// Index of the first event in `events`.
+    pub first_index: u64,
+    /// Pass this as `since` next time to get only newer events.
+    pub next_index: u64,
+    pub events: Vec<async_graphql::Json<ArenaEvent>>,
+}
+
+/// What an `ActivityItem` records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum ActivityKind {
+    TournamentScheduled,
+    TournamentStarted,
+    TournamentEnded,
+    TournamentPaused,
+    TournamentResumed,
+    PlayerRegistered,
+    /// The admin role changed; `wallet` is the new admin.
+    AdminChanged,
+    ArenaReset,
+    /// A player finished the board (from `leaderboard_log`).
+    BoardCompleted,
+}
+
+/// One line of the Hub's activity feed, built from `event_log` and
+/// `leaderboard_log`. Fields that don't apply to `kind` are `None`.
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct ActivityItem {
+    pub kind: ActivityKind,
+    pub at_micros: u64,
+    pub tournament_id: Option<u64>,
+    pub wallet: Option<AccountOwner>,
+    pub discord_username: Option<String>,
+    pub score: Option<u64>,
+}
+
+impl ActivityItem {
+    fn new(kind: ActivityKind, at_micros: u64) -> Self {
+        ActivityItem {
+            kind,
+            at_micros,
+            tournament_id: None,
+            wallet: None,
+            discord_username: None,
+            score: None,
+        }
+    }
+
+    /// The feed item for a logged event. Leaderboard snapshots aren't
+    /// activity in themselves and yield `None`.
+    pub fn from_event(event: &ArenaEvent) -> Option<Self> {
+        let at_micros = event.emitted_at_micros();
+        let item = match event {
+            ArenaEvent::TournamentStarted { tournament_id, .. } => ActivityItem {
+                tournament_id: Some(*tournament_id),
+                ..Self::new(ActivityKind::TournamentStarted, at_micros)
+            },
+            ArenaEvent::TournamentEnded { tournament_id, .. } => ActivityItem {
+                tournament_id: Some(*tournament_id),
+                ..Self::new(ActivityKind::TournamentEnded, at_micros)
+            },
+            ArenaEvent::TournamentPaused { tournament_id, .. } => ActivityItem {
+                tournament_id: Some(*tournament_id),
+                ..Self::new(ActivityKind::TournamentPaused, at_micros)
+            },
+            ArenaEvent::TournamentResumed { tournament_id, .. } => ActivityItem {
+                tournament_id: Some(*tournament_id),
+                ..Self::new(ActivityKind::TournamentResumed, at_micros)
+            },
+            ArenaEvent::AdminChanged { new, .. } => ActivityItem {
+                wallet: Some(*new),
+                ..Self::new(ActivityKind::AdminChanged, at_micros)
+            },
+            ArenaEvent::ArenaReset { .. } => Self::new(ActivityKind::ArenaReset, at_micros),
+            ArenaEvent::PlayerRegistered { wallet, discord_username, .. } => ActivityItem {
+                wallet: Some(*wallet),
+                discord_username: Some(discord_username.clone()),
+                ..Self::new(ActivityKind::PlayerRegistered, at_micros)
+            },
+            ArenaEvent::TournamentScheduled { .. } => {
+                Self::new(ActivityKind::TournamentScheduled, at_micros)
+            }
+            // Completions are read from `leaderboard_log` instead, and
+            // announcements from `latestAnnouncement` and notifications.
+            ArenaEvent::LeaderboardUpdated { .. }
+            | ArenaEvent::PlayerCompleted { .. }
+            | ArenaEvent::PlayerAllowed { .. }
+            | ArenaEvent::Announcement { .. } => return None,
+        };
+        Some(item)
+    }
+
+    /// The feed item for a completed leaderboard entry.
+    pub fn from_completion(entry: &LeaderboardEntry) -> Self {
+        ActivityItem {
+            wallet: Some(entry.wallet),
+            discord_username: Some(entry.discord_username.clone()),
+            score: Some(entry.score),
+            ..Self::new(ActivityKind::BoardCompleted, entry.completion_time_micros)
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Response types
 // ---------------------------------------------------------------------------
@@ -224,21 +974,97 @@ pub enum ArenaResponse {
     /// A cell was cleared.
     CellCleared(CellClearedResponse),
 
+    /// A batch of cells was cleared.
+    CellsCleared(CellsClearedResponse),
+
     /// The board was completed.
     BoardCompleted(BoardCompletedResponse),
 
+    /// The player reached the tournament's `max_penalties`; their game is
+    /// over.
+    GameOver(GameOverResponse),
+
+    /// A submitted board was not the solution.
+    BoardRejected(BoardRejectedResponse),
+
     /// A tournament was started (admin).
     TournamentStarted(TournamentStartedResponse),
 
     /// A tournament was ended (admin).
     TournamentEnded(TournamentEndedResponse),
 
+    /// The tournament clock was paused (admin).
+    TournamentPaused(TournamentPausedResponse),
+
+    /// The tournament clock was resumed (admin).
+    TournamentResumed(TournamentResumedResponse),
+
     /// Leaderboard request was sent.
     LeaderboardRequested(LeaderboardRequestedResponse),
 
     /// Subscription to hub was established.
     Subscribed(SubscribedResponse),
 
+    /// Subscription to hub was removed.
+    Unsubscribed(UnsubscribedResponse),
+
+    /// A full resync was requested from the Hub.
+    ResyncRequested(ResyncRequestedResponse),
+
+    /// The puzzle was regenerated from the tournament seed.
+    PuzzleLoaded(PuzzleLoadedResponse),
+
+    /// A tournament was scheduled (admin).
+    TournamentScheduled(TournamentScheduledResponse),
+
+    /// The player checked in for the scheduled tournament.
+    CheckedIn(CheckedInResponse),
+
+    /// A heartbeat was recorded or sent to the Hub.
+    HeartbeatSent(HeartbeatSentResponse),
+
+    /// A wallet was allowlisted (admin).
+    PlayerAllowed(PlayerAllowedResponse),
+
+    /// The weekly schedule was enabled or disabled (admin).
+    WeeklyScheduleUpdated(WeeklyScheduleResponse),
+
+    /// A player's game reset was issued (admin).
+    PlayerGameReset(PlayerGameResetResponse),
+
+    /// A player's score was overridden (admin).
+    ScoreAdjusted(ScoreAdjustment),
+
+    /// The admin role changed hands.
+    AdminTransferred(AdminTransferredResponse),
+
+    /// The arena was wiped (admin).
+    ArenaReset(ArenaResetResponse),
+
+    /// A season was started or ended (admin).
+    SeasonUpdated(Season),
+
+    /// A puzzle string was vetted (admin).
+    PuzzleValidated(PuzzleValidation),
+
+    /// A daily puzzle time was recorded.
+    DailyResultRecorded(DailyResultResponse),
+
+    /// Result of a `RequestLogicHint`.
+    LogicHint(LogicHintResponse),
+
+    /// The player's preferences were stored.
+    PreferencesUpdated(PreferencesUpdatedResponse),
+
+    /// A tournament prize was claimed.
+    RewardClaimed(RewardClaimedResponse),
+
+    /// An announcement was broadcast.
+    Announced(AnnouncedResponse),
+
+    /// The chain's move sync batch size was changed.
+    SyncBatchSizeUpdated(SyncBatchSizeResponse),
+
     /// An error occurred.
     Error(ErrorResponse),
 }
@@ -260,70 +1086,393 @@ pub struct CellPlacedResponse {
     pub row: u8,
     pub col: u8,
     pub value: u8,
+    /// Always `true` in a blind-mode tournament.
     pub valid: bool,
+    /// Always 0 in a blind-mode tournament.
     pub penalty_count: u32,
     pub board_complete: bool,
 }
 
+impl CellPlacedResponse {
+    /// The response for a placement that didn't finish the game. In blind
+    /// mode `valid` and `penalty_count` are masked so the player gets no
+    /// free error-checking; the real count is in the completion summary.
+    pub fn new(row: u8, col: u8, value: u8, valid: bool, penalty_count: u32, blind: bool) -> Self {
+        CellPlacedResponse {
+            row, col, value,
+            valid: valid || blind,
+            penalty_count: if blind { 0 } else { penalty_count },
+            board_complete: false,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
 pub struct CellClearedResponse {
     pub row: u8,
     pub col: u8,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct CellsClearedResponse {
+    /// Coordinates that were actually cleared.
+    pub cleared: Vec<CellPosition>,
+    /// One result per requested cell, in request order.
+    pub results: Vec<ClearCellResult>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
 pub struct BoardCompletedResponse {
     pub completion_time_micros: u64,
     pub penalty_count: u32,
     pub score: u64,
+    /// Non-given cells on the finished board.
+    pub total_cells_filled: u32,
+    /// Share of moves that weren't invalid placements (forgiven ones
+    /// included), rounded down to a whole percent.
+    pub accuracy_percent: u32,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
-pub struct TournamentStartedResponse {
-    pub tournament_id: u64,
-    pub seed: u64,
-    pub start_time_micros: u64,
-    pub end_time_micros: u64,
+pub struct AdminTransferredResponse {
+    pub old_admin: AccountOwner,
+    pub new_admin: AccountOwner,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
-pub struct TournamentEndedResponse {
-    pub tournament_id: u64,
-    pub total_players: u32,
-    pub total_completions: u32,
+pub struct LogicHintResponse {
+    /// Whether some empty cell is forced by the rules. When `false`, the
+    /// player has to guess (or use techniques beyond naked singles).
+    pub deducible: bool,
+    pub cell: Option<CellPosition>,
+    /// The forced value, if `reveal_value` was set.
+    pub value: Option<u8>,
+    pub logic_hints_used: u32,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
-pub struct LeaderboardRequestedResponse {
-    pub message: String,
+pub struct PreferencesUpdatedResponse {
+    pub wallet: AccountOwner,
+    pub preferences: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
-pub struct SubscribedResponse {
-    pub hub_chain_id: ChainId,
+pub struct RewardClaimedResponse {
+    pub tournament_id: u64,
+    pub wallet: AccountOwner,
+    /// 1-based finishing place among completers.
+    pub rank: u32,
+    pub amount: u64,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
-pub struct ErrorResponse {
-    pub message: String,
+pub struct AnnouncedResponse {
+    pub text: String,
+    pub emitted_at_micros: u64,
 }
 
-// ---------------------------------------------------------------------------
-// Data structures
-// ---------------------------------------------------------------------------
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct SyncBatchSizeResponse {
+    pub moves: u32,
+}
 
-/// A registered player's profile.
-#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, InputObject)]
-#[graphql(input_name = "PlayerInfoInput")]
-pub struct PlayerInfo {
-    pub wallet: AccountOwner,
-    pub discord_username: String,
-    pub registered_at_micros: u64,
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct DailyResultResponse {
+    pub date_ymd: u32,
+    pub solve_time_micros: u64,
+    /// The player's best time for that date, this one included.
+    pub best_time_micros: u64,
 }
 
-/// A tournament descriptor.
-#[derive(Clone, Debug, Default, Serialize, Deserialize, SimpleObject, InputObject)]
-#[graphql(input_name = "TournamentInput")]
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct ArenaResetResponse {
+    /// Registered players before the reset.
+    pub players_cleared: u64,
+    /// Tournaments started before the reset.
+    pub tournaments_cleared: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct GameOverResponse {
+    pub penalty_count: u32,
+    pub max_penalties: u32,
+    /// Non-given cells left empty.
+    pub cells_remaining: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct BoardRejectedResponse {
+    /// Non-given cells that don't match the solution. Always 0 in a
+    /// blind-mode tournament.
+    pub wrong_cells: u32,
+    /// Always 0 in a blind-mode tournament.
+    pub penalty_count: u32,
+}
+
+impl BoardRejectedResponse {
+    /// The response for a submission with wrong cells. In blind mode the
+    /// counts are masked, as for `CellPlacedResponse`; the player only
+    /// learns the board isn't solved.
+    pub fn new(wrong_cells: u32, penalty_count: u32, blind: bool) -> Self {
+        if blind {
+            BoardRejectedResponse { wrong_cells: 0, penalty_count: 0 }
+        } else {
+            BoardRejectedResponse { wrong_cells, penalty_count }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct TournamentStartedResponse {
+    pub tournament_id: u64,
+    pub seed: u64,
+    pub variant: SudokuVariant,
+    pub start_time_micros: u64,
+    pub end_time_micros: u64,
+    pub restricted: bool,
+    pub scoring_mode: ScoringMode,
+    pub allow_bulk_submit: bool,
+    pub free_mistakes: u32,
+    pub max_penalties: Option<u32>,
+    pub box_bonus_enabled: bool,
+    pub board_size: BoardSize,
+    pub prizes: Vec<u64>,
+    pub blind_mode: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct TournamentEndedResponse {
+    pub tournament_id: u64,
+    pub total_players: u32,
+    pub total_completions: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct TournamentPausedResponse {
+    pub tournament_id: u64,
+    pub paused_at_micros: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct TournamentResumedResponse {
+    pub tournament_id: u64,
+    /// Total time paused so far in this tournament.
+    pub paused_micros: u64,
+    /// The end time after pushing it back by this pause.
+    pub end_time_micros: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct LeaderboardRequestedResponse {
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct SubscribedResponse {
+    pub hub_chain_id: ChainId,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct UnsubscribedResponse {
+    pub hub_chain_id: ChainId,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct ResyncRequestedResponse {
+    pub hub_chain_id: ChainId,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct PuzzleLoadedResponse {
+    pub tournament_id: u64,
+    pub variant: SudokuVariant,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct TournamentScheduledResponse {
+    pub start_time_micros: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct CheckedInResponse {
+    pub wallet: AccountOwner,
+    pub scheduled_start_micros: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct HeartbeatSentResponse {
+    pub wallet: AccountOwner,
+    pub timestamp_micros: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct PlayerAllowedResponse {
+    pub wallet: AccountOwner,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct WeeklyScheduleResponse {
+    /// The schedule now in force (`None` once disabled).
+    pub schedule: Option<WeeklySchedule>,
+    pub next_start_micros: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct PlayerGameResetResponse {
+    pub wallet: AccountOwner,
+    /// The player's home chain, which was sent the `ResetGame`.
+    pub player_chain_id: ChainId,
+    /// Whether the player had a leaderboard entry that was removed.
+    pub leaderboard_entry_removed: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct ErrorResponse {
+    /// Machine-readable reason, for clients to branch on or localize.
+    pub error_code: ErrorCode,
+    /// Human-readable explanation.
+    pub message: String,
+}
+
+/// Why an operation was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum ErrorCode {
+    /// The signer has no player profile on this chain.
+    NotRegistered,
+    /// `RegisterPlayer` was called by an existing player.
+    AlreadyRegistered,
+    /// The username fails `validate_username`.
+    InvalidUsername,
+    /// Another wallet already holds the username.
+    UsernameTaken,
+    /// The team name fails `validate_username`.
+    InvalidTeam,
+    /// There is no live tournament.
+    NoActiveTournament,
+    /// The tournament's time window has passed.
+    TournamentExpired,
+    /// A tournament is already running.
+    TournamentAlreadyActive,
+    /// The seed was used by an earlier tournament.
+    SeedReused,
+    /// Row, column, or value is out of range.
+    InvalidCell,
+    /// The cell is part of the puzzle and can't be changed.
+    GivenCell,
+    /// The tournament's puzzle isn't available on this chain.
+    PuzzleNotLoaded,
+    /// The player has no game for the current tournament.
+    NoGameInProgress,
+    /// The player's board is already solved.
+    AlreadyCompleted,
+    /// Moves arrived faster than the minimum move interval.
+    RateLimited,
+    /// No tournament is scheduled.
+    NotScheduled,
+    /// Check-in has closed because the tournament started.
+    CheckInClosed,
+    /// The scheduled start time is invalid.
+    InvalidSchedule,
+    /// The operation is Hub-only and was sent to another chain.
+    NotHub,
+    /// The signer isn't the arena admin.
+    NotAdmin,
+    /// The tournament is restricted and the wallet isn't allowlisted.
+    NotAllowed,
+    /// The tournament duration is zero, too long, or overflows the clock.
+    InvalidDuration,
+    /// The tournament doesn't accept `SubmitBoard`.
+    BulkSubmitDisabled,
+    /// A submitted board is malformed or alters a given cell.
+    InvalidBoard,
+    /// The player has no leaderboard entry in the current tournament.
+    NoLeaderboardEntry,
+    /// A required reason is empty or too long.
+    InvalidReason,
+    /// The loaded puzzle belongs to a different tournament.
+    PuzzleMismatch,
+    /// The tournament clock is paused.
+    TournamentPaused,
+    /// `ResumeTournament` was called while the clock is running.
+    TournamentNotPaused,
+    /// Fewer players have registered than the start requires.
+    NotEnoughPlayers,
+    /// The proposed admin is a reserved or all-zero owner.
+    InvalidAdmin,
+    /// The seed's puzzle couldn't be generated within the step budget.
+    GenerationFailed,
+    /// `ResetArena` was called without the exact confirmation phrase.
+    ConfirmationRequired,
+    /// `StartSeason` was called while a season is running.
+    SeasonAlreadyActive,
+    /// `EndSeason` was called with no season running.
+    NoActiveSeason,
+    /// The season's placement points table is empty or too long.
+    InvalidPointsTable,
+    /// A date wasn't a plausible `YYYYMMDD`.
+    InvalidDate,
+    /// The board size can't be combined with the requested variant or
+    /// box bonus.
+    UnsupportedBoardSize,
+    /// A preferences blob was too long or contained control characters.
+    InvalidPreferences,
+    /// A prize table had more than `MAX_PRIZE_PLACES` places.
+    InvalidPrizes,
+    /// The tournament hasn't ended (or doesn't exist), so prizes can't be
+    /// claimed yet.
+    TournamentNotEnded,
+    /// The caller didn't finish in a prize-winning place.
+    NoReward,
+    /// The caller already claimed this tournament's prize.
+    RewardAlreadyClaimed,
+    /// An announcement was empty, too long, or had control characters.
+    InvalidAnnouncement,
+    /// A sync batch size was 0 or above `MAX_SYNC_BATCH`.
+    InvalidBatchSize,
+}
+
+/// Whether `date_ymd` reads as a `YYYYMMDD` date (years 2000-9999). Days
+/// are only checked against 31, whatever the month.
+pub fn is_valid_date_ymd(date_ymd: u32) -> bool {
+    let (year, month, day) = (date_ymd / 10_000, date_ymd / 100 % 100, date_ymd % 100);
+    (2000..=9999).contains(&year) && (1..=12).contains(&month) && (1..=31).contains(&day)
+}
+
+/// Phrase `ResetArena` must be given verbatim before it wipes anything.
+pub const RESET_ARENA_CONFIRMATION: &str = "RESET FRIDAYCHAIN ARENA";
+
+/// Whether `confirm` is exactly `RESET_ARENA_CONFIRMATION` (no trimming
+/// or case folding, so it can't be typed by accident).
+pub fn is_reset_confirmed(confirm: &str) -> bool {
+    confirm == RESET_ARENA_CONFIRMATION
+}
+
+// ---------------------------------------------------------------------------
+// Data structures
+// ---------------------------------------------------------------------------
+
+/// A registered player's profile.
+///
+/// `Deserialize` is implemented in `migration` so profiles stored before
+/// `team` and `preferences` existed (schema v0) still decode.
+#[derive(Clone, Debug, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "PlayerInfoInput")]
+pub struct PlayerInfo {
+    pub wallet: AccountOwner,
+    pub discord_username: String,
+    pub registered_at_micros: u64,
+    /// Team/guild chosen at registration, if any.
+    pub team: Option<String>,
+    /// Opaque frontend preferences set with `SetPreferences`.
+    pub preferences: Option<String>,
+}
+
+/// A tournament descriptor.
+///
+/// `Deserialize` is implemented in `migration` so tournaments stored before
+/// the rule fields existed (schema v0) still decode, with those defaulted.
+#[derive(Clone, Debug, Default, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "TournamentInput")]
 pub struct Tournament {
     pub id: u64,
     pub seed: u64,
@@ -332,27 +1481,399 @@ pub struct Tournament {
     pub active: bool,
     pub total_players: u32,
     pub total_completions: u32,
+    /// SHA-256 of the solution grid (see `sudoku::grid_commitment`), fixed
+    /// when the tournament starts. Check it against `revealSolution` afterwards.
+    pub solution_commitment: [u8; 32],
+    /// Puzzle rules for this tournament.
+    pub variant: SudokuVariant,
+    /// Invite-only: only wallets added with `AllowPlayer` may play.
+    pub restricted: bool,
+    /// How completion time and penalties are weighed (see `compute_score`).
+    pub scoring_mode: ScoringMode,
+    /// Whether players may finish with a single `SubmitBoard`.
+    pub allow_bulk_submit: bool,
+    /// Invalid placements per player that don't count as penalties.
+    pub free_mistakes: u32,
+    /// Penalties at which a player's game ends as a failure; `None` is
+    /// unlimited.
+    pub max_penalties: Option<u32>,
+    /// Whether the puzzle has exactly one solution, checked once when it is
+    /// generated (`count_solutions` is too slow to run per query).
+    pub unique_solution: bool,
+    /// Total length of finished pauses. `end_time_micros` already includes it.
+    pub paused_micros: u64,
+    /// When the current pause began, if the clock is paused.
+    pub paused_since_micros: Option<u64>,
+    /// Size of live leaderboard broadcasts; `None` means
+    /// `DEFAULT_BROADCAST_TOP_N`.
+    pub broadcast_top_n: Option<u32>,
+    /// Whether the first player to correctly fill each 3×3 box earns
+    /// `BOX_BONUS_POINTS` on the Hub's leaderboard.
+    pub box_bonus_enabled: bool,
+    /// Grid size; 9×9 unless a mini board was picked.
+    pub board_size: BoardSize,
+    /// Prize for each finishing place, winner first; empty if none.
+    pub prizes: Vec<u64>,
+    /// Placement responses don't say whether a move was valid; penalties
+    /// still count and show once the board is done.
+    pub blind_mode: bool,
+}
+
+impl Tournament {
+    /// Whether `board` is this tournament's puzzle: same variant and a
+    /// solution matching `solution_commitment`. Guards against a stale
+    /// `current_puzzle` left over from another tournament. Tournaments from
+    /// before commitments existed (all-zero) only check the variant.
+    pub fn matches_puzzle(&self, board: &SudokuBoard) -> bool {
+        board.variant == self.variant
+            && board.size == self.board_size
+            && (self.solution_commitment == [0; 32]
+                || sudoku::grid_commitment(&board.solution) == self.solution_commitment)
+    }
+
+    /// This tournament's puzzle, regenerated from its seed. `None` if
+    /// generation fails or the result doesn't match the commitment.
+    pub fn regenerate_puzzle(&self) -> Option<SudokuBoard> {
+        self.generate_puzzle().filter(|board| self.matches_puzzle(board))
+    }
+
+    /// Generate this tournament's puzzle from its seed, variant and size.
+    pub fn generate_puzzle(&self) -> Option<SudokuBoard> {
+        sudoku::generate_puzzle_sized(self.seed, self.variant, self.board_size)
+    }
+
+    /// Whether `claimed` is this tournament's solution, judged only against
+    /// `solution_commitment`. Anything but a 9×9 grid is rejected, as is
+    /// every claim for a tournament without a commitment.
+    pub fn verifies_solution(&self, claimed: &[Vec<u8>]) -> bool {
+        self.solution_commitment != [0; 32]
+            && validate_board_arg(claimed)
+                .is_ok_and(|grid| sudoku::grid_commitment(&grid) == self.solution_commitment)
+    }
+
+    /// Score for finishing (or standing) at `at_micros` with `penalty_count`
+    /// penalties, under this tournament's scoring mode.
+    ///
+    /// Time is always measured from the tournament's start, never from the
+    /// player's first move: everyone races the same clock, and the Hub —
+    /// which can't see when a player first opened the board — arrives at the
+    /// same number as the player chain. Both sides score through here.
+    pub fn score_at(&self, at_micros: u64, penalty_count: u32) -> u64 {
+        compute_score(self.elapsed_micros(at_micros), penalty_count, self.scoring_mode)
+    }
+
+    /// The live estimate for an unfinished game standing at `at_micros`.
+    /// Blind mode leaves penalties out until the board is done, so the
+    /// estimate can't be watched to tell a valid placement from a wrong one.
+    pub fn live_score(&self, at_micros: u64, penalty_count: u32) -> u64 {
+        self.score_at(at_micros, if self.blind_mode { 0 } else { penalty_count })
+    }
+
+    /// Playing time from the start to `at_micros`, not counting pauses
+    /// (including one still in progress).
+    pub fn elapsed_micros(&self, at_micros: u64) -> u64 {
+        let current_pause = self.paused_since_micros
+            .map_or(0, |since| at_micros.saturating_sub(since));
+        at_micros
+            .saturating_sub(self.start_time_micros)
+            .saturating_sub(self.paused_micros + current_pause)
+    }
+
+    /// Whether `penalty_count` has reached `max_penalties`.
+    pub fn penalties_exhausted(&self, penalty_count: u32) -> bool {
+        self.max_penalties.is_some_and(|max| penalty_count >= max)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_since_micros.is_some()
+    }
+
+    /// Whether `now` falls inside the tournament's time window.
+    pub fn in_window(&self, now: u64) -> bool {
+        self.start_time_micros <= now && now <= self.end_time_micros
+    }
+
+    /// Whether the Hub should accept a completion the player chain stamped
+    /// `completion_time_micros`, arriving at Hub time `received_at`. Both
+    /// must be within `COMPLETION_GRACE_MICROS` of the end time: player
+    /// clocks drift, and the message takes a few blocks to arrive, but the
+    /// Hub's end time is the one that counts.
+    pub fn accepts_completion(&self, completion_time_micros: u64, received_at: u64) -> bool {
+        let deadline = self.end_time_micros.saturating_add(COMPLETION_GRACE_MICROS);
+        completion_time_micros <= deadline && received_at <= deadline
+    }
+
+    /// `(rank, prize)` for `wallet` in this tournament's final `rankings`
+    /// (in rank order). Only completers place, as with season placement
+    /// points; `None` past the prize table or for a zero prize.
+    pub fn reward_for(
+        &self,
+        rankings: &[LeaderboardEntry],
+        wallet: &AccountOwner,
+    ) -> Option<(u32, u64)> {
+        rankings.iter()
+            .filter(|e| e.completed && !e.failed)
+            .zip(&self.prizes)
+            .enumerate()
+            .find(|(_, (e, _))| e.wallet == *wallet)
+            .map(|(i, (_, &amount))| (i as u32 + 1, amount))
+            .filter(|&(_, amount)| amount > 0)
+    }
+
+    /// Freeze the clock at `now`. Does nothing if already paused.
+    pub fn pause(&mut self, now: u64) {
+        if self.paused_since_micros.is_none() {
+            self.paused_since_micros = Some(now);
+        }
+    }
+
+    /// Restart the clock at `now`, adding the pause to `paused_micros` and
+    /// pushing the end time back by the same amount. Does nothing if not
+    /// paused.
+    pub fn resume(&mut self, now: u64) {
+        if let Some(since) = self.paused_since_micros.take() {
+            let pause = now.saturating_sub(since);
+            self.paused_micros = self.paused_micros.saturating_add(pause);
+            self.end_time_micros = self.end_time_micros.saturating_add(pause);
+        }
+    }
+
+    /// Bonus points for a player who was first to fill `boxes_won` boxes;
+    /// 0 unless `box_bonus_enabled`.
+    pub fn box_bonus(&self, boxes_won: u32) -> u64 {
+        if self.box_bonus_enabled {
+            (boxes_won as u64).saturating_mul(BOX_BONUS_POINTS)
+        } else {
+            0
+        }
+    }
+
+    /// How many entries a live leaderboard broadcast carries.
+    pub fn broadcast_size(&self) -> u32 {
+        self.broadcast_top_n
+            .unwrap_or(DEFAULT_BROADCAST_TOP_N)
+            .clamp(1, MAX_BROADCAST_TOP_N)
+    }
+
+    /// A `LeaderboardUpdated` event carrying the top `broadcast_size()` of
+    /// the sorted `entries`.
+    pub fn leaderboard_event(&self, mut entries: Vec<LeaderboardEntry>, now: u64) -> ArenaEvent {
+        entries.truncate(self.broadcast_size() as usize);
+        ArenaEvent::LeaderboardUpdated { entries, emitted_at_micros: now }
+    }
+}
+
+/// How late (past `end_time_micros`) the Hub still accepts a completion.
+pub const COMPLETION_GRACE_MICROS: u64 = 30_000_000;
+
+/// Points for each 3×3 box a player was first to fill (`box_bonus_enabled`).
+pub const BOX_BONUS_POINTS: u64 = 50;
+
+/// Live leaderboard broadcast size when `StartTournament` doesn't set one.
+pub const DEFAULT_BROADCAST_TOP_N: u32 = 50;
+
+/// Largest live leaderboard broadcast a tournament may ask for.
+pub const MAX_BROADCAST_TOP_N: u32 = 200;
+
+/// Most places a tournament's prize table may pay.
+pub const MAX_PRIZE_PLACES: usize = 100;
+
+/// Longest tournament that can be started (7 days).
+pub const MAX_TOURNAMENT_DURATION_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// End time of a tournament starting at `start_micros` and lasting
+/// `duration_secs`. Rejects a zero or over-long duration and an end time
+/// that doesn't fit in `u64`, with a human-readable reason.
+pub fn tournament_end_time(start_micros: u64, duration_secs: u64) -> Result<u64, String> {
+    if duration_secs == 0 {
+        return Err("duration_secs must be positive".into());
+    }
+    if duration_secs > MAX_TOURNAMENT_DURATION_SECS {
+        return Err(format!(
+            "duration_secs must be at most {} (7 days)", MAX_TOURNAMENT_DURATION_SECS,
+        ));
+    }
+    duration_secs
+        .checked_mul(1_000_000)
+        .and_then(|micros| start_micros.checked_add(micros))
+        .ok_or_else(|| "Tournament end time overflows".into())
+}
+
+/// Whether the Hub should accept a player → Hub sync message about
+/// `wallet`. Any chain can send the Hub a message naming any wallet, so the
+/// wallet itself must have signed it (`signer`, forwarded by
+/// `with_authentication`), and it must come from the chain the wallet first
+/// synced from (`home_chain`, or any chain if there is none yet) so one
+/// wallet can't feed the Hub moves from two boards.
+pub fn is_authentic_sync(
+    wallet: &AccountOwner,
+    signer: Option<AccountOwner>,
+    origin: Option<ChainId>,
+    home_chain: Option<ChainId>,
+) -> bool {
+    let Some(origin) = origin else {
+        return false;
+    };
+    signer.as_ref() == Some(wallet) && home_chain.is_none_or(|home| home == origin)
+}
+
+/// Whether `owner` can hold the admin role: not a reserved owner (such as
+/// `AccountOwner::CHAIN`) and not an all-zero address, which no key signs for.
+pub fn is_valid_admin(owner: &AccountOwner) -> bool {
+    match owner {
+        AccountOwner::Reserved(_) => false,
+        AccountOwner::Address20(bytes) => bytes.iter().any(|&b| b != 0),
+        AccountOwner::Address32(hash) => hash.as_bytes().iter().any(|&b| b != 0),
+    }
+}
+
+/// Whether `signer` is the current admin. Only the owner recorded now counts,
+/// so a `TransferAdmin` revokes the previous admin immediately.
+pub fn is_admin_signer(admin_owner: Option<&AccountOwner>, signer: &AccountOwner) -> bool {
+    admin_owner == Some(signer)
+}
+
+/// Check a `StartTournament` `min_players` requirement against the Hub's
+/// registered `player_count`, with a human-readable reason on failure.
+pub fn check_min_players(player_count: u64, min_players: Option<u32>) -> Result<(), String> {
+    match min_players {
+        Some(min) if player_count < min as u64 => Err(format!(
+            "{} players registered; at least {} needed to start", player_count, min,
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// The Hub's `player_count` after a `SyncPlayer` for a wallet; `known`
+/// says whether the wallet was already in `players`. Only a wallet seen
+/// for the first time is counted.
+pub fn player_count_after_sync(player_count: u64, known: bool) -> u64 {
+    if known {
+        player_count
+    } else {
+        player_count + 1
+    }
+}
+
+/// Check a `StartTournament` seed against the Hub's `used_seeds`, where
+/// `previous` is the tournament that already used it, if any.
+pub fn check_seed_unused(seed: u64, previous: Option<u64>) -> Result<(), String> {
+    match previous {
+        Some(previous) => Err(format!(
+            "Seed {} was already used by tournament #{}; pick a fresh seed", seed, previous,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// How many seeds an auto-started tournament tries before skipping its slot.
+pub const MAX_SEED_ATTEMPTS: usize = 8;
+
+/// Seeds for an auto-started tournament to try in order: `seed` itself,
+/// then successors derived from it, for when the schedule lands on a seed
+/// an earlier tournament used (week numbers repeat every year). No admin
+/// is there to pick a fresh one.
+pub fn seed_candidates(seed: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(Some(seed), |seed| {
+        Some(seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407))
+    })
+    .take(MAX_SEED_ATTEMPTS)
 }
 
 /// A player's current game state for the active tournament.
-#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+///
+/// `Deserialize` is implemented in `migration` so games stored with nested
+/// boards (schema v0) or before the later fields existed still decode.
+#[derive(Clone, Debug, Serialize, SimpleObject)]
 pub struct PlayerGameState {
     /// The player's current board state (0 = empty, 1-9 = placed value).
+    /// Stored as 81 flat bytes (see `compact`).
+    #[serde(with = "compact::board")]
     pub board: Vec<Vec<u8>>,
     /// Which cells are pre-filled (given) and cannot be changed.
+    /// Stored as an 11-byte bitset (see `compact`).
+    #[serde(with = "compact::mask")]
     pub given_mask: Vec<Vec<bool>>,
-    /// Number of invalid placements so far.
+    /// Number of invalid placements that counted as penalties.
     pub penalty_count: u32,
     /// Total moves made.
     pub move_count: u32,
     /// Timestamp (micros) when the player made their first move.
     pub start_time_micros: u64,
+    /// Timestamp (micros) of the most recent placement (0 = none yet).
+    pub last_move_time_micros: u64,
     /// Whether the board has been completed.
     pub completed: bool,
     /// Timestamp (micros) when the board was completed.
     pub completion_time_micros: Option<u64>,
     /// The computed score (0 if not completed).
     pub score: u64,
+    /// Every placement and clear in order, for replay.
+    pub move_history: Vec<MoveRecord>,
+    /// Playing time (µs since the tournament started, pauses excluded) of
+    /// each `move_history` entry, for timed replays via `verify_game_timed`.
+    /// Shorter than `move_history` for games started before it was recorded.
+    pub move_times: Vec<u64>,
+    /// Number of `SubmitBoard` attempts.
+    pub board_submissions: u32,
+    /// Every invalid placement, including those forgiven by the tournament's
+    /// `free_mistakes`.
+    pub raw_mistake_count: u32,
+    /// The game ended by reaching `max_penalties` (`completed` is also set,
+    /// so no further moves are accepted).
+    pub failed: bool,
+    /// `RequestLogicHint`s that found a cell. They cost no penalty.
+    pub logic_hints_used: u32,
+    /// Placements not yet synced to the Hub (see `SetSyncBatchSize`).
+    #[graphql(skip)]
+    pub pending_sync: Vec<SyncedMove>,
+    /// Unit counts for O(1) placement checks (see `allows_placement`).
+    /// Never stored: rebuilt from `board` on first use after loading.
+    #[serde(skip)]
+    #[graphql(skip)]
+    pub tracker: Option<sudoku::ConstraintTracker>,
+}
+
+/// A deliberately reduced, read-only view of a player's game for spectators
+/// and stream overlays. It carries only what is already visible on screen —
+/// no move history, timestamps, or given mask — so it can't leak solving hints.
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct SpectatorView {
+    /// The player's visible board (0 = empty).
+    pub board: Vec<Vec<u8>>,
+    pub move_count: u32,
+    pub penalty_count: u32,
+    pub completed: bool,
+    /// Non-given cells still empty, for progress bars.
+    pub cells_remaining: u32,
+}
+
+/// Summary of a player's own (non-given) cells. `conflicting` counts
+/// entries that break a rule against the rest of the board; it never
+/// consults the solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct SolveProgress {
+    pub filled: u32,
+    pub remaining: u32,
+    pub conflicting: u32,
+}
+
+/// Which rows, columns, and boxes are completely filled with 1-9 (1 to the
+/// side on a mini board) and no repeats. Judged from the board alone, never
+/// the solution. Entries past a mini board's side are always false.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct RegionProgress {
+    pub rows: [bool; 9],
+    pub cols: [bool; 9],
+    pub boxes: [bool; 9],
+}
+
+/// One entry in a player's move history. `value == 0` records a clear.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct MoveRecord {
+    pub row: u8,
+    pub col: u8,
+    pub value: u8,
 }
 
 impl PlayerGameState {
@@ -375,10 +1896,227 @@ impl PlayerGameState {
             penalty_count: 0,
             move_count: 0,
             start_time_micros: 0,
+            last_move_time_micros: 0,
             completed: false,
             completion_time_micros: None,
             score: 0,
+            move_history: Vec::new(),
+            move_times: Vec::new(),
+            board_submissions: 0,
+            raw_mistake_count: 0,
+            failed: false,
+            logic_hints_used: 0,
+            pending_sync: Vec::new(),
+            tracker: None,
+        }
+    }
+
+    /// A fresh game for `board`. On a mini board the cells outside it are
+    /// marked given, so they can't be played and don't count as remaining.
+    pub fn for_puzzle(board: &SudokuBoard) -> Self {
+        let mut state = Self::new(&board.puzzle);
+        for r in 0..9 {
+            for c in 0..9 {
+                if !board.size.contains(r, c) {
+                    state.given_mask[r][c] = true;
+                }
+            }
+        }
+        state
+    }
+
+    /// An empty cell of the current board with exactly one value left under
+    /// `variant`'s rules, as `(row, col, value)`. Uses only the board, so
+    /// a wrong earlier entry can make the hint wrong too.
+    pub fn logic_hint(&self, variant: SudokuVariant, size: BoardSize) -> Option<(usize, usize, u8)> {
+        sudoku::find_naked_single(&self.grid(), variant, size)
+    }
+
+    /// Whether some empty cell has no legal value left under `variant`'s
+    /// rules, so the board can't be completed without clearing an earlier
+    /// entry. Uses only the board, never the solution.
+    pub fn is_stuck(&self, variant: SudokuVariant, size: BoardSize) -> bool {
+        sudoku::find_dead_cell(&self.grid(), variant, size).is_some()
+    }
+
+    /// The board as a fixed 9×9 grid.
+    fn grid(&self) -> [[u8; 9]; 9] {
+        let mut grid = [[0u8; 9]; 9];
+        for (r, row) in self.board.iter().enumerate().take(9) {
+            for (c, &value) in row.iter().enumerate().take(9) {
+                grid[r][c] = value;
+            }
+        }
+        grid
+    }
+
+    /// Whether `value` may go in `(row, col)` under `variant` on a `size`
+    /// board, like `sudoku::validate_placement_sized` but without rescanning
+    /// the board.
+    pub fn allows_placement(
+        &mut self,
+        row: usize,
+        col: usize,
+        value: u8,
+        variant: SudokuVariant,
+        size: BoardSize,
+    ) -> bool {
+        let board = &self.board;
+        let tracker = self.tracker
+            .get_or_insert_with(|| sudoku::ConstraintTracker::new_sized(board, variant, size));
+        if tracker.variant() != variant || tracker.size() != size {
+            *tracker = sudoku::ConstraintTracker::new_sized(board, variant, size);
+        }
+        tracker.allows(row, col, value)
+    }
+
+    /// Set one cell (0 clears it), keeping the tracker in step. Cell writes
+    /// outside this method must reset `tracker` to `None`.
+    pub fn set_cell(&mut self, row: usize, col: usize, value: u8) {
+        self.board[row][col] = value;
+        if let Some(tracker) = self.tracker.as_mut() {
+            tracker.set(row, col, value);
+        }
+    }
+
+    /// The reduced spectator view of this game.
+    pub fn spectator_view(&self) -> SpectatorView {
+        SpectatorView {
+            board: self.board.clone(),
+            move_count: self.move_count,
+            penalty_count: self.penalty_count,
+            completed: self.completed,
+            cells_remaining: self.cells_remaining(),
+        }
+    }
+
+    /// The completion screen summary for a finished game.
+    pub fn completion_summary(&self) -> BoardCompletedResponse {
+        let total_cells_filled = self.board.iter().flatten().zip(self.given_mask.iter().flatten())
+            .filter(|&(&value, &given)| value != 0 && !given)
+            .count() as u32;
+        let accuracy_percent = match self.move_count {
+            0 => 100,
+            moves => (moves.saturating_sub(self.raw_mistake_count) as u64 * 100 / moves as u64) as u32,
+        };
+        BoardCompletedResponse {
+            completion_time_micros: self.completion_time_micros.unwrap_or(0),
+            penalty_count: self.penalty_count,
+            score: self.score,
+            total_cells_filled,
+            accuracy_percent,
+        }
+    }
+
+    /// Count filled, empty, and conflicting non-given cells under `variant`.
+    pub fn solve_progress(&self, variant: SudokuVariant, size: BoardSize) -> SolveProgress {
+        let conflicts = sudoku::board_conflicts_sized(&self.board, variant, size);
+        let mut filled = 0;
+        let mut conflicting = 0;
+        for r in 0..9 {
+            for c in 0..9 {
+                if self.board[r][c] != 0 && !self.given_mask[r][c] {
+                    filled += 1;
+                    if conflicts.contains(&(r, c)) {
+                        conflicting += 1;
+                    }
+                }
+            }
+        }
+        SolveProgress { filled, remaining: self.cells_remaining(), conflicting }
+    }
+
+    /// Completed rows, columns, and boxes on the current board.
+    pub fn region_progress(&self, size: BoardSize) -> RegionProgress {
+        let side = size.side();
+        let (box_rows, box_cols) = size.box_shape();
+        let boxes_across = side / box_cols;
+        let complete = |cells: &mut dyn Iterator<Item = (usize, usize)>| {
+            let mut seen = 0u16;
+            for (r, c) in cells {
+                let value = self.board[r][c];
+                if value == 0 || seen & (1 << value) != 0 {
+                    return false;
+                }
+                seen |= 1 << value;
+            }
+            true
+        };
+        RegionProgress {
+            rows: std::array::from_fn(|r| r < side && complete(&mut (0..side).map(|c| (r, c)))),
+            cols: std::array::from_fn(|c| c < side && complete(&mut (0..side).map(|r| (r, c)))),
+            boxes: std::array::from_fn(|b| {
+                let (top, left) = (b / boxes_across * box_rows, b % boxes_across * box_cols);
+                b < side
+                    && complete(&mut (0..side).map(|i| (top + i / box_cols, left + i % box_cols)))
+            }),
+        }
+    }
+
+    /// Count an invalid placement. Returns whether it became a penalty, i.e.
+    /// the player had already used their `free_mistakes`.
+    pub fn record_mistake(&mut self, free_mistakes: u32) -> bool {
+        self.raw_mistake_count += 1;
+        let penalized = self.raw_mistake_count > free_mistakes;
+        if penalized {
+            self.penalty_count += 1;
+        }
+        penalized
+    }
+
+    /// End the game at `at_micros` as a failure, scoring 0.
+    pub fn fail(&mut self, at_micros: u64) {
+        self.completed = true;
+        self.failed = true;
+        self.completion_time_micros = Some(at_micros);
+        self.score = 0;
+    }
+
+    /// How many times each value 1-9 appears on the board, givens
+    /// included (index 0 is value 1), so a UI can grey out used-up digits.
+    pub fn digit_counts(&self) -> [u8; 9] {
+        let mut counts = [0u8; 9];
+        for &value in self.board.iter().flatten() {
+            if (1..=9).contains(&value) {
+                counts[value as usize - 1] += 1;
+            }
+        }
+        counts
+    }
+
+    /// Number of non-given cells that are still empty.
+    pub fn cells_remaining(&self) -> u32 {
+        self.board.iter().flatten().zip(self.given_mask.iter().flatten())
+            .filter(|&(&value, &given)| value == 0 && !given)
+            .count() as u32
+    }
+
+    /// Append `record` to the move history, played at `elapsed_micros`.
+    pub fn record_move(&mut self, record: MoveRecord, elapsed_micros: u64) {
+        self.move_history.push(record);
+        self.move_times.push(elapsed_micros);
+    }
+
+    /// Reconstruct the board after the first `n` entries of `move_history`,
+    /// starting from the givens. An `n` past the end yields the final board.
+    pub fn board_at_move(&self, n: usize) -> Vec<Vec<u8>> {
+        let mut board: Vec<Vec<u8>> = self.board.iter().zip(&self.given_mask)
+            .map(|(row, mask)| {
+                row.iter().zip(mask).map(|(&v, &given)| if given { v } else { 0 }).collect()
+            })
+            .collect();
+
+        for m in self.move_history.iter().take(n) {
+            board[m.row as usize][m.col as usize] = m.value;
         }
+        board
+    }
+
+    /// Whether a placement at `now_micros` comes too soon after the previous one.
+    /// The first placement is never throttled.
+    pub fn is_rate_limited(&self, now_micros: u64, min_interval_micros: u64) -> bool {
+        self.last_move_time_micros != 0
+            && now_micros.saturating_sub(self.last_move_time_micros) < min_interval_micros
     }
 
     /// Check if the board matches the solution.
@@ -393,24 +2131,126 @@ impl PlayerGameState {
         true
     }
 
-    /// Calculate score based on completion time and penalties.
-    /// Higher is better. Formula:
-    ///   score = 10000 - (time_seconds * 2) - (penalties * 100)
-    /// Minimum score is 0.
-    /// NOTE: Must match the Hub's formula in handle_sync_board_complete.
-    pub fn calculate_score(&self, start_micros: u64, end_micros: u64) -> u64 {
-        let elapsed_secs = (end_micros.saturating_sub(start_micros)) / 1_000_000;
-        let time_penalty = elapsed_secs.saturating_mul(2);
-        let move_penalty = (self.penalty_count as u64).saturating_mul(100);
-        10_000u64
-            .saturating_sub(time_penalty)
-            .saturating_sub(move_penalty)
+    /// Clear each of `cells` that holds a player-placed value, recording a
+    /// clear in the move history for each. Other cells are left alone.
+    pub fn clear_cells(&mut self, cells: &[(u8, u8)], elapsed_micros: u64) -> Vec<ClearCellResult> {
+        cells.iter().map(|&(row, col)| {
+            let (r, c) = (row as usize, col as usize);
+            let outcome = if r > 8 || c > 8 {
+                ClearOutcome::OutOfRange
+            } else if self.given_mask[r][c] {
+                ClearOutcome::Given
+            } else if self.board[r][c] == 0 {
+                ClearOutcome::AlreadyEmpty
+            } else {
+                self.set_cell(r, c, 0);
+                self.record_move(MoveRecord { row, col, value: 0 }, elapsed_micros);
+                ClearOutcome::Cleared
+            };
+            ClearCellResult { row, col, outcome }
+        }).collect()
     }
-}
+
+    /// The live estimate the Hub keeps for an unfinished game, as of `now`
+    /// (see `Tournament::live_score`). `None` once the board is completed.
+    pub fn estimated_score(&self, tournament: &Tournament, now: u64) -> Option<u64> {
+        (!self.completed).then(|| tournament.live_score(now, self.penalty_count))
+    }
+
+    /// This game as its player may see it while `tournament` (the active
+    /// one, if any) runs. Blind mode hides the penalty counts until the
+    /// board is done, as `CellPlacedResponse::new` does per placement.
+    pub fn visible(mut self, tournament: Option<&Tournament>) -> Self {
+        if tournament.is_some_and(|t| t.blind_mode) && !self.completed {
+            self.penalty_count = 0;
+            self.raw_mistake_count = 0;
+        }
+        self
+    }
+
+    /// When the player would finish at their pace so far: the time per
+    /// filled cell since their first move, extrapolated linearly over the
+    /// cells still empty. Assumes the pace holds (late cells are often
+    /// quicker) and ignores tournament pauses. The completion time once
+    /// finished; `None` after a failed game or with fewer than
+    /// `MIN_ESTIMATE_CELLS` cells filled.
+    pub fn estimated_finish_micros(&self, now: u64) -> Option<u64> {
+        if self.completed {
+            return self.completion_time_micros.filter(|_| !self.failed);
+        }
+        let open = self.given_mask.iter().flatten().filter(|&&given| !given).count() as u64;
+        let remaining = self.cells_remaining() as u64;
+        let filled = open - remaining;
+        if filled < MIN_ESTIMATE_CELLS as u64 || self.start_time_micros == 0 {
+            return None;
+        }
+        let elapsed = now.saturating_sub(self.start_time_micros);
+        Some(now.saturating_add(elapsed / filled * remaining))
+    }
+
+    /// Score for this game finishing at `end_micros` in `tournament`
+    /// (see `Tournament::score_at`).
+    pub fn calculate_score(&self, tournament: &Tournament, end_micros: u64) -> u64 {
+        tournament.score_at(end_micros, self.penalty_count)
+    }
+}
+
+/// Cells a player must fill before `estimated_finish_micros` extrapolates.
+pub const MIN_ESTIMATE_CELLS: u32 = 5;
+
+/// A player's profile and current game in one response, for the page
+/// every player keeps open.
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct PlayerDashboard {
+    pub wallet: AccountOwner,
+    /// `None` if the wallet isn't registered on this chain.
+    pub player: Option<PlayerInfo>,
+    /// `None` if the player hasn't started a game.
+    pub game: Option<PlayerGameState>,
+    /// Live score estimate as of now; `None` without an unfinished game in
+    /// an active tournament (see `PlayerGameState::estimated_score`).
+    pub estimated_score: Option<u64>,
+    pub cells_remaining: Option<u32>,
+    /// See `PlayerGameState::digit_counts`; `None` without a game.
+    pub digit_counts: Option<[u8; 9]>,
+    /// 1-based position in the cached leaderboard, as fresh as the last
+    /// `RequestLeaderboard`. `None` if the player isn't in it.
+    pub rank: Option<u32>,
+}
+
+impl PlayerDashboard {
+    /// Bundle whatever is known about `wallet`. `tournament` should be the
+    /// active tournament, if any; `cached` the cached leaderboard entries.
+    pub fn build(
+        wallet: AccountOwner,
+        player: Option<PlayerInfo>,
+        game: Option<PlayerGameState>,
+        tournament: Option<&Tournament>,
+        cached: &[LeaderboardEntry],
+        now: u64,
+    ) -> Self {
+        let game = game.map(|game| game.visible(tournament));
+        let estimated_score = game.as_ref()
+            .zip(tournament)
+            .and_then(|(game, tournament)| game.estimated_score(tournament, now));
+        PlayerDashboard {
+            wallet,
+            player,
+            estimated_score,
+            cells_remaining: game.as_ref().map(PlayerGameState::cells_remaining),
+            digit_counts: game.as_ref().map(PlayerGameState::digit_counts),
+            rank: cached.iter().position(|e| e.wallet == wallet).map(|i| i as u32 + 1),
+            game,
+        }
+    }
+}
 
 /// A leaderboard entry representing a player's tournament performance.
-#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, InputObject)]
-#[graphql(input_name = "LeaderboardEntryInput")]
+///
+/// `Deserialize` is implemented in `migration` so entries stored before the
+/// pace fields existed (schema v0) still decode, with those fields zeroed.
+#[derive(Clone, Debug, Serialize, SimpleObject, InputObject)]
+#[graphql(complex, input_name = "LeaderboardEntryInput")]
 pub struct LeaderboardEntry {
     pub wallet: AccountOwner,
     pub discord_username: String,
@@ -421,19 +2261,526 @@ pub struct LeaderboardEntry {
     pub completed: bool,
     /// Timestamp (µs) of the player's first cell placement in this tournament.
     /// Used with `last_move_time_micros` to measure actual solving pace.
-    #[serde(default)]
     pub first_move_time_micros: u64,
     /// Timestamp (µs) of the player's most recent cell placement.
-    #[serde(default)]
     pub last_move_time_micros: u64,
     /// Set to `true` by the Hub when the player's average pace is
-    /// suspiciously fast (< 6 s per move measured from first to last move).
-    #[serde(default)]
+    /// suspiciously fast (< 6 s per move measured from first to last move),
+    /// or another finisher played the identical move sequence.
     pub is_suspicious: bool,
+    /// Non-given cells the player has filled, as of their last synced move.
+    pub cells_filled: u32,
+    /// Non-given cells still empty, as of their last synced move.
+    pub cells_remaining: u32,
+    /// Every synced placement folded in order with `fold_move_hash`, or 0
+    /// before the first one. Finishers sharing a hash played the exact same
+    /// moves in the same order (see `duplicate_sequences`).
+    pub move_sequence_hash: u64,
+    /// The game ended at `max_penalties`. Failed entries have `completed`
+    /// unset and score 0.
+    pub failed: bool,
+}
+
+#[ComplexObject]
+impl LeaderboardEntry {
+    /// Share of placements that were valid, in `0.0..=1.0`.
+    async fn accuracy(&self) -> Option<f64> {
+        self.placement_accuracy()
+    }
+}
+
+impl LeaderboardEntry {
+    /// `(move_count - penalty_count) / move_count`, or `None` before the
+    /// first move.
+    pub fn placement_accuracy(&self) -> Option<f64> {
+        if self.move_count == 0 {
+            return None;
+        }
+        let valid = self.move_count.saturating_sub(self.penalty_count);
+        Some(valid as f64 / self.move_count as f64)
+    }
+
+    /// Time from the player's first move to completion.
+    pub fn solve_duration_micros(&self) -> u64 {
+        self.completion_time_micros.saturating_sub(self.first_move_time_micros)
+    }
+
+    /// A Hub entry for a player whose first synced move was at
+    /// `first_move`, before any placement is recorded.
+    pub fn in_progress(wallet: AccountOwner, discord_username: String, first_move: u64) -> Self {
+        Self {
+            wallet,
+            discord_username,
+            score: 0,
+            completion_time_micros: 0,
+            penalty_count: 0,
+            move_count: 0,
+            completed: false,
+            first_move_time_micros: first_move,
+            last_move_time_micros: first_move,
+            is_suspicious: false,
+            cells_filled: 0,
+            cells_remaining: 0,
+            move_sequence_hash: MOVE_SEQUENCE_HASH_INIT,
+            failed: false,
+        }
+    }
+
+    /// Apply synced placements in order: move count, penalties, last move
+    /// time and sequence hash, flagging the entry once the average pace
+    /// from the first move drops under `SUSPICIOUS_PACE_SECS`. The pace is
+    /// checked after every move, so a batch flags exactly when the same
+    /// moves synced one at a time would. In a `blind` tournament the
+    /// penalty count stays 0 until `SyncBoardComplete` brings the final one.
+    pub fn record_placements(&mut self, moves: &[SyncedMove], blind: bool) {
+        for m in moves {
+            self.move_count += 1;
+            self.penalty_count = if blind { 0 } else { m.penalty_count };
+            self.last_move_time_micros = m.timestamp_micros;
+            self.move_sequence_hash =
+                fold_move_hash(self.move_sequence_hash, m.row, m.col, m.value);
+
+            // Detect suspicious pace: avg interval between moves
+            if self.move_count >= 5 && self.first_move_time_micros > 0 {
+                let solve_secs = m.timestamp_micros
+                    .saturating_sub(self.first_move_time_micros) / 1_000_000;
+                // N moves → N-1 intervals
+                let intervals = (self.move_count - 1) as u64;
+                let avg_pace = solve_secs.checked_div(intervals).unwrap_or(u64::MAX);
+                if avg_pace < SUSPICIOUS_PACE_SECS {
+                    self.is_suspicious = true;
+                }
+            }
+        }
+    }
+
+    /// Leaderboard ordering. Completed players rank above in-progress ones.
+    ///
+    /// - Completed: score desc, then completion time asc, then move count asc
+    ///   (fewer moves wins an exact score-and-time tie).
+    /// - In progress: estimated score desc, then penalties asc, then move
+    ///   count desc (more progress ranks higher).
+    ///
+    /// Players whose game ended at `max_penalties` rank below everyone
+    /// still playing, among themselves as in-progress players.
+    ///
+    /// Entries equal on all of these fall back to wallet order. That has no
+    /// bearing on merit; it only keeps the order stable between queries.
+    pub fn rank_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+        let (a, b) = (self, other);
+        match (a.failed, b.failed) {
+            (false, true) => return Ordering::Less,
+            (true, false) => return Ordering::Greater,
+            _ => {}
+        }
+        match (a.completed, b.completed) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (true, true) => {
+                b.score.cmp(&a.score)
+                    .then(a.completion_time_micros.cmp(&b.completion_time_micros))
+                    .then(a.move_count.cmp(&b.move_count))
+                    .then(a.wallet.cmp(&b.wallet))
+            }
+            (false, false) => {
+                b.score.cmp(&a.score)
+                    .then(a.penalty_count.cmp(&b.penalty_count))
+                    .then(b.move_count.cmp(&a.move_count))
+                    .then(a.wallet.cmp(&b.wallet))
+            }
+        }
+    }
+}
+
+/// Members whose scores count towards their team's score.
+pub const TEAM_SCORING_MEMBERS: usize = 3;
+
+/// A team's standing on the team leaderboard.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct TeamScore {
+    pub team: String,
+    /// Sum of the best `TEAM_SCORING_MEMBERS` member scores, so larger teams
+    /// don't win on head count alone.
+    pub score: u64,
+    /// Members with a leaderboard entry.
+    pub member_count: u32,
+    pub completions: u32,
+}
+
+impl TeamScore {
+    /// Group `(team, entry)` pairs into team scores, best team first
+    /// (ties broken by team name).
+    pub fn rank(members: &[(String, LeaderboardEntry)]) -> Vec<TeamScore> {
+        let mut by_team: std::collections::BTreeMap<&str, Vec<&LeaderboardEntry>> =
+            std::collections::BTreeMap::new();
+        for (team, entry) in members {
+            by_team.entry(team.as_str()).or_default().push(entry);
+        }
+
+        let mut teams: Vec<TeamScore> = by_team
+            .into_iter()
+            .map(|(team, mut entries)| {
+                entries.sort_by(|a, b| b.score.cmp(&a.score));
+                TeamScore {
+                    team: team.to_string(),
+                    score: entries.iter().take(TEAM_SCORING_MEMBERS).map(|e| e.score).sum(),
+                    member_count: entries.len() as u32,
+                    completions: entries.iter().filter(|e| e.completed).count() as u32,
+                }
+            })
+            .collect();
+        teams.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.team.cmp(&b.team)));
+        teams
+    }
+}
+
+/// A run of tournaments whose scores add up to one standings table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct Season {
+    pub id: u64,
+    pub started_at_micros: u64,
+    /// Set by `EndSeason`; `None` while the season is running.
+    pub ended_at_micros: Option<u64>,
+    /// Season points by finishing place, 1st first. Empty: tournament
+    /// scores are summed instead.
+    #[serde(default)]
+    pub placement_points: Vec<u64>,
+}
+
+/// Formula 1 style placement table for `StartSeason`.
+pub const F1_PLACEMENT_POINTS: [u64; 10] = [25, 18, 15, 12, 10, 8, 6, 4, 2, 1];
+
+/// Most places a season's placement table may award.
+pub const MAX_PLACEMENT_POINTS: usize = 100;
+
+/// Whether `table` is usable as a season's placement points.
+pub fn is_valid_points_table(table: &[u64]) -> bool {
+    !table.is_empty() && table.len() <= MAX_PLACEMENT_POINTS
+}
+
+impl Season {
+    pub fn is_active(&self) -> bool {
+        self.ended_at_micros.is_none()
+    }
+
+    /// Whether points are awarded by finishing place rather than score.
+    pub fn uses_placement(&self) -> bool {
+        !self.placement_points.is_empty()
+    }
+
+    /// Points a completion earns towards the season: the tournament score,
+    /// unless the season is over, scores by placement, or this isn't the
+    /// wallet's `first_completion` of the tournament (see
+    /// `is_first_completion`).
+    pub fn points_for(&self, first_completion: bool, score: u64) -> u64 {
+        if !self.is_active() || self.uses_placement() || !first_completion {
+            return 0;
+        }
+        score
+    }
+
+    /// Placement points for an ended tournament's `rankings` (in rank
+    /// order). Only completers place; places past the table earn nothing.
+    pub fn placement_awards(&self, rankings: &[LeaderboardEntry]) -> Vec<(AccountOwner, u64)> {
+        if !self.is_active() {
+            return Vec::new();
+        }
+        rankings.iter()
+            .filter(|e| e.completed && !e.failed)
+            .zip(&self.placement_points)
+            .filter(|(_, &points)| points > 0)
+            .map(|(e, &points)| (e.wallet, points))
+            .collect()
+    }
+}
+
+/// A player's standing on the season leaderboard.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct SeasonStanding {
+    pub wallet: AccountOwner,
+    pub discord_username: String,
+    /// Sum of the player's tournament scores this season.
+    pub points: u64,
+}
+
+impl SeasonStanding {
+    /// Most points first (ties broken by wallet).
+    pub fn rank(mut standings: Vec<SeasonStanding>) -> Vec<SeasonStanding> {
+        standings.sort_by(|a, b| b.points.cmp(&a.points).then_with(|| a.wallet.cmp(&b.wallet)));
+        standings
+    }
+}
+
+/// One player's live progress, as the Hub last heard it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct ProgressEntry {
+    pub wallet: AccountOwner,
+    pub discord_username: String,
+    pub cells_filled: u32,
+    pub cells_remaining: u32,
+    pub penalty_count: u32,
+    pub completed: bool,
+}
+
+impl ProgressEntry {
+    /// Every player's progress, closest to finishing first.
+    pub fn from_entries(entries: &[LeaderboardEntry]) -> Vec<ProgressEntry> {
+        let mut progress: Vec<ProgressEntry> = entries.iter()
+            .map(|e| ProgressEntry {
+                wallet: e.wallet,
+                discord_username: e.discord_username.clone(),
+                cells_filled: e.cells_filled,
+                cells_remaining: e.cells_remaining,
+                penalty_count: e.penalty_count,
+                completed: e.completed,
+            })
+            .collect();
+        progress.sort_by(|a, b| {
+            b.completed.cmp(&a.completed)
+                .then(a.cells_remaining.cmp(&b.cells_remaining))
+                .then(a.penalty_count.cmp(&b.penalty_count))
+        });
+        progress
+    }
+}
+
+/// `wallet`'s 1-based position among the completed entries in rank order,
+/// or `None` if it hasn't completed.
+pub fn completion_rank(entries: &[LeaderboardEntry], wallet: &AccountOwner) -> Option<u32> {
+    let mut completed: Vec<&LeaderboardEntry> = entries.iter().filter(|e| e.completed).collect();
+    completed.sort_by(|a, b| a.rank_cmp(b));
+    completed.iter().position(|e| &e.wallet == wallet).map(|i| i as u32 + 1)
+}
+
+/// Whether a completion of `tournament_id` is the wallet's first: `existing`
+/// (its leaderboard entry) hasn't completed, and `counted_for` (the latest
+/// tournament its completion was counted for) is another tournament. The
+/// second check covers `ResetPlayerGame`, which drops the entry, so a
+/// repeated completion sync or a replay after a reset counts only once.
+pub fn is_first_completion(
+    existing: Option<&LeaderboardEntry>,
+    counted_for: Option<u64>,
+    tournament_id: u64,
+) -> bool {
+    !existing.is_some_and(|e| e.completed) && counted_for != Some(tournament_id)
+}
+
+/// Whether `wallet`'s completion streak survives a tournament that ended
+/// with `rankings`: only players who finished it keep theirs. Anyone who
+/// played without finishing, or didn't play at all, starts over.
+pub fn keeps_streak(rankings: &[LeaderboardEntry], wallet: &AccountOwner) -> bool {
+    rankings.iter().any(|e| &e.wallet == wallet && e.completed)
+}
+
+/// Sort `entries` into leaderboard order (see `LeaderboardEntry::rank_cmp`).
+/// Never drops entries; callers truncate for display.
+pub fn rank_order(mut entries: Vec<LeaderboardEntry>) -> Vec<LeaderboardEntry> {
+    entries.sort_by(LeaderboardEntry::rank_cmp);
+    entries
+}
+
+/// Most tournaments kept in a player's history; older ones are dropped.
+pub const MAX_PLAYER_HISTORY: usize = 100;
+
+/// Note that a player took part in `tournament_id`, unless it's already
+/// the latest entry. Keeps the newest `MAX_PLAYER_HISTORY`.
+pub fn add_participation(ids: &mut Vec<u64>, tournament_id: u64) {
+    if ids.last() == Some(&tournament_id) {
+        return;
+    }
+    ids.push(tournament_id);
+    if ids.len() > MAX_PLAYER_HISTORY {
+        ids.drain(..ids.len() - MAX_PLAYER_HISTORY);
+    }
+}
+
+/// One tournament in a player's history.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct TournamentResult {
+    pub tournament_id: u64,
+    pub score: u64,
+    /// 1-based position among every entry, finished or not.
+    pub rank: u32,
+    pub completed: bool,
+    pub failed: bool,
+}
+
+impl TournamentResult {
+    /// `wallet`'s result in `rankings` (already in rank order), or `None`
+    /// if it has no entry.
+    pub fn from_rankings(
+        tournament_id: u64,
+        rankings: &[LeaderboardEntry],
+        wallet: &AccountOwner,
+    ) -> Option<Self> {
+        let index = rankings.iter().position(|e| &e.wallet == wallet)?;
+        let entry = &rankings[index];
+        Some(TournamentResult {
+            tournament_id,
+            score: entry.score,
+            rank: index as u32 + 1,
+            completed: entry.completed,
+            failed: entry.failed,
+        })
+    }
+}
+
+/// Finishers only, fastest first-move-to-completion time first, ignoring
+/// score and penalties. Ties go to whoever completed earlier.
+pub fn fastest_finishers(entries: &[LeaderboardEntry]) -> Vec<LeaderboardEntry> {
+    let mut finishers: Vec<LeaderboardEntry> = entries.iter()
+        .filter(|e| e.completed)
+        .cloned()
+        .collect();
+    finishers.sort_by(|a, b| {
+        a.solve_duration_micros().cmp(&b.solve_duration_micros())
+            .then(a.completion_time_micros.cmp(&b.completion_time_micros))
+    });
+    finishers
+}
+
+/// Players still solving at `now_micros`: not finished (or out of the game)
+/// and with a synced move within the last `window_micros`.
+pub fn count_active(entries: &[LeaderboardEntry], now_micros: u64, window_micros: u64) -> u32 {
+    let since = now_micros.saturating_sub(window_micros);
+    entries.iter()
+        .filter(|e| !e.completed && !e.failed && e.last_move_time_micros >= since)
+        .count() as u32
+}
+
+/// How many of `last_seen` heartbeat times fall within `window_micros`
+/// before `now_micros`.
+pub fn count_online(
+    last_seen: impl IntoIterator<Item = u64>,
+    now_micros: u64,
+    window_micros: u64,
+) -> u32 {
+    let since = now_micros.saturating_sub(window_micros);
+    last_seen.into_iter().filter(|&seen| seen >= since).count() as u32
+}
+
+/// `LeaderboardEntry::move_sequence_hash` before any move is folded in
+/// (the 64-bit FNV-1a offset basis).
+pub const MOVE_SEQUENCE_HASH_INIT: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Minimum average seconds per move before the Hub flags a player.
+pub const SUSPICIOUS_PACE_SECS: u64 = 6;
+
+/// Largest `SetSyncBatchSize`.
+pub const MAX_SYNC_BATCH: u32 = 20;
+
+/// One placement as synced to the Hub in a `SyncCellBatch`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedMove {
+    pub row: u8,
+    pub col: u8,
+    pub value: u8,
+    pub timestamp_micros: u64,
+    /// The player's penalty count after this move.
+    pub penalty_count: u32,
+}
+
+/// Fold one placement into a running move sequence hash (FNV-1a over the
+/// row, column and value bytes).
+pub fn fold_move_hash(hash: u64, row: u8, col: u8, value: u8) -> u64 {
+    [row, col, value].iter().fold(hash, |h, &byte| {
+        (h ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Finishers who share a move sequence hash.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct DuplicateSequence {
+    pub move_sequence_hash: u64,
+    pub wallets: Vec<AccountOwner>,
+}
+
+/// Groups of two or more finishers with identical move sequences, ordered
+/// by hash; wallets keep their order in `entries`. Entries without a hash
+/// (0: no synced placements) are never grouped.
+pub fn duplicate_sequences(entries: &[LeaderboardEntry]) -> Vec<DuplicateSequence> {
+    let mut by_hash: std::collections::BTreeMap<u64, Vec<AccountOwner>> =
+        std::collections::BTreeMap::new();
+    for entry in entries.iter().filter(|e| e.completed && e.move_sequence_hash != 0) {
+        by_hash.entry(entry.move_sequence_hash).or_default().push(entry.wallet);
+    }
+    by_hash.into_iter()
+        .filter(|(_, wallets)| wallets.len() > 1)
+        .map(|(move_sequence_hash, wallets)| DuplicateSequence { move_sequence_hash, wallets })
+        .collect()
+}
+
+/// Where `candidate` would place if it were inserted into `entries` (rank
+/// order, 1-based). Any entry already held by the candidate's wallet is
+/// ignored; `entries` itself is not modified.
+pub fn projected_rank(entries: &[LeaderboardEntry], candidate: &LeaderboardEntry) -> u32 {
+    let ahead = entries.iter()
+        .filter(|e| e.wallet != candidate.wallet)
+        .filter(|e| e.rank_cmp(candidate) == std::cmp::Ordering::Less)
+        .count();
+    ahead as u32 + 1
+}
+
+/// Percentage (0-100) of `scores` strictly below `score`, as in "you beat
+/// 80% of players". 0 when there are no scores.
+pub fn score_percentile(scores: impl IntoIterator<Item = u64>, score: u64) -> f64 {
+    let (mut below, mut total) = (0u64, 0u64);
+    for s in scores {
+        total += 1;
+        if s < score {
+            below += 1;
+        }
+    }
+    if total == 0 {
+        return 0.0;
+    }
+    below as f64 * 100.0 / total as f64
+}
+
+/// Longest reason accepted for an admin score override.
+pub const MAX_ADJUSTMENT_REASON_LEN: usize = 200;
+
+/// An admin's manual correction of a player's score (`SetPlayerScore`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct ScoreAdjustment {
+    pub wallet: AccountOwner,
+    pub tournament_id: u64,
+    pub previous_score: u64,
+    pub new_score: u64,
+    pub reason: String,
+    pub admin: AccountOwner,
+    pub at_micros: u64,
+}
+
+impl ScoreAdjustment {
+    /// Set `entry`'s score and describe the change for the audit log.
+    pub fn apply(
+        entry: &mut LeaderboardEntry,
+        tournament_id: u64,
+        new_score: u64,
+        reason: String,
+        admin: AccountOwner,
+        at_micros: u64,
+    ) -> ScoreAdjustment {
+        let previous_score = std::mem::replace(&mut entry.score, new_score);
+        ScoreAdjustment {
+            wallet: entry.wallet,
+            tournament_id,
+            previous_score,
+            new_score,
+            reason,
+            admin,
+            at_micros,
+        }
+    }
 }
 
 /// A cached leaderboard response stored on a player's chain.
-#[derive(Clone, Debug, Default, Serialize, Deserialize, SimpleObject)]
+///
+/// `Deserialize` is implemented in `migration`: a cache written with an
+/// older `LeaderboardEntry` layout reads as empty instead of failing.
+#[derive(Clone, Debug, Default, Serialize, SimpleObject)]
 pub struct CachedLeaderboard {
     pub entries: Vec<LeaderboardEntry>,
     pub tournament_id: u64,
@@ -441,14 +2788,374 @@ pub struct CachedLeaderboard {
     pub fetched_at_micros: u64,
 }
 
+/// How long a `RequestLeaderboard` may stay unanswered before it is reported
+/// as stale (30 s).
+pub const LEADERBOARD_REQUEST_TIMEOUT_MICROS: u64 = 30_000_000;
+
+/// Lifecycle of the player chain's most recent `RequestLeaderboard`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum LeaderboardRequestStatus {
+    /// No request has been made and nothing is cached.
+    Idle,
+    /// A request was sent and the Hub hasn't answered yet.
+    Pending,
+    /// The cached leaderboard is at least as new as the last request.
+    Fulfilled,
+    /// The Hub hasn't answered within `LEADERBOARD_REQUEST_TIMEOUT_MICROS`.
+    Stale,
+}
+
+impl LeaderboardRequestStatus {
+    /// Derive the status from the pending request timestamp and the
+    /// cached leaderboard's `fetched_at_micros`.
+    pub fn compute(requested_at: Option<u64>, fetched_at: Option<u64>, now_micros: u64) -> Self {
+        match (requested_at, fetched_at) {
+            (Some(requested), Some(fetched)) if fetched >= requested => Self::Fulfilled,
+            (Some(requested), _) => {
+                if now_micros.saturating_sub(requested) > LEADERBOARD_REQUEST_TIMEOUT_MICROS {
+                    Self::Stale
+                } else {
+                    Self::Pending
+                }
+            }
+            (None, Some(_)) => Self::Fulfilled,
+            (None, None) => Self::Idle,
+        }
+    }
+}
+
+const MICROS_PER_HOUR: u64 = 3_600_000_000;
+const MICROS_PER_DAY: u64 = 24 * MICROS_PER_HOUR;
+const MICROS_PER_WEEK: u64 = 7 * MICROS_PER_DAY;
+
+/// Named scoring presets. Weights are points lost per minute of solving
+/// time and per invalid placement.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum ScoringMode {
+    /// 120 points per minute, 100 per penalty.
+    #[default]
+    Balanced,
+    /// Raw speed: 120 points per minute, penalties are free.
+    Speedrun,
+    /// Precision: 10 points per minute, 1000 per penalty.
+    Accuracy,
+}
+
+impl ScoringMode {
+    /// `(points per minute, points per penalty)`.
+    pub fn weights(self) -> (u64, u64) {
+        match self {
+            ScoringMode::Balanced => (120, 100),
+            ScoringMode::Speedrun => (120, 0),
+            ScoringMode::Accuracy => (10, 1_000),
+        }
+    }
+}
+
+/// The score for a solve, shared by player chains and the Hub. Higher is
+/// better, starting from 10000 and never below 0:
+///   score = 10000 - elapsed_minutes × time_weight - penalties × penalty_weight
+/// with `elapsed_minutes` fractional (whole seconds), weights per `mode`.
+pub fn compute_score(elapsed_micros: u64, penalty_count: u32, mode: ScoringMode) -> u64 {
+    let (per_minute, per_penalty) = mode.weights();
+    let elapsed_secs = elapsed_micros / 1_000_000;
+    let time_penalty = elapsed_secs.saturating_mul(per_minute) / 60;
+    let move_penalty = (penalty_count as u64).saturating_mul(per_penalty);
+    10_000u64
+        .saturating_sub(time_penalty)
+        .saturating_sub(move_penalty)
+}
+
+/// Day of the week (UTC) for the weekly schedule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// How a weekly auto-started tournament picks its seed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum SeedStrategy {
+    /// Number of weeks since the first slot after the Unix epoch. Known in
+    /// advance, so only suitable for testing and practice runs.
+    WeekNumber,
+    /// Hub block time at activation mixed with the tournament counter.
+    ActivationTime,
+}
+
+/// A recurring weekly tournament slot (Hub only).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct WeeklySchedule {
+    pub weekday: Weekday,
+    /// Start hour, 0-23 UTC.
+    pub hour_utc: u8,
+    pub duration_secs: u64,
+    pub seed_strategy: SeedStrategy,
+}
+
+impl WeeklySchedule {
+    /// Offset of the slot from the Unix epoch within the first week.
+    /// 1970-01-01 was a Thursday, so Monday is 4 days in (mod 7).
+    fn first_slot_micros(&self) -> u64 {
+        ((self.weekday as u64 + 4) % 7) * MICROS_PER_DAY + self.hour_utc as u64 * MICROS_PER_HOUR
+    }
+
+    /// Start of the most recent slot at or before `now_micros`.
+    pub fn slot_at_or_before(&self, now_micros: u64) -> Option<u64> {
+        let first = self.first_slot_micros();
+        if now_micros < first {
+            return None;
+        }
+        Some(first + (now_micros - first) / MICROS_PER_WEEK * MICROS_PER_WEEK)
+    }
+
+    /// Start of the first slot at or after `now_micros`.
+    pub fn next_start_at_or_after(&self, now_micros: u64) -> u64 {
+        match self.slot_at_or_before(now_micros) {
+            Some(slot) if slot == now_micros => slot,
+            Some(slot) => slot + MICROS_PER_WEEK,
+            None => self.first_slot_micros(),
+        }
+    }
+
+    /// Weeks elapsed between the first slot and `slot_micros`.
+    pub fn week_number(&self, slot_micros: u64) -> u64 {
+        slot_micros.saturating_sub(self.first_slot_micros()) / MICROS_PER_WEEK
+    }
+}
+
+/// Grid size. Mini boards (for beginners and kids' events) use the
+/// top-left corner of the usual 9×9 arrays; the cells outside it stay 0
+/// and count as givens, so they can't be played.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum BoardSize {
+    /// 9×9 with 3×3 boxes.
+    #[default]
+    Nine,
+    /// 6×6 with 2×3 boxes (2 rows, 3 columns).
+    Six,
+    /// 4×4 with 2×2 boxes.
+    Four,
+}
+
+impl BoardSize {
+    /// Rows (and columns), which is also the largest value.
+    pub fn side(self) -> usize {
+        match self {
+            BoardSize::Nine => 9,
+            BoardSize::Six => 6,
+            BoardSize::Four => 4,
+        }
+    }
+
+    /// `(rows, cols)` of one box.
+    pub fn box_shape(self) -> (usize, usize) {
+        match self {
+            BoardSize::Nine => (3, 3),
+            BoardSize::Six => (2, 3),
+            BoardSize::Four => (2, 2),
+        }
+    }
+
+    /// Variant regions are laid out for 9×9, so mini boards are `Classic` only.
+    pub fn supports(self, variant: SudokuVariant) -> bool {
+        self == BoardSize::Nine || variant == SudokuVariant::Classic
+    }
+
+    /// Whether `(row, col)` lies on the board.
+    pub fn contains(self, row: usize, col: usize) -> bool {
+        row < self.side() && col < self.side()
+    }
+}
+
+/// The rule set a puzzle is generated and validated under.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum SudokuVariant {
+    /// Rows, columns and 3×3 boxes.
+    #[default]
+    Classic,
+    /// Classic plus both main diagonals must contain 1-9.
+    XSudoku,
+    /// Classic plus four shaded 3×3 "windoku" windows must contain 1-9.
+    HyperSudoku,
+}
+
+/// Puzzle difficulty, by the simplest technique that solves it
+/// (see `sudoku::rate`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum Difficulty {
+    /// Solvable with naked singles alone.
+    Easy,
+    /// Needs hidden singles.
+    Medium,
+    /// Needs techniques beyond singles.
+    Hard,
+}
+
+/// Outcome of `ValidatePuzzleString` (see `sudoku::validate_puzzle_string`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct PuzzleValidation {
+    /// Whether the string is 81 cells of `1`-`9`, `0` or `.`.
+    pub parses: bool,
+    pub givens: u32,
+    /// Solutions found, counting stops at 2.
+    pub solution_count: u32,
+    pub unique: bool,
+    /// Set only for puzzles with a unique solution.
+    pub difficulty: Option<Difficulty>,
+    /// Why the puzzle was rejected, if it was.
+    pub error: Option<String>,
+}
+
+/// How the blank cells of a puzzle mirror each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum Symmetry {
+    /// Blanks mirror through the centre cell (a 180° turn).
+    Rotational,
+    /// Blanks mirror across the top-left to bottom-right diagonal.
+    Diagonal,
+    /// Neither pattern holds.
+    Asymmetric,
+}
+
+/// Everything needed to reproduce the active puzzle offline with
+/// `sudoku::generate_puzzle_sized(seed, variant, board_size)`. Never
+/// includes the solution.
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct PuzzleParams {
+    pub seed: u64,
+    pub variant: SudokuVariant,
+    pub board_size: BoardSize,
+    /// Informational rating of the generated puzzle; not a generator input.
+    pub difficulty: Difficulty,
+    pub cells_removed: u32,
+}
+
+/// The current puzzle with the metadata a frontend needs to render it, in
+/// one call. Never includes the solution.
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct PuzzleInfo {
+    /// Row-major grid, 0 for empty cells; side × side for mini boards.
+    pub grid: Vec<Vec<u8>>,
+    /// The same grid as one character per cell (81 on a 9×9 board), `.`
+    /// for empty cells.
+    pub puzzle_string: String,
+    pub givens: u32,
+    pub variant: SudokuVariant,
+    pub difficulty: Difficulty,
+}
+
+impl PuzzleInfo {
+    pub fn from_board(board: &SudokuBoard) -> Self {
+        let grid = board.grid();
+        let cells = grid.iter().flatten();
+        PuzzleInfo {
+            puzzle_string: cells.clone()
+                .map(|&v| if v == 0 { '.' } else { (b'0' + v) as char })
+                .collect(),
+            givens: cells.filter(|&&v| v != 0).count() as u32,
+            grid,
+            variant: board.variant,
+            difficulty: sudoku::rate_sized(&board.puzzle, board.variant, board.size),
+        }
+    }
+}
+
+/// Most cells one `ClearCells` operation may touch.
+pub const MAX_CLEAR_CELLS: usize = 20;
+
+/// What `ClearCells` did with one requested cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum ClearOutcome {
+    Cleared,
+    /// Givens can't be cleared.
+    Given,
+    AlreadyEmpty,
+    /// Row or column above 8.
+    OutOfRange,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct ClearCellResult {
+    pub row: u8,
+    pub col: u8,
+    pub outcome: ClearOutcome,
+}
+
+/// A single cell coordinate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct CellPosition {
+    pub row: u8,
+    pub col: u8,
+}
+
 /// Sudoku puzzle board with puzzle and solution.
-/// The solution is NEVER exposed through the service GraphQL layer.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// The solution is NEVER exposed through the service GraphQL layer while the
+/// tournament is active; `revealSolution` publishes it once it has ended.
+///
+/// `Deserialize` is implemented in `migration` so puzzles stored before
+/// `variant` and `size` existed (schema v0) decode as classic 9×9.
+#[derive(Clone, Debug, Serialize)]
 pub struct SudokuBoard {
     /// The puzzle grid (0 = empty, 1-9 = given value).
     pub puzzle: [[u8; 9]; 9],
     /// The complete solution grid.
     pub solution: [[u8; 9]; 9],
+    /// The rules the grid was generated under.
+    pub variant: SudokuVariant,
+    /// The grid size; cells outside a mini board are 0 in both grids.
+    pub size: BoardSize,
+}
+
+impl SudokuBoard {
+    /// Cells a player has to fill.
+    pub fn open_cells(&self) -> u32 {
+        let side = self.size.side();
+        self.puzzle.iter().take(side).flat_map(|row| &row[..side]).filter(|&&v| v == 0).count() as u32
+    }
+
+    /// The puzzle's playable cells, side × side, 0 for blanks.
+    pub fn grid(&self) -> Vec<Vec<u8>> {
+        let side = self.size.side();
+        self.puzzle.iter().take(side).map(|row| row[..side].to_vec()).collect()
+    }
+
+    /// Which of `grid`'s cells are givens.
+    pub fn givens_mask(&self) -> Vec<Vec<bool>> {
+        self.grid().iter().map(|row| row.iter().map(|&v| v != 0).collect()).collect()
+    }
+
+    /// Fold synced placements into `correct`, the Hub's bitset (bit
+    /// `row * 9 + col`) of a player's cells whose latest placement matches
+    /// the solution. Clears aren't synced, so a cell stays set until a
+    /// wrong value is placed over it.
+    pub fn mark_correct(&self, mut correct: u128, moves: &[SyncedMove]) -> u128 {
+        for m in moves.iter().filter(|m| m.row < 9 && m.col < 9) {
+            let bit = 1u128 << (m.row as usize * 9 + m.col as usize);
+            if self.solution[m.row as usize][m.col as usize] == m.value {
+                correct |= bit;
+            } else {
+                correct &= !bit;
+            }
+        }
+        correct
+    }
+
+    /// Which 3×3 boxes (row-major) are filled: every cell given or set in
+    /// `correct` (see `mark_correct`).
+    pub fn filled_boxes(&self, correct: u128) -> [bool; 9] {
+        std::array::from_fn(|b| {
+            (0..9).all(|i| {
+                let (r, c) = (b / 3 * 3 + i / 3, b % 3 * 3 + i % 3);
+                self.puzzle[r][c] != 0 || correct & (1 << (r * 9 + c)) != 0
+            })
+        })
+    }
 }
 
 /// Input for move verification queries.
@@ -459,8 +3166,18 @@ pub struct MoveInput {
     pub value: u8,
 }
 
-/// Result of a game verification replay.
-#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+/// A move with its playing time, for `verify_game_timed`.
+#[derive(Clone, Debug, Serialize, Deserialize, InputObject)]
+pub struct TimedMoveInput {
+    pub row: u8,
+    pub col: u8,
+    pub value: u8,
+    /// The matching `move_times` entry.
+    pub elapsed_micros: u64,
+}
+
+/// Result of a game verification replay.
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
 pub struct VerifyResult {
     pub valid: bool,
     pub total_moves: u32,
@@ -478,6 +3195,214 @@ pub struct TournamentStats {
     pub average_score: u64,
     pub best_score: u64,
     pub is_active: bool,
+    /// Total moves divided by total active solving time (first to last
+    /// move, summed over players), in moves per minute. 0 if no time elapsed.
+    pub average_moves_per_minute: u64,
+    /// Shortest first-move-to-completion duration among finishers (0 if none).
+    pub fastest_completion_micros: u64,
+    /// Median score over all leaderboard entries, completed or not. With an
+    /// even count this is the mean of the two middle scores.
+    pub median_score: u64,
+}
+
+impl TournamentStats {
+    /// Aggregate stats for `tournament` from its leaderboard entries.
+    pub fn from_entries(tournament: &Tournament, entries: &[LeaderboardEntry]) -> Self {
+        let mut total_completions = 0u32;
+        let mut total_score = 0u64;
+        let mut best_score = 0u64;
+        let mut total_moves = 0u64;
+        let mut total_active_micros = 0u64;
+        let mut fastest_completion_micros: Option<u64> = None;
+        let mut scores = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            if entry.completed {
+                total_completions += 1;
+                let duration = entry.solve_duration_micros();
+                fastest_completion_micros = Some(
+                    fastest_completion_micros.map_or(duration, |f| f.min(duration)),
+                );
+            }
+            total_score += entry.score;
+            best_score = best_score.max(entry.score);
+            scores.push(entry.score);
+            total_moves += entry.move_count as u64;
+            total_active_micros += entry.last_move_time_micros
+                .saturating_sub(entry.first_move_time_micros);
+        }
+
+        let total_players = entries.len() as u32;
+        let average_score = if total_players > 0 {
+            total_score / total_players as u64
+        } else {
+            0
+        };
+        let average_moves_per_minute = if total_active_micros > 0 {
+            total_moves.saturating_mul(60_000_000) / total_active_micros
+        } else {
+            0
+        };
+
+        scores.sort_unstable();
+        let mid = scores.len() / 2;
+        let median_score = match scores.len() {
+            0 => 0,
+            n if n % 2 == 1 => scores[mid],
+            _ => (scores[mid - 1] + scores[mid]) / 2,
+        };
+
+        TournamentStats {
+            tournament_id: tournament.id,
+            total_players,
+            total_completions,
+            average_score,
+            best_score,
+            is_active: tournament.active,
+            average_moves_per_minute,
+            fastest_completion_micros: fastest_completion_micros.unwrap_or(0),
+            median_score,
+        }
+    }
+}
+
+/// Post-event digest of one tournament, for organizers to post.
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct TournamentReport {
+    pub tournament: Tournament,
+    /// Every entry, best first (`rank_cmp`).
+    pub rankings: Vec<LeaderboardEntry>,
+    pub stats: TournamentStats,
+    pub suspicious_players: u32,
+    /// Players whose game ended at `max_penalties`.
+    pub failed_players: u32,
+    /// The finisher with the shortest first-move-to-completion time.
+    pub fastest_finisher: Option<LeaderboardEntry>,
+    /// Moves made by all players combined.
+    pub total_moves: u64,
+}
+
+impl TournamentReport {
+    pub fn build(tournament: Tournament, entries: Vec<LeaderboardEntry>) -> Self {
+        let stats = TournamentStats::from_entries(&tournament, &entries);
+        let rankings = rank_order(entries);
+        TournamentReport {
+            suspicious_players: rankings.iter().filter(|e| e.is_suspicious).count() as u32,
+            failed_players: rankings.iter().filter(|e| e.failed).count() as u32,
+            fastest_finisher: fastest_finishers(&rankings).into_iter().next(),
+            total_moves: rankings.iter().map(|e| e.move_count as u64).sum(),
+            tournament,
+            rankings,
+            stats,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Username helpers
+// ---------------------------------------------------------------------------
+
+/// Normalize a Discord username for uniqueness checks.
+/// Discord usernames are case-insensitive, so `Alice` and `alice ` collide.
+pub fn normalize_username(username: &str) -> String {
+    username.trim().to_lowercase()
+}
+
+/// Whether `wallet` may claim a username that the Hub's `usernames` map
+/// gives to `holder`: nobody holds it, or `wallet` already does.
+pub fn may_claim_username(holder: Option<AccountOwner>, wallet: &AccountOwner) -> bool {
+    holder.is_none_or(|holder| holder == *wallet)
+}
+
+/// The profile a player chain keeps after the Hub rejects `rejected`
+/// (`UsernameRejected`). `local` goes back to `current_username`, or is
+/// dropped (`None`) if the Hub has never seen the wallet. A player who has
+/// already picked another name keeps it.
+pub fn roll_back_username(
+    local: Option<PlayerInfo>,
+    rejected: &str,
+    current_username: Option<String>,
+) -> Option<PlayerInfo> {
+    let mut player = local?;
+    if player.discord_username != rejected {
+        return Some(player);
+    }
+    player.discord_username = current_username?;
+    Some(player)
+}
+
+/// Validate a Discord username before it is stored on-chain.
+///
+/// Accepts 1-32 bytes of ASCII alphanumerics plus `.`, `_` and `-`.
+/// Returns a human-readable reason on rejection.
+pub fn validate_username(username: &str) -> Result<(), String> {
+    if username.is_empty() || username.len() > 32 {
+        return Err("Discord username must be 1-32 characters".into());
+    }
+    if username.chars().any(char::is_control) {
+        return Err("Discord username must not contain control characters".into());
+    }
+    if username.trim() != username {
+        return Err("Discord username must not start or end with whitespace".into());
+    }
+    if let Some(c) = username
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '.' | '_' | '-'))
+    {
+        return Err(format!("Discord username contains disallowed character '{}'", c));
+    }
+    Ok(())
+}
+
+/// Longest preferences blob a player may store, in bytes.
+pub const MAX_PREFERENCES_LEN: usize = 256;
+
+/// Check a `SetPreferences` blob: at most `MAX_PREFERENCES_LEN` bytes and
+/// no control characters. The contents are otherwise left to the frontend.
+pub fn validate_preferences(preferences: &str) -> Result<(), String> {
+    if preferences.len() > MAX_PREFERENCES_LEN {
+        return Err(format!("Preferences must be at most {} bytes", MAX_PREFERENCES_LEN));
+    }
+    if preferences.chars().any(char::is_control) {
+        return Err("Preferences must not contain control characters".into());
+    }
+    Ok(())
+}
+
+/// Longest announcement the admin may broadcast, in bytes.
+pub const MAX_ANNOUNCEMENT_LEN: usize = 280;
+
+/// Check an `Announce` text: 1-`MAX_ANNOUNCEMENT_LEN` bytes, no control
+/// characters.
+pub fn validate_announcement(text: &str) -> Result<(), String> {
+    if text.trim().is_empty() || text.len() > MAX_ANNOUNCEMENT_LEN {
+        return Err(format!("Announcement must be 1-{} bytes", MAX_ANNOUNCEMENT_LEN));
+    }
+    if text.chars().any(char::is_control) {
+        return Err("Announcement must not contain control characters".into());
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Board arguments
+// ---------------------------------------------------------------------------
+
+/// Check a whole-board argument from GraphQL before anything indexes into
+/// it: 9 rows of 9 values, each 0 (empty) to 9. Ragged or mis-sized input
+/// is `InvalidBoard`; a value above 9 is `InvalidCell`.
+pub fn validate_board_arg(board: &[Vec<u8>]) -> Result<[[u8; 9]; 9], ErrorCode> {
+    if board.len() != 9 || board.iter().any(|row| row.len() != 9) {
+        return Err(ErrorCode::InvalidBoard);
+    }
+    let mut grid = [[0u8; 9]; 9];
+    for (target, row) in grid.iter_mut().zip(board) {
+        if row.iter().any(|&value| value > 9) {
+            return Err(ErrorCode::InvalidCell);
+        }
+        target.copy_from_slice(row);
+    }
+    Ok(grid)
 }
 
 // ---------------------------------------------------------------------------
@@ -486,3 +3411,1728 @@ pub struct TournamentStats {
 
 /// The stream name used for tournament event broadcasting.
 pub const TOURNAMENT_STREAM: &[u8] = b"tournament";
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_username_accepts_valid_names() {
+        for name in ["alice", "Bob_99", "x", "the.real-one", "a".repeat(32).as_str()] {
+            assert!(validate_username(name).is_ok(), "{} should be valid", name);
+        }
+    }
+
+    #[test]
+    fn test_username_conflict_rejects_and_rolls_back() {
+        let ada = AccountOwner::Address20([1; 20]);
+        let bob = AccountOwner::Address20([2; 20]);
+        let player = |name: &str| PlayerInfo {
+            wallet: bob,
+            discord_username: name.into(),
+            registered_at_micros: 0,
+            team: None,
+            preferences: None,
+        };
+
+        // Hub: "Ada" is held by ada, so bob's claim of "ada " is refused.
+        assert!(may_claim_username(None, &bob));
+        assert!(may_claim_username(Some(bob), &bob));
+        assert_eq!(normalize_username("ada "), normalize_username("Ada"));
+        assert!(!may_claim_username(Some(ada), &bob));
+
+        // Player chain: a rename goes back to the name the Hub has...
+        let restored = roll_back_username(Some(player("ada ")), "ada ", Some("bob".into()));
+        assert_eq!(restored.unwrap().discord_username, "bob");
+        // ...a first registration is dropped...
+        assert!(roll_back_username(Some(player("ada ")), "ada ", None).is_none());
+        // ...and a name picked since the rejected one is kept.
+        let kept = roll_back_username(Some(player("bobby")), "ada ", Some("bob".into()));
+        assert_eq!(kept.unwrap().discord_username, "bobby");
+        assert!(roll_back_username(None, "ada ", Some("bob".into())).is_none());
+    }
+
+    #[test]
+    fn test_preferences_validate_and_round_trip() {
+        let blob = r#"{"theme":"dark","highlight":"peers"}"#;
+        assert!(validate_preferences(blob).is_ok());
+        assert!(validate_preferences("").is_ok());
+        assert!(validate_preferences(&"x".repeat(MAX_PREFERENCES_LEN)).is_ok());
+        assert!(validate_preferences(&"x".repeat(MAX_PREFERENCES_LEN + 1)).unwrap_err().contains("256 bytes"));
+        assert!(validate_preferences("{\n}").unwrap_err().contains("control characters"));
+
+        let player = PlayerInfo {
+            wallet: AccountOwner::Address20([4; 20]),
+            discord_username: "prefs".into(),
+            registered_at_micros: 0,
+            team: None,
+            preferences: Some(blob.into()),
+        };
+        let stored: PlayerInfo =
+            linera_sdk::bcs::from_bytes(&linera_sdk::bcs::to_bytes(&player).unwrap()).unwrap();
+        assert_eq!(stored.preferences.as_deref(), Some(blob));
+    }
+
+    #[test]
+    fn test_validate_username_rejects_invalid_names() {
+        let too_long = "a".repeat(33);
+        let cases = [
+            ("", "1-32 characters"),
+            (too_long.as_str(), "1-32 characters"),
+            ("bad\nname", "control characters"),
+            (" alice", "whitespace"),
+            ("alice ", "whitespace"),
+            ("al ice", "disallowed character ' '"),
+            ("alice@home", "disallowed character '@'"),
+            ("émile", "disallowed character 'é'"),
+        ];
+        for (name, reason) in cases {
+            let err = validate_username(name).expect_err(name);
+            assert!(err.contains(reason), "{:?}: unexpected reason {:?}", name, err);
+        }
+    }
+
+    #[test]
+    fn test_tournament_end_time_bounds() {
+        assert_eq!(tournament_end_time(5, 60), Ok(60_000_005));
+        assert!(tournament_end_time(5, MAX_TOURNAMENT_DURATION_SECS).is_ok());
+        assert!(tournament_end_time(5, 0).is_err());
+        assert!(tournament_end_time(5, MAX_TOURNAMENT_DURATION_SECS + 1).is_err());
+        assert!(tournament_end_time(5, u64::MAX).is_err());
+        assert!(tournament_end_time(u64::MAX - 1_000, 60).is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_rejects_placements_too_close_together() {
+        let mut state = PlayerGameState::new(&[[0u8; 9]; 9]);
+        let min = DEFAULT_MIN_MOVE_INTERVAL_MICROS;
+        assert!(!state.is_rate_limited(1_000_000, min), "first move is never throttled");
+
+        state.last_move_time_micros = 1_000_000;
+        assert!(state.is_rate_limited(1_000_000 + min / 2, min));
+        assert!(!state.is_rate_limited(1_000_000 + min, min));
+    }
+
+    #[test]
+    fn test_player_dashboard_with_and_without_game() {
+        let wallet = AccountOwner::Address20([2; 20]);
+        let player = PlayerInfo {
+            wallet,
+            discord_username: "player2".into(),
+            registered_at_micros: 0,
+            team: None,
+            preferences: None,
+        };
+        let tournament = Tournament {
+            start_time_micros: 0,
+            end_time_micros: 3_600_000_000,
+            active: true,
+            ..Tournament::default()
+        };
+        let cached = vec![entry(1, 9_000, true), entry(2, 8_000, false)];
+
+        // Registered, no game yet: profile and rank only.
+        let idle = PlayerDashboard::build(
+            wallet, Some(player.clone()), None, Some(&tournament), &cached, 60_000_000,
+        );
+        assert_eq!(idle.player.as_ref().map(|p| p.wallet), Some(wallet));
+        assert!(idle.game.is_none());
+        assert_eq!((idle.estimated_score, idle.cells_remaining), (None, None));
+        assert_eq!(idle.rank, Some(2));
+
+        let mut puzzle = [[0u8; 9]; 9];
+        puzzle[0][0] = 5;
+        let game = PlayerGameState::new(&puzzle);
+        let playing = PlayerDashboard::build(
+            wallet, Some(player), Some(game), Some(&tournament), &cached, 60_000_000,
+        );
+        assert_eq!(playing.cells_remaining, Some(80));
+        assert_eq!(playing.digit_counts, Some([0, 0, 0, 0, 1, 0, 0, 0, 0]));
+        assert_eq!(playing.estimated_score, Some(tournament.score_at(60_000_000, 0)));
+
+        // Unknown wallet, no tournament: nothing but the wallet.
+        let stranger = AccountOwner::Address20([9; 20]);
+        let empty = PlayerDashboard::build(stranger, None, None, None, &cached, 0);
+        assert!(empty.player.is_none() && empty.rank.is_none());
+    }
+
+    #[test]
+    fn test_digit_counts_with_three_fives() {
+        let mut puzzle = [[0u8; 9]; 9];
+        puzzle[0][0] = 5;
+        puzzle[4][4] = 9;
+        let mut game = PlayerGameState::new(&puzzle);
+        game.set_cell(3, 1, 5);
+        game.set_cell(8, 7, 5);
+        game.set_cell(2, 2, 1);
+        assert_eq!(game.digit_counts(), [1, 0, 0, 0, 3, 0, 0, 0, 1]);
+
+        game.set_cell(8, 7, 0);
+        assert_eq!(game.digit_counts()[4], 2);
+    }
+
+    #[test]
+    fn test_is_stuck_on_zero_candidate_cell() {
+        let mut game = PlayerGameState::new(&[[0u8; 9]; 9]);
+        assert!(!game.is_stuck(SudokuVariant::Classic, BoardSize::Nine));
+
+        // Row 0 holds 1-8 and column 0 holds 9: (0, 0) has nothing left,
+        // though every entry is legal on its own.
+        for c in 1..9 {
+            game.set_cell(0, c, c as u8);
+        }
+        assert!(!game.is_stuck(SudokuVariant::Classic, BoardSize::Nine));
+        game.set_cell(5, 0, 9);
+        assert!(game.is_stuck(SudokuVariant::Classic, BoardSize::Nine));
+        assert_eq!(
+            sudoku::find_dead_cell(&game.grid(), SudokuVariant::Classic, BoardSize::Nine),
+            Some((0, 0)),
+        );
+
+        // Clearing the blocking entry frees the cell again.
+        game.set_cell(5, 0, 0);
+        assert!(!game.is_stuck(SudokuVariant::Classic, BoardSize::Nine));
+    }
+
+    #[test]
+    fn test_logic_hint_finds_naked_single() {
+        let board = sudoku::generate_puzzle(42, SudokuVariant::Classic).expect("should generate");
+        // Everything filled in but one cell, which can only take its answer.
+        let mut game = PlayerGameState::new(&board.solution);
+        game.board[3][5] = 0;
+        assert_eq!(game.logic_hint(SudokuVariant::Classic, BoardSize::Nine), Some((3, 5, board.solution[3][5])));
+
+        // An empty board forces nothing: the player has to guess.
+        let empty = PlayerGameState::new(&[[0u8; 9]; 9]);
+        assert_eq!(empty.logic_hint(SudokuVariant::Classic, BoardSize::Nine), None);
+    }
+
+    #[test]
+    fn test_estimated_finish_extrapolates_pace() {
+        let board = sudoku::generate_puzzle(8, SudokuVariant::Classic).expect("should generate");
+        let mut game = PlayerGameState::new(&board.puzzle);
+        game.start_time_micros = 1_000_000;
+        let open: Vec<(usize, usize)> = (0..81).map(|i| (i / 9, i % 9))
+            .filter(|&(r, c)| board.puzzle[r][c] == 0).collect();
+        assert_eq!(game.estimated_finish_micros(2_000_000), None);
+
+        // Half the open cells at 10 s each.
+        let half = open.len() / 2;
+        for &(r, c) in &open[..half] {
+            game.board[r][c] = board.solution[r][c];
+        }
+        let now = 1_000_000 + half as u64 * 10_000_000;
+        let remaining = (open.len() - half) as u64;
+        assert_eq!(game.estimated_finish_micros(now), Some(now + remaining * 10_000_000));
+
+        game.completed = true;
+        game.completion_time_micros = Some(now);
+        assert_eq!(game.estimated_finish_micros(now + 1), Some(now));
+    }
+
+    #[test]
+    fn test_blind_mode_hides_validity_but_counts_penalties() {
+        let mut game = PlayerGameState::new(&[[0u8; 9]; 9]);
+        game.set_cell(0, 0, 4);
+        // A second 4 in the same row breaks the rules.
+        let valid = game.allows_placement(0, 5, 4, SudokuVariant::Classic, BoardSize::Nine);
+        assert!(!valid);
+        assert!(game.record_mistake(0));
+        assert_eq!(game.penalty_count, 1);
+
+        let blind = CellPlacedResponse::new(0, 5, 4, valid, game.penalty_count, true);
+        assert!(blind.valid);
+        assert_eq!(blind.penalty_count, 0);
+        let open = CellPlacedResponse::new(0, 5, 4, valid, game.penalty_count, false);
+        assert!(!open.valid);
+        assert_eq!(open.penalty_count, 1);
+        let rejected = BoardRejectedResponse::new(3, 4, true);
+        assert_eq!((rejected.wrong_cells, rejected.penalty_count), (0, 0));
+        let rejected = BoardRejectedResponse::new(3, 4, false);
+        assert_eq!((rejected.wrong_cells, rejected.penalty_count), (3, 4));
+
+        // The penalty still costs points at completion.
+        let tournament = Tournament {
+            blind_mode: true,
+            end_time_micros: 3_600_000_000,
+            ..Tournament::default()
+        };
+        let clean = PlayerGameState { penalty_count: 0, ..game.clone() };
+        let at = 60_000_000;
+        assert!(game.calculate_score(&tournament, at) < clean.calculate_score(&tournament, at));
+    }
+
+    #[test]
+    fn test_blind_mode_hides_penalties_from_live_views() {
+        let wallet = AccountOwner::Address20([7; 20]);
+        let blind = Tournament {
+            blind_mode: true,
+            end_time_micros: 3_600_000_000,
+            ..Tournament::default()
+        };
+        let open = Tournament { blind_mode: false, ..blind.clone() };
+        let mut game = PlayerGameState::new(&[[0u8; 9]; 9]);
+        game.move_count = 4;
+        game.penalty_count = 2;
+        game.raw_mistake_count = 2;
+        let at = 60_000_000;
+        let clean = open.score_at(at, 0);
+
+        // Player chain: `playerDashboard`, `playerGameState`, `spectate`.
+        let dashboard =
+            PlayerDashboard::build(wallet, None, Some(game.clone()), Some(&blind), &[], at);
+        let shown = dashboard.game.unwrap();
+        assert_eq!((shown.penalty_count, shown.raw_mistake_count), (0, 0));
+        assert_eq!(dashboard.estimated_score, Some(clean));
+        assert_eq!(game.clone().visible(Some(&blind)).spectator_view().penalty_count, 0);
+        let dashboard =
+            PlayerDashboard::build(wallet, None, Some(game.clone()), Some(&open), &[], at);
+        assert_eq!(dashboard.game.unwrap().penalty_count, 2);
+        assert!(dashboard.estimated_score < Some(clean));
+
+        // Hub: the live entry built from a synced batch.
+        let moves =
+            [SyncedMove { row: 0, col: 0, value: 4, timestamp_micros: at, penalty_count: 2 }];
+        let mut entry = LeaderboardEntry::in_progress(wallet, "ada".into(), 0);
+        entry.record_placements(&moves, blind.blind_mode);
+        assert_eq!(entry.penalty_count, 0);
+        assert_eq!(blind.live_score(at, 2), clean);
+        entry.record_placements(&moves, open.blind_mode);
+        assert_eq!(entry.penalty_count, 2);
+
+        // Once the board is done, everything is shown.
+        game.completed = true;
+        assert_eq!(game.visible(Some(&blind)).penalty_count, 2);
+    }
+
+    #[test]
+    fn test_board_at_move_replays_history() {
+        let mut puzzle = [[0u8; 9]; 9];
+        puzzle[0][0] = 5;
+        let mut state = PlayerGameState::new(&puzzle);
+
+        for m in [
+            MoveRecord { row: 0, col: 1, value: 3 },
+            MoveRecord { row: 4, col: 4, value: 7 },
+            MoveRecord { row: 0, col: 1, value: 0 },
+        ] {
+            state.board[m.row as usize][m.col as usize] = m.value;
+            state.move_history.push(m);
+        }
+
+        let start = state.board_at_move(0);
+        assert_eq!(start[0][0], 5, "givens are kept");
+        assert_eq!(start[4][4], 0);
+
+        let mid = state.board_at_move(2);
+        assert_eq!((mid[0][1], mid[4][4]), (3, 7));
+
+        assert_eq!(state.board_at_move(3), state.board);
+        assert_eq!(state.board_at_move(100), state.board);
+    }
+
+    fn entry(id: u8, score: u64, completed: bool) -> LeaderboardEntry {
+        LeaderboardEntry {
+            wallet: AccountOwner::Address20([id; 20]),
+            discord_username: format!("player{}", id),
+            score,
+            completion_time_micros: 0,
+            penalty_count: 0,
+            move_count: 0,
+            completed,
+            first_move_time_micros: 0,
+            last_move_time_micros: 0,
+            is_suspicious: false,
+            cells_filled: 0,
+            cells_remaining: 0,
+            move_sequence_hash: 0,
+            failed: false,
+        }
+    }
+
+    #[test]
+    fn test_stats_pace_and_fastest_completion() {
+        let tournament = Tournament { id: 3, active: true, ..Tournament::default() };
+
+        // 30 moves over 5 minutes, finished 5 minutes after the first move.
+        let mut fast = entry(1, 9_000, true);
+        fast.move_count = 30;
+        fast.first_move_time_micros = 60_000_000;
+        fast.last_move_time_micros = 360_000_000;
+        fast.completion_time_micros = 360_000_000;
+
+        // 10 moves over 5 minutes, still solving.
+        let mut slow = entry(2, 5_000, false);
+        slow.move_count = 10;
+        slow.first_move_time_micros = 0;
+        slow.last_move_time_micros = 300_000_000;
+
+        let stats = TournamentStats::from_entries(&tournament, &[fast, slow]);
+        assert_eq!(stats.total_players, 2);
+        assert_eq!(stats.total_completions, 1);
+        assert_eq!(stats.average_score, 7_000);
+        assert_eq!(stats.average_moves_per_minute, 4); // 40 moves / 10 minutes
+        assert_eq!(stats.fastest_completion_micros, 300_000_000);
+
+        let empty = TournamentStats::from_entries(&tournament, &[]);
+        assert_eq!(empty.average_moves_per_minute, 0);
+        assert_eq!(empty.fastest_completion_micros, 0);
+    }
+
+    #[test]
+    fn test_stats_median_score() {
+        let tournament = Tournament::default();
+
+        let odd = [entry(1, 100, true), entry(2, 9_000, true), entry(3, 300, false)];
+        assert_eq!(TournamentStats::from_entries(&tournament, &odd).median_score, 300);
+
+        let even = [
+            entry(1, 100, true),
+            entry(2, 9_000, true),
+            entry(3, 300, false),
+            entry(4, 500, false),
+        ];
+        assert_eq!(TournamentStats::from_entries(&tournament, &even).median_score, 400);
+
+        assert_eq!(TournamentStats::from_entries(&tournament, &[]).median_score, 0);
+    }
+
+    #[test]
+    fn test_rank_cmp_prefers_fewer_moves_on_tie() {
+        let mut a = entry(1, 8_000, true);
+        a.completion_time_micros = 5_000_000;
+        a.move_count = 50;
+        let mut b = a.clone();
+        b.wallet = AccountOwner::Address20([2; 20]);
+        b.move_count = 46;
+
+        let mut entries = vec![a, b];
+        entries.sort_by(LeaderboardEntry::rank_cmp);
+        assert_eq!(entries[0].move_count, 46);
+        assert_eq!(entries[1].move_count, 50);
+    }
+
+    #[test]
+    fn test_rank_order_is_stable_for_identical_entries() {
+        let mut a = entry(9, 8_000, true);
+        a.completion_time_micros = 5_000_000;
+        let mut b = a.clone();
+        b.wallet = AccountOwner::Address20([3; 20]);
+        let mut c = entry(9, 500, false);
+        c.wallet = AccountOwner::Address20([4; 20]);
+        let mut d = c.clone();
+        d.wallet = AccountOwner::Address20([1; 20]);
+
+        let first = rank_order(vec![a.clone(), b.clone(), c.clone(), d.clone()]);
+        let second = rank_order(vec![d, c, b, a]);
+        let wallets = |v: &[LeaderboardEntry]| v.iter().map(|e| e.wallet).collect::<Vec<_>>();
+        assert_eq!(wallets(&first), wallets(&second));
+        assert_eq!(first[0].wallet, AccountOwner::Address20([3; 20]));
+        assert_eq!(first[2].wallet, AccountOwner::Address20([1; 20]));
+    }
+
+    #[test]
+    fn test_verify_game_timed_matches_hub_score() {
+        let seed = 4321;
+        let board = sudoku::generate_puzzle(seed, SudokuVariant::Classic).unwrap();
+        let mut tournament = Tournament {
+            start_time_micros: 1_000_000,
+            end_time_micros: 3_601_000_000,
+            scoring_mode: ScoringMode::Accuracy,
+            free_mistakes: 1,
+            ..Tournament::default()
+        };
+        let mut game = PlayerGameState::new(&board.puzzle);
+
+        // Play as the contract does: two wrong guesses (one forgiven), each
+        // cleared, then the solution, with a pause halfway through.
+        let empty: Vec<(usize, usize)> = (0..81).map(|i| (i / 9, i % 9))
+            .filter(|&(r, c)| board.puzzle[r][c] == 0)
+            .collect();
+        let (r0, c0) = empty[0];
+        let wrong = board.solution[r0][(c0 + 1) % 9];
+        let mut now = 1_000_000;
+        let mut play = |game: &mut PlayerGameState, tournament: &mut Tournament, r: usize, c: usize, v: u8| {
+            now += 7_000_000;
+            if v != 0 && !sudoku::validate_placement(&game.board, r, c, v, SudokuVariant::Classic) {
+                game.record_mistake(tournament.free_mistakes);
+            }
+            game.board[r][c] = v;
+            game.record_move(MoveRecord { row: r as u8, col: c as u8, value: v }, tournament.elapsed_micros(now));
+            if game.move_history.len() == 20 {
+                tournament.pause(now);
+                now += 600_000_000;
+                tournament.resume(now);
+            }
+            now
+        };
+        for _ in 0..2 {
+            play(&mut game, &mut tournament, r0, c0, wrong);
+            play(&mut game, &mut tournament, r0, c0, 0);
+        }
+        let mut finished = 0;
+        for &(r, c) in &empty {
+            finished = play(&mut game, &mut tournament, r, c, board.solution[r][c]);
+        }
+        assert!(game.check_complete(&board.solution));
+        assert_eq!(game.penalty_count, 1);
+        let hub_score = tournament.score_at(finished, game.penalty_count);
+
+        let moves: Vec<(u8, u8, u8, u64)> = game.move_history.iter().zip(&game.move_times)
+            .map(|(m, &t)| (m.row, m.col, m.value, t))
+            .collect();
+        let result = sudoku::verify_game_timed(
+            seed, SudokuVariant::Classic, ScoringMode::Accuracy, 1, &moves,
+        );
+        assert!(result.board_complete);
+        assert_eq!(result.penalty_count, 1);
+        assert_eq!(result.final_score, hub_score);
+    }
+
+    #[test]
+    fn test_player_history_across_two_tournaments() {
+        let me = AccountOwner::Address20([2; 20]);
+        let mut ids = Vec::new();
+
+        // Tournament 1: second of three.
+        add_participation(&mut ids, 1);
+        add_participation(&mut ids, 1);
+        let first = rank_order(vec![entry(1, 9_000, true), entry(2, 8_000, true), entry(3, 0, false)]);
+        // Tournament 2: wins.
+        add_participation(&mut ids, 2);
+        let second = rank_order(vec![entry(4, 7_000, true), entry(2, 9_500, true)]);
+        assert_eq!(ids, vec![1, 2]);
+
+        let history: Vec<TournamentResult> = ids.iter().rev()
+            .filter_map(|&id| {
+                let rankings = if id == 1 { &first } else { &second };
+                TournamentResult::from_rankings(id, rankings, &me)
+            })
+            .collect();
+        assert_eq!(history.len(), 2);
+        assert_eq!((history[0].tournament_id, history[0].rank, history[0].score), (2, 1, 9_500));
+        assert_eq!((history[1].tournament_id, history[1].rank, history[1].score), (1, 2, 8_000));
+        assert!(history.iter().all(|r| r.completed && !r.failed));
+        assert!(TournamentResult::from_rankings(1, &first, &AccountOwner::Address20([9; 20])).is_none());
+
+        let mut long: Vec<u64> = (0..MAX_PLAYER_HISTORY as u64).collect();
+        add_participation(&mut long, 1_000);
+        assert_eq!(long.len(), MAX_PLAYER_HISTORY);
+        assert_eq!((long[0], *long.last().unwrap()), (1, 1_000));
+    }
+
+    #[test]
+    fn test_tournament_report_aggregates() {
+        let tournament = Tournament { id: 7, ..Tournament::default() };
+        let mut slow = entry(1, 8_000, true);
+        slow.first_move_time_micros = 0;
+        slow.completion_time_micros = 900_000_000;
+        slow.move_count = 60;
+        let mut fast = entry(2, 9_000, true);
+        fast.first_move_time_micros = 100_000_000;
+        fast.completion_time_micros = 400_000_000;
+        fast.move_count = 50;
+        fast.is_suspicious = true;
+        let mut out = entry(3, 0, false);
+        out.failed = true;
+        out.move_count = 12;
+
+        let report = TournamentReport::build(tournament, vec![out, slow, fast]);
+        assert_eq!(report.tournament.id, 7);
+        let order: Vec<u8> = report.rankings.iter()
+            .map(|e| match e.wallet { AccountOwner::Address20(b) => b[0], _ => 0 })
+            .collect();
+        assert_eq!(order, vec![2, 1, 3]);
+        assert_eq!(report.stats.total_players, 3);
+        assert_eq!(report.stats.best_score, 9_000);
+        assert_eq!(report.stats.median_score, 8_000);
+        assert_eq!(report.suspicious_players, 1);
+        assert_eq!(report.failed_players, 1);
+        assert_eq!(report.total_moves, 122);
+        assert_eq!(report.fastest_finisher.map(|e| e.solve_duration_micros()), Some(300_000_000));
+    }
+
+    #[test]
+    fn test_leaderboard_event_respects_broadcast_top_n() {
+        let entries: Vec<LeaderboardEntry> = (0..30u8).map(|i| entry(i, 100, true)).collect();
+        let small = Tournament { broadcast_top_n: Some(10), ..Tournament::default() };
+        match small.leaderboard_event(entries.clone(), 5) {
+            ArenaEvent::LeaderboardUpdated { entries, emitted_at_micros } => {
+                assert!(entries.len() <= 10);
+                assert_eq!(emitted_at_micros, 5);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        assert_eq!(Tournament::default().broadcast_size(), DEFAULT_BROADCAST_TOP_N);
+        let huge = Tournament { broadcast_top_n: Some(u32::MAX), ..Tournament::default() };
+        assert_eq!(huge.broadcast_size(), MAX_BROADCAST_TOP_N);
+    }
+
+    #[test]
+    fn test_late_completion_rejected() {
+        let tournament = Tournament {
+            start_time_micros: 1_000_000,
+            end_time_micros: 61_000_000,
+            ..Tournament::default()
+        };
+        let end = tournament.end_time_micros;
+        assert!(tournament.accepts_completion(end - 1, end + 5_000_000));
+        // A lagging clock or slow delivery is forgiven up to the grace period.
+        assert!(tournament.accepts_completion(end + 10_000_000, end + COMPLETION_GRACE_MICROS));
+        // Past it, either timestamp being late is enough to reject.
+        assert!(!tournament.accepts_completion(end - 1, end + COMPLETION_GRACE_MICROS + 1));
+        assert!(!tournament.accepts_completion(end + COMPLETION_GRACE_MICROS + 1, end));
+
+        // A resumed pause pushes the deadline back with the end time.
+        let mut paused = tournament.clone();
+        paused.pause(11_000_000);
+        paused.resume(41_000_000);
+        assert!(paused.accepts_completion(end + 30_000_000, end + 40_000_000));
+    }
+
+    #[test]
+    fn test_pause_excludes_paused_time() {
+        let mut tournament = Tournament {
+            start_time_micros: 1_000_000,
+            end_time_micros: 61_000_000,
+            ..Tournament::default()
+        };
+        let unpaused = tournament.clone();
+
+        tournament.pause(11_000_000);
+        assert!(tournament.is_paused());
+        // Time spent paused so far already doesn't count.
+        assert_eq!(tournament.elapsed_micros(31_000_000), 10_000_000);
+
+        tournament.resume(41_000_000);
+        assert!(!tournament.is_paused());
+        assert_eq!(tournament.paused_micros, 30_000_000);
+        assert_eq!(tournament.end_time_micros, 91_000_000);
+        assert_eq!(tournament.elapsed_micros(51_000_000), 20_000_000);
+        assert!(tournament.in_window(80_000_000));
+        assert!(!unpaused.in_window(80_000_000));
+
+        // Finishing 20s of play later scores as if there had been no pause.
+        assert_eq!(tournament.score_at(51_000_000, 1), unpaused.score_at(21_000_000, 1));
+
+        // A second resume is a no-op.
+        tournament.resume(60_000_000);
+        assert_eq!(tournament.paused_micros, 30_000_000);
+    }
+
+    #[test]
+    fn test_duplicate_sequences_groups_identical_moves() {
+        let moves = [(0u8, 1u8, 3u8), (4, 4, 7), (8, 0, 2)];
+        let hash = |moves: &[(u8, u8, u8)]| {
+            moves.iter().fold(MOVE_SEQUENCE_HASH_INIT, |h, &(r, c, v)| fold_move_hash(h, r, c, v))
+        };
+        let reordered = [moves[1], moves[0], moves[2]];
+        assert_ne!(hash(&moves), hash(&reordered));
+
+        let mut a = entry(1, 9_000, true);
+        a.move_sequence_hash = hash(&moves);
+        let mut b = entry(2, 8_000, true);
+        b.move_sequence_hash = hash(&moves);
+        let mut c = entry(3, 7_000, true);
+        c.move_sequence_hash = hash(&reordered);
+        // Same moves but unfinished, and finishers with no synced moves.
+        let mut d = entry(4, 500, false);
+        d.move_sequence_hash = hash(&moves);
+        let e = entry(5, 6_000, true);
+        let f = entry(6, 6_000, true);
+
+        let groups = duplicate_sequences(&[a.clone(), b.clone(), c, d, e, f]);
+        assert_eq!(groups, vec![DuplicateSequence {
+            move_sequence_hash: a.move_sequence_hash,
+            wallets: vec![a.wallet, b.wallet],
+        }]);
+    }
+
+    #[test]
+    fn test_check_min_players() {
+        assert!(check_min_players(2, Some(3)).is_err());
+        assert!(check_min_players(3, Some(3)).is_ok());
+        assert!(check_min_players(0, None).is_ok());
+        assert!(check_min_players(0, Some(0)).is_ok());
+    }
+
+    #[test]
+    fn test_syncing_a_wallet_twice_counts_it_once() {
+        // The Hub's `players` and `player_count`.
+        let mut players = std::collections::BTreeSet::new();
+        let mut player_count = 0;
+        let mut sync = |wallet: AccountOwner| {
+            player_count = player_count_after_sync(player_count, players.contains(&wallet));
+            players.insert(wallet);
+            player_count
+        };
+
+        let (ada, bob) = (AccountOwner::Address20([1; 20]), AccountOwner::Address20([2; 20]));
+        assert_eq!(sync(ada), 1);
+        assert_eq!(sync(ada), 1);
+        // bob's `UpdateUsername` sync arrives before the registration one.
+        assert_eq!(sync(bob), 2);
+        assert_eq!(sync(bob), 2);
+    }
+
+    #[test]
+    fn test_same_seed_twice_is_rejected() {
+        // The Hub's `used_seeds`: a seed keeps the first tournament to use it.
+        let mut used_seeds = std::collections::BTreeMap::new();
+        let mut start = |seed: u64, tournament_id: u64| {
+            check_seed_unused(seed, used_seeds.get(&seed).copied())?;
+            used_seeds.entry(seed).or_insert(tournament_id);
+            Ok::<_, String>(())
+        };
+
+        assert!(start(42, 1).is_ok());
+        let err = start(42, 2).unwrap_err();
+        assert!(err.contains("tournament #1"), "{}", err);
+        assert!(start(43, 2).is_ok());
+        assert_eq!(used_seeds[&42], 1);
+
+        // Auto-start moves on to the first candidate nobody used.
+        let candidates: Vec<u64> = seed_candidates(42).collect();
+        assert_eq!(candidates.len(), MAX_SEED_ATTEMPTS);
+        assert_eq!(candidates[0], 42);
+        let distinct: std::collections::BTreeSet<u64> = candidates.iter().copied().collect();
+        assert_eq!(distinct.len(), MAX_SEED_ATTEMPTS);
+        let fresh = seed_candidates(42).find(|seed| !used_seeds.contains_key(seed));
+        assert_eq!(fresh, Some(candidates[1]));
+    }
+
+    #[test]
+    fn test_log_page_pages_through_log() {
+        let mut log = Vec::new();
+        for (i, score) in [9_000, 8_500, 9_200, 7_000, 9_900].into_iter().enumerate() {
+            log.push(entry(i as u8, score, true));
+        }
+
+        let first: Vec<u64> = log[log_page(log.len(), 0, 2)].iter().map(|e| e.score).collect();
+        assert_eq!(first, vec![9_000, 8_500]);
+        let second: Vec<u64> = log[log_page(log.len(), 2, 2)].iter().map(|e| e.score).collect();
+        assert_eq!(second, vec![9_200, 7_000]);
+        assert_eq!(log_page(log.len(), 4, 2), 4..5);
+        assert_eq!(log_page(log.len(), 9, 2), 5..5);
+        assert_eq!(log_page(1_000, 0, u32::MAX).len(), MAX_LOG_PAGE as usize);
+    }
+
+    #[test]
+    fn test_reset_requires_exact_confirmation() {
+        assert!(is_reset_confirmed(RESET_ARENA_CONFIRMATION));
+        for attempt in ["", "reset fridaychain arena", " RESET FRIDAYCHAIN ARENA", "RESET", "yes"] {
+            assert!(!is_reset_confirmed(attempt), "{:?} should not confirm", attempt);
+        }
+
+        let event = ArenaEvent::ArenaReset { emitted_at_micros: 42 };
+        assert_eq!(event.emitted_at_micros(), 42);
+        let item = ActivityItem::from_event(&event).unwrap();
+        assert_eq!(item.kind, ActivityKind::ArenaReset);
+        assert_eq!(item.at_micros, 42);
+    }
+
+    #[test]
+    fn test_announcement_round_trips_through_stream() {
+        assert!(validate_announcement("10 minutes left!").is_ok());
+        assert!(validate_announcement("  ").is_err());
+        assert!(validate_announcement(&"!".repeat(MAX_ANNOUNCEMENT_LEN + 1)).is_err());
+        assert!(validate_announcement("line\nbreak").is_err());
+
+        let event = ArenaEvent::Announcement {
+            text: "10 minutes left!".into(),
+            emitted_at_micros: 99,
+        };
+        let wire = linera_sdk::bcs::to_bytes(&StreamEvent::new(&event)).unwrap();
+        let received: StreamEvent = linera_sdk::bcs::from_bytes(&wire).unwrap();
+        match received.decode() {
+            Some(ArenaEvent::Announcement { text, emitted_at_micros }) => {
+                assert_eq!(text, "10 minutes left!");
+                assert_eq!(emitted_at_micros, 99);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert!(ActivityItem::from_event(&event).is_none());
+    }
+
+    #[test]
+    fn test_batched_sync_matches_single_moves() {
+        let wallet = AccountOwner::Address20([7; 20]);
+        // Two seconds apart: suspicious from the fifth move on.
+        let moves: Vec<SyncedMove> = (0..8u8)
+            .map(|i| SyncedMove {
+                row: i,
+                col: 8 - i,
+                value: i + 1,
+                timestamp_micros: 1_000_000 + i as u64 * 2_000_000,
+                penalty_count: (i / 3) as u32,
+            })
+            .collect();
+
+        let start = moves[0].timestamp_micros;
+        let mut single = LeaderboardEntry::in_progress(wallet, "ada".into(), start);
+        for m in &moves {
+            single.record_placements(std::slice::from_ref(m), false);
+        }
+        let mut batched = LeaderboardEntry::in_progress(wallet, "ada".into(), start);
+        batched.record_placements(&moves[..3], false);
+        batched.record_placements(&moves[3..], false);
+
+        assert_eq!(single.move_count, 8);
+        assert_eq!(single.penalty_count, 2);
+        assert!(single.is_suspicious);
+        assert_eq!(
+            single.move_sequence_hash,
+            moves.iter()
+                .fold(MOVE_SEQUENCE_HASH_INIT, |h, m| fold_move_hash(h, m.row, m.col, m.value)),
+        );
+        assert_eq!(
+            linera_sdk::bcs::to_bytes(&batched).unwrap(),
+            linera_sdk::bcs::to_bytes(&single).unwrap(),
+        );
+
+        // A slow player stays unflagged either way.
+        let slow: Vec<SyncedMove> = moves.iter()
+            .map(|m| SyncedMove { timestamp_micros: m.timestamp_micros * 10, ..m.clone() })
+            .collect();
+        let mut batched = LeaderboardEntry::in_progress(wallet, "ada".into(), start * 10);
+        batched.record_placements(&slow, false);
+        assert!(!batched.is_suspicious);
+    }
+
+    #[test]
+    fn test_completion_streak_counts_and_resets() {
+        // The Hub's bookkeeping: +1 on completion, dropped at the end of a
+        // tournament the player didn't finish.
+        let wallet = AccountOwner::Address20([1; 20]);
+        let mut streak = 0u32;
+        let mut play = |rankings: Vec<LeaderboardEntry>| {
+            if rankings.iter().any(|e| e.wallet == wallet && e.completed) {
+                streak += 1;
+            }
+            if !keeps_streak(&rankings, &wallet) {
+                streak = 0;
+            }
+            streak
+        };
+
+        assert_eq!(play(vec![entry(1, 9_000, true), entry(2, 500, false)]), 1);
+        assert_eq!(play(vec![entry(2, 9_000, true), entry(1, 8_000, true)]), 2);
+        // Skipped entirely.
+        assert_eq!(play(vec![entry(2, 9_000, true)]), 0);
+        assert_eq!(play(vec![entry(1, 9_000, true)]), 1);
+        // Played but didn't finish.
+        assert_eq!(play(vec![entry(1, 700, false)]), 0);
+
+        // Finishing tournament 9 again after `ResetPlayerGame` dropped the
+        // entry doesn't extend the streak a second time.
+        let (mut counted_for, mut streak) = (None, 0u32);
+        for tournament_id in [9, 9, 10] {
+            if is_first_completion(None, counted_for, tournament_id) {
+                counted_for = Some(tournament_id);
+                streak += 1;
+            }
+        }
+        assert_eq!(streak, 2);
+    }
+
+    #[test]
+    fn test_stream_event_skips_undecodable_payloads() {
+        let event = ArenaEvent::TournamentPaused {
+            tournament_id: 3,
+            paused_at_micros: 10,
+            emitted_at_micros: 11,
+        };
+        let envelope = StreamEvent::new(&event);
+        let wire = linera_sdk::bcs::to_bytes(&envelope).unwrap();
+        let received: StreamEvent = linera_sdk::bcs::from_bytes(&wire).unwrap();
+        assert!(matches!(
+            received.decode(),
+            Some(ArenaEvent::TournamentPaused { tournament_id: 3, .. }),
+        ));
+
+        // A variant this build doesn't know, from a newer Hub.
+        let unknown =
+            StreamEvent::Versioned { version: EVENT_VERSION, payload: vec![200, 1, 2, 3] };
+        let wire = linera_sdk::bcs::to_bytes(&unknown).unwrap();
+        let received: StreamEvent = linera_sdk::bcs::from_bytes(&wire).unwrap();
+        assert!(received.decode().is_none());
+
+        // A known variant with a field appended by a newer contract.
+        let StreamEvent::Versioned { version, mut payload } = envelope else {
+            panic!("new events are versioned");
+        };
+        payload.push(1);
+        assert!(StreamEvent::Versioned { version, payload: payload.clone() }.decode().is_none());
+
+        payload.pop();
+        assert!(StreamEvent::Versioned { version: EVENT_VERSION + 1, payload }.decode().is_none());
+    }
+
+    #[test]
+    fn test_stream_event_reads_original_bare_events() {
+        /// `ArenaEvent` as the original contract emitted it, unwrapped.
+        #[derive(Serialize)]
+        enum OriginalEvent {
+            TournamentStarted {
+                tournament_id: u64,
+                seed: u64,
+                start_time_micros: u64,
+                end_time_micros: u64,
+            },
+            TournamentEnded {
+                tournament_id: u64,
+                final_rankings: Vec<LegacyLeaderboardEntry>,
+            },
+            PlayerRegistered {
+                wallet: AccountOwner,
+                discord_username: String,
+            },
+            LeaderboardUpdated {
+                entries: Vec<LegacyLeaderboardEntry>,
+            },
+        }
+        let read = |event: &OriginalEvent| {
+            let wire = linera_sdk::bcs::to_bytes(event).unwrap();
+            linera_sdk::bcs::from_bytes::<StreamEvent>(&wire).unwrap().decode()
+        };
+        let wallet = AccountOwner::Address20([5; 20]);
+        let original = |score| LegacyLeaderboardEntry {
+            wallet,
+            discord_username: "ada".into(),
+            score,
+            completion_time_micros: 60_000_000,
+            penalty_count: 1,
+            move_count: 50,
+            completed: true,
+            first_move_time_micros: 1,
+            last_move_time_micros: 60_000_000,
+            is_suspicious: false,
+        };
+
+        let started = OriginalEvent::TournamentStarted {
+            tournament_id: 4,
+            seed: 20260213,
+            start_time_micros: 10,
+            end_time_micros: 20,
+        };
+        match read(&started) {
+            Some(ArenaEvent::TournamentStarted {
+                tournament_id: 4, seed: 20260213, start_time_micros: 10, end_time_micros: 20,
+                variant, solution_commitment, board_size, emitted_at_micros, ..
+            }) => {
+                assert_eq!(variant, SudokuVariant::Classic);
+                assert_eq!(board_size, BoardSize::Nine);
+                // No commitment: the puzzle is checked by variant only.
+                assert_eq!(solution_commitment, [0; 32]);
+                assert_eq!(emitted_at_micros, 0);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        let ended = OriginalEvent::TournamentEnded {
+            tournament_id: 4,
+            final_rankings: vec![original(9_000), original(8_000)],
+        };
+        match read(&ended) {
+            Some(ArenaEvent::TournamentEnded { tournament_id: 4, final_rankings, .. }) => {
+                let scores: Vec<u64> = final_rankings.iter().map(|e| e.score).collect();
+                assert_eq!(scores, vec![9_000, 8_000]);
+                assert!(final_rankings.iter().all(|e| e.completed && !e.failed));
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        let registered =
+            OriginalEvent::PlayerRegistered { wallet, discord_username: "ada".into() };
+        assert!(matches!(
+            read(&registered),
+            Some(ArenaEvent::PlayerRegistered { discord_username, .. }) if discord_username == "ada",
+        ));
+        let updated = OriginalEvent::LeaderboardUpdated { entries: vec![original(7_000)] };
+        assert!(matches!(
+            read(&updated),
+            Some(ArenaEvent::LeaderboardUpdated { entries, .. }) if entries[0].score == 7_000,
+        ));
+    }
+
+    #[test]
+    fn test_inbox_range_from_offset() {
+        // Five events received, none dropped yet.
+        assert_eq!(inbox_range(5, 5, 0), 0..5);
+        assert_eq!(inbox_range(5, 5, 3), 3..5);
+        assert_eq!(inbox_range(5, 5, 5), 5..5);
+        assert_eq!(inbox_range(5, 5, 9), 5..5);
+
+        // 250 received, the oldest 50 dropped: index 120 is position 70, and
+        // a client that last saw index 10 gets everything still held.
+        assert_eq!(inbox_range(250, 200, 120), 70..200);
+        assert_eq!(inbox_range(250, 200, 10), 0..200);
+    }
+
+    #[test]
+    fn test_is_authentic_sync_rejects_forged_wallet() {
+        let chain = |n: u8| -> ChainId { linera_sdk::bcs::from_bytes(&[n; 32]).unwrap() };
+        let alice = AccountOwner::Address20([1; 20]);
+        let mallory = AccountOwner::Address20([2; 20]);
+
+        // First sync from any chain, signed by the wallet itself.
+        assert!(is_authentic_sync(&alice, Some(alice), Some(chain(1)), None));
+        assert!(is_authentic_sync(&alice, Some(alice), Some(chain(1)), Some(chain(1))));
+        // Mallory's chain claiming to be Alice.
+        assert!(!is_authentic_sync(&alice, Some(mallory), Some(chain(2)), None));
+        assert!(!is_authentic_sync(&alice, None, Some(chain(2)), None));
+        // Alice's key, but not her home chain.
+        assert!(!is_authentic_sync(&alice, Some(alice), Some(chain(2)), Some(chain(1))));
+        assert!(!is_authentic_sync(&alice, Some(alice), None, None));
+    }
+
+    #[test]
+    fn test_max_penalties_ends_game() {
+        let tournament = Tournament { max_penalties: Some(3), ..Tournament::default() };
+        let mut game = PlayerGameState::new(&[[0u8; 9]; 9]);
+        game.score = 4_200;
+
+        for _ in 0..2 {
+            game.record_mistake(tournament.free_mistakes);
+            assert!(!tournament.penalties_exhausted(game.penalty_count));
+        }
+        game.record_mistake(tournament.free_mistakes);
+        assert!(tournament.penalties_exhausted(game.penalty_count));
+        game.fail(9_000_000);
+        assert!(game.completed && game.failed);
+        assert_eq!((game.score, game.completion_time_micros), (0, Some(9_000_000)));
+
+        assert!(!Tournament::default().penalties_exhausted(u32::MAX));
+
+        // Failed players rank below active ones, even with a higher score.
+        let mut out = entry(1, 900, false);
+        out.failed = true;
+        let playing = entry(2, 100, false);
+        let done = entry(3, 5_000, true);
+        let ranked = rank_order(vec![out, playing, done]);
+        let ids: Vec<u8> = ranked.iter().map(|e| match e.wallet {
+            AccountOwner::Address20(bytes) => bytes[0],
+            _ => 0,
+        }).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_arena_config_reflects_instantiation() {
+        let chain = |n: u8| -> ChainId { linera_sdk::bcs::from_bytes(&[n; 32]).unwrap() };
+        let parameters = ArenaParameters {
+            hub_chain_id: chain(1),
+            min_move_interval_micros: None,
+        };
+        let admin = AccountOwner::Address20([9; 20]);
+
+        let hub = ArenaConfig::new(&parameters, Some(admin), chain(1));
+        assert_eq!(hub, ArenaConfig {
+            hub_chain_id: chain(1),
+            admin_owner: Some(admin),
+            is_hub: true,
+        });
+
+        let player = ArenaConfig::new(&parameters, None, chain(2));
+        assert_eq!(player.hub_chain_id, chain(1));
+        assert!(!player.is_hub);
+    }
+
+    #[test]
+    fn test_capabilities_defaults() {
+        let caps = Capabilities::new(STATE_SCHEMA_VERSION, false, false);
+        assert_eq!(caps.contract_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(caps.schema_version, STATE_SCHEMA_VERSION);
+        assert_eq!(caps.event_version, EVENT_VERSION);
+        assert_eq!(caps.variants.len(), 3);
+        assert!(caps.variants.contains(&SudokuVariant::default()));
+        assert!(caps.scoring_modes.contains(&ScoringMode::default()));
+        assert!(caps.teams && caps.seasons && caps.bulk_submit && caps.box_bonus);
+        assert!(!caps.weekly_schedule_enabled);
+        assert!(!caps.season_active);
+    }
+
+    #[test]
+    fn test_is_valid_admin() {
+        assert!(is_valid_admin(&AccountOwner::Address20([7; 20])));
+        assert!(!is_valid_admin(&AccountOwner::Address20([0; 20])));
+        assert!(!is_valid_admin(&AccountOwner::Reserved(0)));
+        let zero32: AccountOwner = linera_sdk::bcs::from_bytes(&[[1u8].as_slice(), &[0; 32]].concat())
+            .unwrap();
+        assert!(matches!(zero32, AccountOwner::Address32(_)));
+        assert!(!is_valid_admin(&zero32));
+    }
+
+    #[test]
+    fn test_transfer_admin_revokes_old_admin() {
+        let old = AccountOwner::Address20([1; 20]);
+        let new = AccountOwner::Address20([2; 20]);
+        let mut admin_owner = Some(old);
+        assert!(is_admin_signer(admin_owner.as_ref(), &old));
+        assert!(!is_admin_signer(admin_owner.as_ref(), &new));
+
+        // TransferAdmin: the old admin can no longer start tournaments.
+        assert!(is_valid_admin(&new));
+        admin_owner = Some(new);
+        assert!(!is_admin_signer(admin_owner.as_ref(), &old));
+        assert!(is_admin_signer(admin_owner.as_ref(), &new));
+        assert!(!is_admin_signer(None, &old));
+    }
+
+    #[test]
+    fn test_count_active_recent_entries_only() {
+        let now = 1_000_000_000;
+        let mut recent = entry(1, 500, false);
+        recent.last_move_time_micros = now - 30_000_000;
+        let mut edge = entry(2, 500, false);
+        edge.last_move_time_micros = now - 300_000_000;
+        let mut stale = entry(3, 500, false);
+        stale.last_move_time_micros = now - 301_000_000;
+        let mut finished = entry(4, 9_000, true);
+        finished.last_move_time_micros = now;
+        let mut out = entry(5, 0, false);
+        out.failed = true;
+        out.last_move_time_micros = now;
+
+        let entries = [recent, edge, stale, finished, out];
+        assert_eq!(count_active(&entries, now, 300_000_000), 2);
+        assert_eq!(count_active(&entries, now, 60_000_000), 1);
+        assert_eq!(count_active(&[], now, 60_000_000), 0);
+    }
+
+    #[test]
+    fn test_completion_summary_matches_game() {
+        let mut puzzle = [[0u8; 9]; 9];
+        puzzle[0][0] = 5;
+        puzzle[8][8] = 1;
+        let mut game = PlayerGameState::new(&puzzle);
+        for r in 0..9 {
+            for c in 0..9 {
+                if !game.given_mask[r][c] {
+                    game.board[r][c] = 1 + ((r * 3 + r / 3 + c) % 9) as u8;
+                }
+            }
+        }
+        game.move_count = 82;
+        game.raw_mistake_count = 3;
+        game.penalty_count = 1;
+        game.completed = true;
+        game.completion_time_micros = Some(42_000_000);
+        game.score = 9_000;
+
+        let summary = game.completion_summary();
+        assert_eq!(summary.total_cells_filled, 79);
+        assert_eq!(summary.accuracy_percent, 96);
+        assert_eq!(summary.completion_time_micros, 42_000_000);
+        assert_eq!((summary.penalty_count, summary.score), (1, 9_000));
+    }
+
+    #[test]
+    fn test_count_online_within_window() {
+        let now = 500_000_000;
+        let seen = [now - 10_000_000, now - 60_000_000, now - 61_000_000, now];
+        assert_eq!(count_online(seen, now, 60_000_000), 3);
+        assert_eq!(count_online(seen, now, 5_000_000), 1);
+        assert_eq!(count_online(seen, now, 0), 1);
+        assert_eq!(count_online([], now, 60_000_000), 0);
+    }
+
+    #[test]
+    fn test_regenerate_puzzle_after_clearing() {
+        let original = sudoku::generate_puzzle(8080, SudokuVariant::XSudoku).unwrap();
+        let tournament = Tournament {
+            seed: 8080,
+            variant: SudokuVariant::XSudoku,
+            solution_commitment: sudoku::grid_commitment(&original.solution),
+            ..Tournament::default()
+        };
+        let mut current_puzzle = Some(original.clone());
+
+        current_puzzle.take();
+        current_puzzle = tournament.regenerate_puzzle();
+        let reloaded = current_puzzle.expect("puzzle reloads from the seed");
+        assert_eq!(
+            (reloaded.puzzle, reloaded.solution),
+            (original.puzzle, original.solution),
+        );
+
+        let other = Tournament { seed: 8081, ..tournament };
+        assert!(other.regenerate_puzzle().is_none(), "commitment rejects a different seed");
+    }
+
+    #[test]
+    fn test_validate_board_arg() {
+        let mut board = vec![vec![0u8; 9]; 9];
+        board[2][3] = 9;
+        let grid = validate_board_arg(&board).unwrap();
+        assert_eq!(grid[2][3], 9);
+
+        let mut ragged = board.clone();
+        ragged[4].push(1);
+        assert_eq!(validate_board_arg(&ragged), Err(ErrorCode::InvalidBoard));
+        ragged[4].truncate(8);
+        assert_eq!(validate_board_arg(&ragged), Err(ErrorCode::InvalidBoard));
+
+        assert_eq!(validate_board_arg(&board[..8]), Err(ErrorCode::InvalidBoard));
+        assert_eq!(validate_board_arg(&[]), Err(ErrorCode::InvalidBoard));
+
+        let mut out_of_range = board;
+        out_of_range[8][8] = 10;
+        assert_eq!(validate_board_arg(&out_of_range), Err(ErrorCode::InvalidCell));
+    }
+
+    #[test]
+    fn test_verifies_solution_claim() {
+        let board = sudoku::generate_puzzle(99, SudokuVariant::Classic).unwrap();
+        let tournament = Tournament {
+            solution_commitment: sudoku::grid_commitment(&board.solution),
+            ..Tournament::default()
+        };
+        let mut claim: Vec<Vec<u8>> = board.solution.iter().map(|row| row.to_vec()).collect();
+        assert!(tournament.verifies_solution(&claim));
+
+        claim[4].swap(0, 1);
+        assert!(!tournament.verifies_solution(&claim));
+        claim[4].swap(0, 1);
+        claim[8].pop();
+        assert!(!tournament.verifies_solution(&claim));
+        assert!(!tournament.verifies_solution(&claim[..8]));
+
+        let full: Vec<Vec<u8>> = board.solution.iter().map(|row| row.to_vec()).collect();
+        assert!(!Tournament::default().verifies_solution(&full));
+    }
+
+    #[test]
+    fn test_box_bonus_for_first_completed_boxes() {
+        let board = sudoku::generate_puzzle(606, SudokuVariant::Classic).unwrap();
+        let synced = |r: usize, c: usize, value: u8| SyncedMove {
+            row: r as u8, col: c as u8, value, timestamp_micros: 0, penalty_count: 0,
+        };
+        let before = board.filled_boxes(0);
+
+        // The Hub sees box 5 (middle right) filled from the synced moves.
+        let moves: Vec<SyncedMove> = (0..9)
+            .map(|i| (3 + i / 3, 6 + i % 3))
+            .filter(|&(r, c)| board.puzzle[r][c] == 0)
+            .map(|(r, c)| synced(r, c, board.solution[r][c]))
+            .collect();
+        let correct = board.mark_correct(0, &moves);
+        let after = board.filled_boxes(correct);
+        assert!(after[5], "box 5 (middle right) is filled correctly");
+        for b in (0..9).filter(|&b| b != 5) {
+            assert_eq!(after[b], before[b]);
+        }
+
+        // A wrong value placed over a cell and the box no longer counts;
+        // a claim the moves don't back never counts.
+        let (r, c) = (moves[0].row as usize, moves[0].col as usize);
+        let wrong = synced(r, c, board.solution[r][c] % 9 + 1);
+        assert!(!board.filled_boxes(board.mark_correct(correct, &[wrong]))[5]);
+        assert!(!board.filled_boxes(board.mark_correct(0, &moves[1..]))[5]);
+
+        let plain = Tournament::default();
+        let bonus = Tournament { box_bonus_enabled: true, ..Tournament::default() };
+        assert_eq!(plain.box_bonus(2), 0);
+        assert_eq!(bonus.box_bonus(2), 2 * BOX_BONUS_POINTS);
+        assert_eq!(
+            bonus.score_at(60_000_000, 0) + bonus.box_bonus(2),
+            plain.score_at(60_000_000, 0) + 100,
+        );
+    }
+
+    #[test]
+    fn test_region_progress_one_complete_row() {
+        let mut game = PlayerGameState::new(&[[0u8; 9]; 9]);
+        game.board[2] = vec![4, 9, 1, 3, 8, 2, 7, 6, 5];
+        // A full but repeating row doesn't count.
+        game.board[5] = vec![1, 2, 3, 4, 5, 6, 7, 8, 8];
+
+        let progress = game.region_progress(BoardSize::Nine);
+        let rows: Vec<usize> = (0..9).filter(|&r| progress.rows[r]).collect();
+        assert_eq!(rows, vec![2]);
+        assert_eq!(progress.cols, [false; 9]);
+        assert_eq!(progress.boxes, [false; 9]);
+    }
+
+    #[test]
+    fn test_mini_board_views_use_its_side() {
+        let board = sudoku::generate_puzzle_sized(7, SudokuVariant::Classic, BoardSize::Six)
+            .unwrap();
+        let info = PuzzleInfo::from_board(&board);
+        assert_eq!(info.grid.len(), 6);
+        assert!(info.grid.iter().all(|row| row.len() == 6));
+        assert_eq!(info.puzzle_string.len(), 36);
+        assert_eq!(info.givens, 36 - board.open_cells());
+        let mask = board.givens_mask();
+        assert_eq!((mask.len(), mask[0].len()), (6, 6));
+        assert_eq!(mask.iter().flatten().filter(|&&given| given).count() as u32, info.givens);
+
+        // A solved 6×6 board completes its 6 rows, columns and 2×3 boxes.
+        let mut game = PlayerGameState::for_puzzle(&board);
+        game.board = board.solution.iter().map(|row| row.to_vec()).collect();
+        let progress = game.region_progress(BoardSize::Six);
+        let expected: [bool; 9] = std::array::from_fn(|i| i < 6);
+        assert_eq!((progress.rows, progress.cols, progress.boxes), (expected, expected, expected));
+        // Read with 9×9 rules, the padding means nothing is ever complete.
+        assert_eq!(game.region_progress(BoardSize::Nine).rows, [false; 9]);
+    }
+
+    #[test]
+    fn test_projected_rank_ignores_own_entry() {
+        let board = vec![entry(1, 9_000, true), entry(2, 5_000, false), entry(3, 4_000, false)];
+        let mut me = entry(3, 6_000, false);
+        assert_eq!(projected_rank(&board, &me), 2);
+        me.score = 3_000;
+        assert_eq!(projected_rank(&board, &me), 3);
+        me.completed = true;
+        assert_eq!(projected_rank(&board, &me), 2);
+        assert_eq!(projected_rank(&[], &me), 1);
+    }
+
+    #[test]
+    fn test_free_mistakes_forgive_first_errors() {
+        let mut game = PlayerGameState::new(&[[0u8; 9]; 9]);
+        let penalized: Vec<bool> = (0..3).map(|_| game.record_mistake(2)).collect();
+        assert_eq!(penalized, vec![false, false, true]);
+        assert_eq!(game.raw_mistake_count, 3);
+        assert_eq!(game.penalty_count, 1);
+
+        let mut strict = PlayerGameState::new(&[[0u8; 9]; 9]);
+        strict.record_mistake(0);
+        assert_eq!(strict.penalty_count, 1);
+    }
+
+    #[test]
+    fn test_rank_order_keeps_every_entry() {
+        let entries: Vec<LeaderboardEntry> = (0..=255u8)
+            .chain(0..=255u8)
+            .map(|id| entry(id, id as u64 * 10, id % 2 == 0))
+            .collect();
+        let ranked = rank_order(entries);
+        assert_eq!(ranked.len(), 512);
+        assert!(ranked.windows(2).all(|w| w[0].rank_cmp(&w[1]).is_le()));
+    }
+
+    #[test]
+    fn test_player_and_hub_scores_match() {
+        let tournament = Tournament {
+            start_time_micros: 1_000_000,
+            scoring_mode: ScoringMode::Balanced,
+            ..Tournament::default()
+        };
+        let mut game = PlayerGameState::new(&[[0u8; 9]; 9]);
+        game.penalty_count = 3;
+        // The first move came a minute after the start; it must not matter.
+        game.start_time_micros = 61_000_000;
+        let end = 301_000_000;
+
+        let player_side = game.calculate_score(&tournament, end);
+        let hub_side = tournament.score_at(end, game.penalty_count);
+        assert_eq!(player_side, hub_side);
+        assert_eq!(player_side, compute_score(300_000_000, 3, ScoringMode::Balanced));
+    }
+
+    #[test]
+    fn test_all_progress_orders_closest_first() {
+        let mut a = entry(1, 0, false);
+        a.cells_filled = 10;
+        a.cells_remaining = 36;
+        let mut b = entry(2, 0, false);
+        b.cells_filled = 40;
+        b.cells_remaining = 6;
+        b.penalty_count = 2;
+        let mut c = entry(3, 0, true);
+        c.cells_filled = 46;
+
+        let progress = ProgressEntry::from_entries(&[a, b, c]);
+        let order: Vec<u32> = progress.iter().map(|p| p.cells_remaining).collect();
+        assert_eq!(order, vec![0, 6, 36]);
+        assert_eq!(progress[1].cells_filled, 40);
+        assert_eq!(progress[1].penalty_count, 2);
+    }
+
+    #[test]
+    fn test_fastest_finishers_ignore_score() {
+        // High score but slow solve.
+        let mut slow = entry(1, 9_500, true);
+        slow.first_move_time_micros = 10_000_000;
+        slow.completion_time_micros = 610_000_000;
+        // Lower score (penalties) but quicker solve.
+        let mut fast = entry(2, 7_000, true);
+        fast.first_move_time_micros = 100_000_000;
+        fast.completion_time_micros = 400_000_000;
+        let unfinished = entry(3, 9_900, false);
+
+        let order: Vec<u64> = fastest_finishers(&[slow, unfinished, fast])
+            .iter().map(|e| e.score).collect();
+        assert_eq!(order, vec![7_000, 9_500]);
+    }
+
+    #[test]
+    fn test_score_adjustment_overrides_and_audits() {
+        let admin = AccountOwner::Address20([9; 20]);
+        let mut log = Vec::new();
+        let mut e = entry(1, 8_000, true);
+
+        log.push(ScoreAdjustment::apply(&mut e, 4, 8_500, "timer glitch".into(), admin, 77));
+        log.push(ScoreAdjustment::apply(&mut e, 4, 8_400, "recount".into(), admin, 88));
+
+        assert_eq!(e.score, 8_400);
+        assert_eq!(log.len(), 2);
+        assert_eq!((log[0].previous_score, log[0].new_score), (8_000, 8_500));
+        assert_eq!((log[1].previous_score, log[1].new_score), (8_500, 8_400));
+        assert_eq!(log[1].reason, "recount");
+        assert_eq!(log[0].admin, admin);
+        assert_eq!(log[0].wallet, e.wallet);
+    }
+
+    #[test]
+    fn test_puzzle_info_matches_grid() {
+        let board = sudoku::generate_puzzle(7, SudokuVariant::Classic).unwrap();
+        let info = PuzzleInfo::from_board(&board);
+
+        assert_eq!(info.puzzle_string.len(), 81);
+        assert_eq!(info.grid[0], board.puzzle[0].to_vec());
+        let from_string: Vec<u8> = info.puzzle_string.bytes()
+            .map(|b| if b == b'.' { 0 } else { b - b'0' })
+            .collect();
+        assert_eq!(from_string, board.puzzle.iter().flatten().copied().collect::<Vec<_>>());
+        assert_eq!(info.givens as usize, from_string.iter().filter(|&&v| v != 0).count());
+    }
+
+    #[test]
+    fn test_stale_puzzle_is_detected() {
+        let current = sudoku::generate_puzzle(11, SudokuVariant::Classic).unwrap();
+        let stale = sudoku::generate_puzzle(10, SudokuVariant::Classic).unwrap();
+        let tournament = Tournament {
+            seed: 11,
+            solution_commitment: sudoku::grid_commitment(&current.solution),
+            ..Tournament::default()
+        };
+
+        assert!(tournament.matches_puzzle(&current));
+        assert!(!tournament.matches_puzzle(&stale));
+
+        let x_tournament = Tournament { variant: SudokuVariant::XSudoku, ..tournament };
+        assert!(!x_tournament.matches_puzzle(&current));
+    }
+
+    #[test]
+    fn test_estimated_score_matches_hub_estimate() {
+        let tournament = Tournament { start_time_micros: 0, ..Tournament::default() };
+        let mut game = PlayerGameState::new(&[[0u8; 9]; 9]);
+        game.penalty_count = 2;
+
+        // 90 s in with 2 penalties under Balanced: 10000 - 180 - 200.
+        assert_eq!(game.estimated_score(&tournament, 90_000_000), Some(9_620));
+        assert_eq!(
+            game.estimated_score(&tournament, 90_000_000),
+            Some(tournament.score_at(90_000_000, 2)),
+        );
+
+        game.completed = true;
+        assert_eq!(game.estimated_score(&tournament, 90_000_000), None);
+    }
+
+    #[test]
+    fn test_clear_cells_skips_givens_and_empties() {
+        let mut puzzle = [[0u8; 9]; 9];
+        puzzle[0][0] = 5;
+        let mut game = PlayerGameState::new(&puzzle);
+        game.board[0][1] = 3;
+        game.board[4][4] = 7;
+
+        let results = game.clear_cells(&[(0, 0), (0, 1), (1, 1), (4, 4), (9, 0)], 0);
+        let outcomes: Vec<ClearOutcome> = results.iter().map(|r| r.outcome).collect();
+        assert_eq!(outcomes, vec![
+            ClearOutcome::Given,
+            ClearOutcome::Cleared,
+            ClearOutcome::AlreadyEmpty,
+            ClearOutcome::Cleared,
+            ClearOutcome::OutOfRange,
+        ]);
+        assert_eq!(game.board[0][0], 5);
+        assert_eq!(game.board[0][1], 0);
+        assert_eq!(game.board[4][4], 0);
+        assert_eq!(game.move_history.len(), 2);
+    }
+
+    #[test]
+    fn test_cells_remaining() {
+        let mut puzzle = [[0u8; 9]; 9];
+        puzzle[0][0] = 5;
+        puzzle[4][4] = 1;
+        let mut game = PlayerGameState::new(&puzzle);
+        assert_eq!(game.cells_remaining(), 79);
+
+        game.board[0][1] = 3;
+        game.board[8][8] = 9;
+        assert_eq!(game.cells_remaining(), 77);
+        assert_eq!(game.spectator_view().cells_remaining, 77);
+    }
+
+    #[test]
+    fn test_weekly_schedule_slots() {
+        let schedule = WeeklySchedule {
+            weekday: Weekday::Friday,
+            hour_utc: 18,
+            duration_secs: 3600,
+            seed_strategy: SeedStrategy::WeekNumber,
+        };
+        // Friday 1970-01-02 18:00 UTC.
+        let first = MICROS_PER_DAY + 18 * MICROS_PER_HOUR;
+        assert_eq!(schedule.slot_at_or_before(first - 1), None);
+        assert_eq!(schedule.next_start_at_or_after(0), first);
+
+        // Friday 2026-10-16 18:00 UTC.
+        let slot = 1_792_173_600_000_000;
+        assert_eq!(schedule.slot_at_or_before(slot), Some(slot));
+        assert_eq!(schedule.slot_at_or_before(slot + MICROS_PER_DAY), Some(slot));
+        assert_eq!(schedule.next_start_at_or_after(slot), slot);
+        assert_eq!(schedule.next_start_at_or_after(slot + 1), slot + MICROS_PER_WEEK);
+        assert_eq!(schedule.week_number(slot + MICROS_PER_WEEK), schedule.week_number(slot) + 1);
+    }
+
+    #[test]
+    fn test_team_scores_use_top_members() {
+        let members = vec![
+            ("owls".to_string(), entry(1, 9_000, true)),
+            ("owls".to_string(), entry(2, 8_000, true)),
+            ("owls".to_string(), entry(3, 7_000, false)),
+            ("owls".to_string(), entry(4, 6_000, false)),
+            ("foxes".to_string(), entry(5, 9_500, true)),
+            ("foxes".to_string(), entry(6, 9_400, true)),
+        ];
+
+        let teams = TeamScore::rank(&members);
+        assert_eq!(teams.len(), 2);
+        // Only the top three owls count: 9000 + 8000 + 7000.
+        assert_eq!(teams[0].team, "owls");
+        assert_eq!(teams[0].score, 24_000);
+        assert_eq!(teams[0].member_count, 4);
+        assert_eq!(teams[0].completions, 2);
+        assert_eq!(teams[1].team, "foxes");
+        assert_eq!(teams[1].score, 18_900);
+    }
+
+    #[test]
+    fn test_placement_accuracy() {
+        let mut e = entry(1, 0, false);
+        assert_eq!(e.placement_accuracy(), None);
+
+        e.move_count = 40;
+        e.penalty_count = 10;
+        assert_eq!(e.placement_accuracy(), Some(0.75));
+    }
+
+    #[test]
+    fn test_solve_progress_counts_one_conflict() {
+        let board = sudoku::generate_puzzle(321, SudokuVariant::Classic).expect("should generate");
+        let mut game = PlayerGameState::new(&board.puzzle);
+
+        // One correct entry, plus a duplicate of a given in the same row.
+        let (r, c) = (0..81).map(|i| (i / 9, i % 9))
+            .find(|&(r, c)| board.puzzle[r][c] == 0).unwrap();
+        game.board[r][c] = board.solution[r][c];
+        let dup_c = (0..9).find(|&c| game.board[r][c] == 0).unwrap();
+        let given_c = (0..9).find(|&c| game.given_mask[r][c]).unwrap();
+        game.board[r][dup_c] = game.board[r][given_c];
+
+        let progress = game.solve_progress(SudokuVariant::Classic, BoardSize::Nine);
+        assert_eq!(progress.filled, 2);
+        assert_eq!(progress.conflicting, 1);
+        assert_eq!(progress.remaining, game.cells_remaining());
+    }
+
+    #[test]
+    fn test_completion_rank() {
+        let mut first = entry(1, 9_000, true);
+        first.completion_time_micros = 100;
+        let mut second = entry(2, 8_500, true);
+        second.completion_time_micros = 200;
+        let in_progress = entry(3, 9_900, false);
+
+        let entries = vec![in_progress.clone(), first.clone()];
+        assert_eq!(completion_rank(&entries, &first.wallet), Some(1));
+
+        let entries = vec![in_progress.clone(), second.clone(), first];
+        assert_eq!(completion_rank(&entries, &second.wallet), Some(2));
+        assert_eq!(completion_rank(&entries, &in_progress.wallet), None);
+    }
+
+    #[test]
+    fn test_scoring_modes_reorder_players() {
+        // Fast but sloppy vs slower and clean.
+        let fast = (300_000_000, 5);
+        let clean = (500_000_000, 0);
+        let score = |(micros, penalties), mode| compute_score(micros, penalties, mode);
+
+        assert_eq!(score(fast, ScoringMode::Balanced), 8_900);
+        assert_eq!(score(clean, ScoringMode::Balanced), 9_000);
+
+        assert_eq!(score(fast, ScoringMode::Speedrun), 9_400);
+        assert_eq!(score(clean, ScoringMode::Speedrun), 9_000);
+
+        assert_eq!(score(fast, ScoringMode::Accuracy), 4_950);
+        assert_eq!(score(clean, ScoringMode::Accuracy), 9_917);
+
+        assert!(score(fast, ScoringMode::Speedrun) > score(clean, ScoringMode::Speedrun));
+        assert!(score(fast, ScoringMode::Balanced) < score(clean, ScoringMode::Balanced));
+        // Accuracy widens the gap Balanced already had.
+        let gap = |mode| score(clean, mode) - score(fast, mode);
+        assert!(gap(ScoringMode::Accuracy) > gap(ScoringMode::Balanced));
+    }
+
+    #[test]
+    fn test_season_sums_two_tournaments() {
+        let season = Season { id: 1, ..Season::default() };
+        let mut points = std::collections::BTreeMap::new();
+        let mut credit = |existing: Option<&LeaderboardEntry>, e: &LeaderboardEntry| {
+            let first = is_first_completion(existing, None, 1);
+            *points.entry(e.wallet).or_insert(0u64) += season.points_for(first, e.score);
+        };
+
+        // Tournament 1: both finish; player 1's completion is synced twice.
+        let (a1, b1) = (entry(1, 9_000, true), entry(2, 8_000, true));
+        credit(None, &a1);
+        credit(Some(&a1), &a1);
+        credit(Some(&entry(2, 300, false)), &b1);
+        // Tournament 2: the leaderboard starts empty again.
+        credit(None, &entry(1, 7_000, true));
+        credit(None, &entry(2, 9_500, true));
+
+        let standings = SeasonStanding::rank(points.into_iter()
+            .map(|(wallet, points)| SeasonStanding {
+                wallet,
+                discord_username: String::new(),
+                points,
+            })
+            .collect());
+        let totals: Vec<u64> = standings.iter().map(|s| s.points).collect();
+        assert_eq!(totals, vec![17_500, 16_000]);
+        assert_eq!(standings[0].wallet, AccountOwner::Address20([2; 20]));
+
+        let ended = Season { ended_at_micros: Some(1), ..season };
+        assert_eq!(ended.points_for(true, 9_000), 0);
+    }
+
+    #[test]
+    fn test_completion_after_reset_counts_once() {
+        let season = Season { id: 1, ..Season::default() };
+        let finished = entry(1, 9_000, true);
+
+        // Tournament 7: the first completion earns points and is recorded.
+        assert!(is_first_completion(None, None, 7));
+        assert_eq!(season.points_for(is_first_completion(None, Some(6), 7), 9_000), 9_000);
+        let counted_for = Some(7);
+        // A repeated sync still sees the completed entry.
+        assert!(!is_first_completion(Some(&finished), counted_for, 7));
+        // `ResetPlayerGame` dropped the entry; finishing again earns nothing.
+        assert!(!is_first_completion(None, counted_for, 7));
+        assert!(!is_first_completion(Some(&entry(1, 300, false)), counted_for, 7));
+        assert_eq!(season.points_for(is_first_completion(None, counted_for, 7), 9_500), 0);
+        // The next tournament counts again.
+        assert!(is_first_completion(None, counted_for, 8));
+    }
+
+    #[test]
+    fn test_placement_points_ignore_absolute_score() {
+        let season = Season {
+            id: 1,
+            placement_points: F1_PLACEMENT_POINTS.to_vec(),
+            ..Season::default()
+        };
+        let (a, b) = (1, 2);
+        // An easy puzzle A wins big, then two hard ones B wins narrowly.
+        let tournaments = [
+            vec![entry(a, 9_900, true), entry(b, 5_000, true), entry(3, 9_950, false)],
+            vec![entry(b, 3_000, true), entry(a, 2_900, true)],
+            vec![entry(b, 2_000, true), entry(a, 1_900, true)],
+        ];
+
+        let mut points = std::collections::BTreeMap::new();
+        let mut raw = std::collections::BTreeMap::new();
+        for rankings in tournaments {
+            let rankings = rank_order(rankings);
+            for e in &rankings {
+                assert_eq!(season.points_for(true, e.score), 0);
+                *raw.entry(e.wallet).or_insert(0u64) += e.score;
+            }
+            for (wallet, awarded) in season.placement_awards(&rankings) {
+                *points.entry(wallet).or_insert(0u64) += awarded;
+            }
+        }
+
+        let wallet = |id| AccountOwner::Address20([id; 20]);
+        assert!(raw[&wallet(a)] > raw[&wallet(b)]);
+        assert_eq!(points[&wallet(b)], 25 + 25 + 18);
+        assert_eq!(points[&wallet(a)], 25 + 18 + 18);
+        // Unfinished players place nowhere, whatever their score.
+        assert!(!points.contains_key(&wallet(3)));
+
+        assert!(is_valid_points_table(&F1_PLACEMENT_POINTS));
+        assert!(!is_valid_points_table(&[]));
+        assert!(!is_valid_points_table(&[1; MAX_PLACEMENT_POINTS + 1]));
+    }
+
+    #[test]
+    fn test_score_percentile_spread() {
+        let scores = [1_000u64, 2_000, 3_000, 4_000, 5_000];
+        assert_eq!(score_percentile(scores, 5_000), 80.0);
+        assert_eq!(score_percentile(scores, 3_500), 60.0);
+        assert_eq!(score_percentile(scores, 1_000), 0.0);
+        assert_eq!(score_percentile(scores, 9_000), 100.0);
+        // Ties aren't beaten.
+        assert_eq!(score_percentile([2_000, 2_000, 1_000, 3_000], 2_000), 25.0);
+        assert_eq!(score_percentile(Vec::new(), 5_000), 0.0);
+    }
+
+    #[test]
+    fn test_reward_for_winners_only() {
+        let tournament = Tournament { prizes: vec![500, 200, 0], ..Tournament::default() };
+        let rankings = rank_order(vec![
+            entry(1, 9_000, true),
+            entry(2, 9_500, false),
+            entry(3, 8_000, true),
+            entry(4, 7_000, true),
+            entry(5, 6_000, true),
+        ]);
+        let wallet = |id| AccountOwner::Address20([id; 20]);
+
+        assert_eq!(tournament.reward_for(&rankings, &wallet(1)), Some((1, 500)));
+        assert_eq!(tournament.reward_for(&rankings, &wallet(3)), Some((2, 200)));
+        // A higher score without finishing wins nothing.
+        assert_eq!(tournament.reward_for(&rankings, &wallet(2)), None);
+        // Third place pays 0; fourth is past the table.
+        assert_eq!(tournament.reward_for(&rankings, &wallet(4)), None);
+        assert_eq!(tournament.reward_for(&rankings, &wallet(5)), None);
+        assert_eq!(Tournament::default().reward_for(&rankings, &wallet(1)), None);
+    }
+
+    #[test]
+    fn test_is_valid_date_ymd() {
+        assert!(is_valid_date_ymd(20261016));
+        assert!(is_valid_date_ymd(20000101));
+        assert!(!is_valid_date_ymd(20261300));
+        assert!(!is_valid_date_ymd(20261000));
+        assert!(!is_valid_date_ymd(20261032));
+        assert!(!is_valid_date_ymd(261016));
+    }
+
+    #[test]
+    fn test_leaderboard_request_status() {
+        use LeaderboardRequestStatus::*;
+        let timeout = LEADERBOARD_REQUEST_TIMEOUT_MICROS;
+        assert_eq!(LeaderboardRequestStatus::compute(None, None, 0), Idle);
+        assert_eq!(LeaderboardRequestStatus::compute(Some(100), None, 200), Pending);
+        assert_eq!(LeaderboardRequestStatus::compute(Some(100), Some(50), 200), Pending);
+        assert_eq!(LeaderboardRequestStatus::compute(Some(100), Some(150), 200), Fulfilled);
+        assert_eq!(LeaderboardRequestStatus::compute(None, Some(150), 200), Fulfilled);
+        assert_eq!(LeaderboardRequestStatus::compute(Some(100), None, 101 + timeout), Stale);
+    }
+}
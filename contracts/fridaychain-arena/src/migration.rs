@@ -0,0 +1,771 @@
+// Copyright (c) FridayChain Arena Contributors
+// SPDX-License-Identifier: MIT
+
+//! Decoding of state written by older contract versions.
+//!
+//! Views store values as BCS, which has no field names or lengths, so
+//! `#[serde(default)]` can't fill in a field that an older blob lacks —
+//! decoding just runs out of input. Types that grew trailing fields
+//! deserialize through a visitor that defaults whatever is missing at the
+//! end, and `ArenaState::migrate` then rewrites them in the current layout.
+//!
+//! Running out of input only marks missing fields when the value is the
+//! last thing in the blob, as a view's value is. Nested inside a larger
+//! value (a `Vec<LeaderboardEntry>`, say) an old layout reads the next
+//! value's bytes instead, so containers of these types are migrated
+//! explicitly rather than decoded leniently.
+//!
+//! | Version | Change |
+//! |---------|--------|
+//! | 0 | Original layout |
+//! | 1 | `LeaderboardEntry` gains `first_move_time_micros`, `last_move_time_micros`, `is_suspicious` |
+//! | 2 | `LeaderboardEntry` gains `cells_filled`, `cells_remaining` |
+//! | 3 | `LeaderboardEntry` gains `move_sequence_hash` |
+//! | 4 | `tournaments_by_id` index added, backfilled from `past_tournaments` |
+//! | 5 | `LeaderboardEntry` gains `failed` |
+//! | 6 | `cached_leaderboard` dropped: its entries may be in any older layout |
+//! | 7 | `Tournament`, `PlayerInfo` and `SudokuBoard` rewritten with their later fields |
+//! | 8 | `PlayerGameState` rewritten with compact boards and its later fields |
+
+use crate::{
+    compact, BoardSize, CachedLeaderboard, LeaderboardEntry, MoveRecord, PlayerGameState,
+    PlayerInfo, ScoringMode, SudokuBoard, SudokuVariant, SyncedMove, Tournament,
+};
+use linera_sdk::linera_base_types::AccountOwner;
+use serde::{
+    de::{value::MapAccessDeserializer, Error, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use std::fmt;
+
+/// Whether `error` is BCS running out of input — the one decode failure
+/// that means a field is absent rather than malformed.
+fn is_end_of_input<E: fmt::Display>(error: &E) -> bool {
+    error.to_string() == linera_sdk::bcs::Error::Eof.to_string()
+}
+
+/// The next field, or its default if the blob ended before it (it was
+/// written before the field existed). Any other error is real.
+fn added_later<'de, A, T>(seq: &mut A) -> Result<T, A::Error>
+where
+    A: SeqAccess<'de>,
+    T: Deserialize<'de> + Default,
+{
+    match seq.next_element() {
+        Ok(value) => Ok(value.unwrap_or_default()),
+        Err(e) if is_end_of_input(&e) => Ok(T::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Implements `Deserialize` for a stored struct: the fields before
+/// `added_later` are required, the rest default when a BCS blob ends before
+/// them. Self-describing formats (JSON) get the same through
+/// `#[serde(default)]`.
+macro_rules! tail_defaulted {
+    (
+        $ty:ident { $($field:ident: $field_ty:ty,)+ }
+        added_later { $($late:ident: $late_ty:ty,)+ }
+    ) => {
+        const _: () = {
+            #[derive(Deserialize)]
+            struct Fields {
+                $($field: $field_ty,)+
+                $(#[serde(default)] $late: $late_ty,)+
+            }
+
+            struct TailVisitor;
+
+            impl<'de> Visitor<'de> for TailVisitor {
+                type Value = $ty;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str(concat!("a stored ", stringify!($ty)))
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<$ty, A::Error> {
+                    let mut read = 0;
+                    $(
+                        let $field: $field_ty = seq.next_element()?
+                            .ok_or_else(|| A::Error::invalid_length(read, &self))?;
+                        read += 1;
+                    )+
+                    let _ = read;
+                    $(let $late: $late_ty = added_later(&mut seq)?;)+
+                    Ok($ty { $($field,)+ $($late,)+ })
+                }
+
+                fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<$ty, A::Error> {
+                    let f = Fields::deserialize(MapAccessDeserializer::new(map))?;
+                    Ok($ty { $($field: f.$field,)+ $($late: f.$late,)+ })
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    const FIELDS: &[&str] = &[$(stringify!($field),)+ $(stringify!($late),)+];
+                    deserializer.deserialize_struct(stringify!($ty), FIELDS, TailVisitor)
+                }
+            }
+        };
+    };
+}
+
+tail_defaulted!(LeaderboardEntry {
+    wallet: AccountOwner,
+    discord_username: String,
+    score: u64,
+    completion_time_micros: u64,
+    penalty_count: u32,
+    move_count: u32,
+    completed: bool,
+} added_later {
+    first_move_time_micros: u64,
+    last_move_time_micros: u64,
+    is_suspicious: bool,
+    cells_filled: u32,
+    cells_remaining: u32,
+    move_sequence_hash: u64,
+    failed: bool,
+});
+
+tail_defaulted!(Tournament {
+    id: u64,
+    seed: u64,
+    start_time_micros: u64,
+    end_time_micros: u64,
+    active: bool,
+    total_players: u32,
+    total_completions: u32,
+} added_later {
+    solution_commitment: [u8; 32],
+    variant: SudokuVariant,
+    restricted: bool,
+    scoring_mode: ScoringMode,
+    allow_bulk_submit: bool,
+    free_mistakes: u32,
+    max_penalties: Option<u32>,
+    unique_solution: bool,
+    paused_micros: u64,
+    paused_since_micros: Option<u64>,
+    broadcast_top_n: Option<u32>,
+    box_bonus_enabled: bool,
+    board_size: BoardSize,
+    prizes: Vec<u64>,
+    blind_mode: bool,
+});
+
+tail_defaulted!(PlayerInfo {
+    wallet: AccountOwner,
+    discord_username: String,
+    registered_at_micros: u64,
+} added_later {
+    team: Option<String>,
+    preferences: Option<String>,
+});
+
+tail_defaulted!(SudokuBoard {
+    puzzle: [[u8; 9]; 9],
+    solution: [[u8; 9]; 9],
+} added_later {
+    variant: SudokuVariant,
+    size: BoardSize,
+});
+
+/// A stored `PlayerGameState` board in either layout: 81 flat cells
+/// (`compact::board`) or the nine length-prefixed rows schema v0 wrote.
+/// Both start with a length, 81 or 9, which tells them apart.
+struct AnyBoard {
+    rows: Vec<Vec<u8>>,
+    nested: bool,
+}
+
+impl<'de> Deserialize<'de> for AnyBoard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BoardVisitor;
+
+        impl<'de> Visitor<'de> for BoardVisitor {
+            type Value = AnyBoard;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("81 cells or 9 rows")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<AnyBoard, A::Error> {
+                match seq.size_hint() {
+                    Some(81) => {
+                        let mut cells = Vec::with_capacity(81);
+                        while let Some(value) = seq.next_element::<u8>()? {
+                            if value > 9 {
+                                return Err(A::Error::custom("cell value out of range"));
+                            }
+                            cells.push(value);
+                        }
+                        if cells.len() != 81 {
+                            return Err(A::Error::invalid_length(cells.len(), &self));
+                        }
+                        let rows = cells.chunks(9).map(|row| row.to_vec()).collect();
+                        Ok(AnyBoard { rows, nested: false })
+                    }
+                    Some(9) => {
+                        let mut rows = Vec::with_capacity(9);
+                        while let Some(row) = seq.next_element::<Vec<u8>>()? {
+                            rows.push(row);
+                        }
+                        Ok(AnyBoard { rows, nested: true })
+                    }
+                    other => Err(A::Error::invalid_length(other.unwrap_or(0), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_seq(BoardVisitor)
+    }
+}
+
+#[derive(Deserialize)]
+struct CompactMask(#[serde(with = "compact::mask")] Vec<Vec<bool>>);
+
+/// `PlayerGameState` as self-describing formats carry it.
+#[derive(Deserialize)]
+struct GameFields {
+    #[serde(with = "compact::board")]
+    board: Vec<Vec<u8>>,
+    #[serde(with = "compact::mask")]
+    given_mask: Vec<Vec<bool>>,
+    penalty_count: u32,
+    move_count: u32,
+    start_time_micros: u64,
+    #[serde(default)]
+    last_move_time_micros: u64,
+    completed: bool,
+    completion_time_micros: Option<u64>,
+    score: u64,
+    #[serde(default)]
+    move_history: Vec<MoveRecord>,
+    #[serde(default)]
+    move_times: Vec<u64>,
+    #[serde(default)]
+    board_submissions: u32,
+    #[serde(default)]
+    raw_mistake_count: u32,
+    #[serde(default)]
+    failed: bool,
+    #[serde(default)]
+    logic_hints_used: u32,
+    #[serde(default)]
+    pending_sync: Vec<SyncedMove>,
+}
+
+/// Reads a stored game in the schema v0 layout (nested boards, no
+/// `last_move_time_micros`, nothing after `score`) or the current one,
+/// whose fields after `score` default when the blob ends before them.
+struct GameVisitor;
+
+impl<'de> Visitor<'de> for GameVisitor {
+    type Value = PlayerGameState;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a stored PlayerGameState")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<PlayerGameState, A::Error> {
+        fn field<'de, A: SeqAccess<'de>, T: Deserialize<'de>>(seq: &mut A) -> Result<T, A::Error> {
+            seq.next_element()?.ok_or_else(|| A::Error::custom("truncated player game"))
+        }
+        let board: AnyBoard = field(&mut seq)?;
+        let given_mask = if board.nested {
+            field(&mut seq)?
+        } else {
+            field::<_, CompactMask>(&mut seq)?.0
+        };
+        let penalty_count = field(&mut seq)?;
+        let move_count = field(&mut seq)?;
+        let start_time_micros = field(&mut seq)?;
+        let last_move_time_micros = if board.nested { 0 } else { field(&mut seq)? };
+        let mut game = PlayerGameState {
+            board: board.rows,
+            given_mask,
+            penalty_count,
+            move_count,
+            start_time_micros,
+            last_move_time_micros,
+            completed: field(&mut seq)?,
+            completion_time_micros: field(&mut seq)?,
+            score: field(&mut seq)?,
+            move_history: Vec::new(),
+            move_times: Vec::new(),
+            board_submissions: 0,
+            raw_mistake_count: 0,
+            failed: false,
+            logic_hints_used: 0,
+            pending_sync: Vec::new(),
+            tracker: None,
+        };
+        if !board.nested {
+            game.move_history = added_later(&mut seq)?;
+            game.move_times = added_later(&mut seq)?;
+            game.board_submissions = added_later(&mut seq)?;
+            game.raw_mistake_count = added_later(&mut seq)?;
+            game.failed = added_later(&mut seq)?;
+            game.logic_hints_used = added_later(&mut seq)?;
+            game.pending_sync = added_later(&mut seq)?;
+        }
+        Ok(game)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<PlayerGameState, A::Error> {
+        let f = GameFields::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(PlayerGameState {
+            board: f.board,
+            given_mask: f.given_mask,
+            penalty_count: f.penalty_count,
+            move_count: f.move_count,
+            start_time_micros: f.start_time_micros,
+            last_move_time_micros: f.last_move_time_micros,
+            completed: f.completed,
+            completion_time_micros: f.completion_time_micros,
+            score: f.score,
+            move_history: f.move_history,
+            move_times: f.move_times,
+            board_submissions: f.board_submissions,
+            raw_mistake_count: f.raw_mistake_count,
+            failed: f.failed,
+            logic_hints_used: f.logic_hints_used,
+            pending_sync: f.pending_sync,
+            tracker: None,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PlayerGameState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const FIELDS: &[&str] = &[
+            "board",
+            "given_mask",
+            "penalty_count",
+            "move_count",
+            "start_time_micros",
+            "last_move_time_micros",
+            "completed",
+            "completion_time_micros",
+            "score",
+            "move_history",
+            "move_times",
+            "board_submissions",
+            "raw_mistake_count",
+            "failed",
+            "logic_hints_used",
+            "pending_sync",
+        ];
+        deserializer.deserialize_struct("PlayerGameState", FIELDS, GameVisitor)
+    }
+}
+
+/// `CachedLeaderboard` as self-describing formats carry it.
+#[derive(Deserialize)]
+#[serde(rename = "CachedLeaderboard")]
+struct CacheFields {
+    entries: Vec<LeaderboardEntry>,
+    tournament_id: u64,
+    is_active: bool,
+    fetched_at_micros: u64,
+}
+
+/// Reads a cached leaderboard to the end of input. The cache only ever sits
+/// at the end of a blob (the `cached_leaderboard` register), and its entries
+/// may be in any older `LeaderboardEntry` layout, which can't be told apart
+/// inside a vector. Rather than fail the whole state load, a blob that
+/// doesn't decode exactly in the current layout reads as an empty cache;
+/// the next leaderboard update refills it.
+struct CacheVisitor;
+
+impl<'de> Visitor<'de> for CacheVisitor {
+    type Value = CachedLeaderboard;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a cached leaderboard")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<CachedLeaderboard, A::Error> {
+        fn field<'de, A: SeqAccess<'de>, T: Deserialize<'de>>(seq: &mut A) -> Result<T, A::Error> {
+            seq.next_element()?.ok_or_else(|| A::Error::custom("truncated cached leaderboard"))
+        }
+        let decoded = (|| {
+            Ok::<_, A::Error>(CachedLeaderboard {
+                entries: field(&mut seq)?,
+                tournament_id: field(&mut seq)?,
+                is_active: field(&mut seq)?,
+                fetched_at_micros: field(&mut seq)?,
+            })
+        })();
+
+        // Skip whatever is left; leftover bytes mean another layout.
+        let mut exact = true;
+        loop {
+            match seq.next_element::<u8>() {
+                Ok(Some(_)) => exact = false,
+                Ok(None) => break,
+                Err(e) if is_end_of_input(&e) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(decoded.ok().filter(|_| exact).unwrap_or_default())
+    }
+}
+
+impl<'de> Deserialize<'de> for CachedLeaderboard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let f = CacheFields::deserialize(deserializer)?;
+            return Ok(CachedLeaderboard {
+                entries: f.entries,
+                tournament_id: f.tournament_id,
+                is_active: f.is_active,
+                fetched_at_micros: f.fetched_at_micros,
+            });
+        }
+        deserializer.deserialize_tuple(usize::MAX, CacheVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        BoardSize, CachedLeaderboard, LeaderboardEntry, MoveRecord, PlayerGameState, PlayerInfo,
+        ScoringMode, SudokuBoard, SudokuVariant, Tournament,
+    };
+    use linera_sdk::linera_base_types::AccountOwner;
+    use serde::Serialize;
+
+    /// `LeaderboardEntry` as schema v0 wrote it.
+    #[derive(Serialize)]
+    struct LeaderboardEntryV0 {
+        wallet: AccountOwner,
+        discord_username: String,
+        score: u64,
+        completion_time_micros: u64,
+        penalty_count: u32,
+        move_count: u32,
+        completed: bool,
+    }
+
+    #[test]
+    fn test_v0_leaderboard_entry_round_trip() {
+        let v0 = LeaderboardEntryV0 {
+            wallet: AccountOwner::Address20([7; 20]),
+            discord_username: "alice".into(),
+            score: 9_000,
+            completion_time_micros: 42,
+            penalty_count: 2,
+            move_count: 50,
+            completed: true,
+        };
+        let blob = linera_sdk::bcs::to_bytes(&v0).unwrap();
+        let entry: LeaderboardEntry = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!(entry.discord_username, "alice");
+        assert_eq!((entry.score, entry.penalty_count, entry.move_count), (9_000, 2, 50));
+        assert!(entry.completed);
+        assert_eq!(entry.first_move_time_micros, 0);
+        assert!(!entry.is_suspicious);
+        assert_eq!(entry.cells_filled, 0);
+        assert_eq!(entry.move_sequence_hash, 0);
+
+        // Rewritten in the current layout, it decodes the same way.
+        let mut current = entry.clone();
+        current.first_move_time_micros = 5;
+        current.is_suspicious = true;
+        current.cells_remaining = 12;
+        current.move_sequence_hash = 99;
+        let blob = linera_sdk::bcs::to_bytes(&current).unwrap();
+        assert!(blob.len() > linera_sdk::bcs::to_bytes(&v0).unwrap().len());
+        let decoded: LeaderboardEntry = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!(decoded.first_move_time_micros, 5);
+        assert!(decoded.is_suspicious);
+        assert_eq!(decoded.cells_remaining, 12);
+        assert_eq!(decoded.move_sequence_hash, 99);
+
+        let json = linera_sdk::serde_json::to_string(&v0).unwrap();
+        let decoded: LeaderboardEntry = linera_sdk::serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.score, 9_000);
+        assert_eq!(decoded.last_move_time_micros, 0);
+    }
+
+    #[test]
+    fn test_v0_tournament_round_trip() {
+        #[derive(Serialize)]
+        struct TournamentV0 {
+            id: u64,
+            seed: u64,
+            start_time_micros: u64,
+            end_time_micros: u64,
+            active: bool,
+            total_players: u32,
+            total_completions: u32,
+        }
+        let v0 = TournamentV0 {
+            id: 3,
+            seed: 77,
+            start_time_micros: 10,
+            end_time_micros: 20,
+            active: true,
+            total_players: 5,
+            total_completions: 1,
+        };
+        let blob = linera_sdk::bcs::to_bytes(&v0).unwrap();
+        let tournament: Tournament = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!((tournament.id, tournament.seed), (3, 77));
+        assert_eq!((tournament.start_time_micros, tournament.end_time_micros), (10, 20));
+        assert!(tournament.active);
+        assert_eq!((tournament.total_players, tournament.total_completions), (5, 1));
+        assert_eq!(tournament.solution_commitment, [0; 32]);
+        assert_eq!(tournament.variant, SudokuVariant::Classic);
+        assert_eq!(tournament.scoring_mode, ScoringMode::Balanced);
+        assert_eq!(tournament.board_size, BoardSize::Nine);
+        assert_eq!(tournament.max_penalties, None);
+        assert!(tournament.prizes.is_empty());
+        assert!(!tournament.blind_mode);
+
+        let mut current = tournament;
+        current.solution_commitment = [4; 32];
+        current.board_size = BoardSize::Six;
+        current.max_penalties = Some(3);
+        current.prizes = vec![50, 20];
+        current.blind_mode = true;
+        let blob = linera_sdk::bcs::to_bytes(&current).unwrap();
+        let decoded: Tournament = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!(decoded.solution_commitment, [4; 32]);
+        assert_eq!(decoded.board_size, BoardSize::Six);
+        assert_eq!(decoded.max_penalties, Some(3));
+        assert_eq!(decoded.prizes, vec![50, 20]);
+        assert!(decoded.blind_mode);
+
+        let json = linera_sdk::serde_json::to_string(&v0).unwrap();
+        let decoded: Tournament = linera_sdk::serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.seed, 77);
+        assert!(!decoded.unique_solution);
+    }
+
+    #[test]
+    fn test_v0_player_info_round_trip() {
+        #[derive(Serialize)]
+        struct PlayerInfoV0 {
+            wallet: AccountOwner,
+            discord_username: String,
+            registered_at_micros: u64,
+        }
+        let v0 = PlayerInfoV0 {
+            wallet: AccountOwner::Address20([7; 20]),
+            discord_username: "alice".into(),
+            registered_at_micros: 42,
+        };
+        let blob = linera_sdk::bcs::to_bytes(&v0).unwrap();
+        let info: PlayerInfo = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!(info.discord_username, "alice");
+        assert_eq!(info.registered_at_micros, 42);
+        assert_eq!(info.team, None);
+        assert_eq!(info.preferences, None);
+
+        let mut current = info;
+        current.team = Some("owls".into());
+        current.preferences = Some("{}".into());
+        let blob = linera_sdk::bcs::to_bytes(&current).unwrap();
+        let decoded: PlayerInfo = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!(decoded.team.as_deref(), Some("owls"));
+        assert_eq!(decoded.preferences.as_deref(), Some("{}"));
+
+        let json = linera_sdk::serde_json::to_string(&v0).unwrap();
+        let decoded: PlayerInfo = linera_sdk::serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.wallet, AccountOwner::Address20([7; 20]));
+        assert_eq!(decoded.team, None);
+    }
+
+    #[test]
+    fn test_v0_sudoku_board_round_trip() {
+        #[derive(Serialize)]
+        struct SudokuBoardV0 {
+            puzzle: [[u8; 9]; 9],
+            solution: [[u8; 9]; 9],
+        }
+        let mut v0 = SudokuBoardV0 { puzzle: [[0; 9]; 9], solution: [[5; 9]; 9] };
+        v0.puzzle[0][0] = 5;
+        let blob = linera_sdk::bcs::to_bytes(&v0).unwrap();
+        let board: SudokuBoard = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!(board.puzzle[0][0], 5);
+        assert_eq!(board.solution, [[5; 9]; 9]);
+        assert_eq!(board.variant, SudokuVariant::Classic);
+        assert_eq!(board.size, BoardSize::Nine);
+
+        let mut current = board;
+        current.size = BoardSize::Four;
+        let blob = linera_sdk::bcs::to_bytes(&current).unwrap();
+        let decoded: SudokuBoard = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!(decoded.size, BoardSize::Four);
+
+        let json = linera_sdk::serde_json::to_string(&v0).unwrap();
+        let decoded: SudokuBoard = linera_sdk::serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.puzzle[0][0], 5);
+        assert_eq!(decoded.size, BoardSize::Nine);
+    }
+
+    #[test]
+    fn test_v0_player_game_round_trip() {
+        #[derive(Serialize)]
+        struct PlayerGameStateV0 {
+            board: Vec<Vec<u8>>,
+            given_mask: Vec<Vec<bool>>,
+            penalty_count: u32,
+            move_count: u32,
+            start_time_micros: u64,
+            completed: bool,
+            completion_time_micros: Option<u64>,
+            score: u64,
+        }
+        let mut puzzle = [[0u8; 9]; 9];
+        puzzle[0][0] = 5;
+        puzzle[8][8] = 9;
+        let mut game = PlayerGameState::new(&puzzle);
+        game.board[4][4] = 7;
+        let v0 = PlayerGameStateV0 {
+            board: game.board.clone(),
+            given_mask: game.given_mask.clone(),
+            penalty_count: 2,
+            move_count: 12,
+            start_time_micros: 30,
+            completed: true,
+            completion_time_micros: Some(90),
+            score: 800,
+        };
+        let blob = linera_sdk::bcs::to_bytes(&v0).unwrap();
+        let decoded: PlayerGameState = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!(decoded.board, game.board);
+        assert_eq!(decoded.given_mask, game.given_mask);
+        assert_eq!((decoded.penalty_count, decoded.move_count), (2, 12));
+        assert_eq!(decoded.start_time_micros, 30);
+        assert_eq!(decoded.last_move_time_micros, 0);
+        assert!(decoded.completed);
+        assert_eq!(decoded.completion_time_micros, Some(90));
+        assert_eq!(decoded.score, 800);
+        assert!(decoded.move_history.is_empty());
+        assert_eq!(decoded.board_submissions, 0);
+
+        // Rewritten in the current layout, it decodes the same way.
+        let mut current = decoded;
+        current.last_move_time_micros = 85;
+        current.move_history = vec![MoveRecord { row: 4, col: 4, value: 7 }];
+        current.logic_hints_used = 1;
+        let blob = linera_sdk::bcs::to_bytes(&current).unwrap();
+        assert!(blob.len() < linera_sdk::bcs::to_bytes(&v0).unwrap().len());
+        let decoded: PlayerGameState = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        assert_eq!(decoded.board, game.board);
+        assert_eq!(decoded.given_mask, game.given_mask);
+        assert_eq!(decoded.last_move_time_micros, 85);
+        assert_eq!(decoded.score, 800);
+        assert_eq!(decoded.move_history.len(), 1);
+        assert_eq!(decoded.logic_hints_used, 1);
+
+        let mut json = linera_sdk::serde_json::to_value(&current).unwrap();
+        json.as_object_mut().unwrap().remove("move_history");
+        let decoded: PlayerGameState = linera_sdk::serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.board, game.board);
+        assert!(decoded.move_history.is_empty());
+        assert_eq!(decoded.logic_hints_used, 1);
+    }
+
+    fn v0_entry(id: u8) -> LeaderboardEntryV0 {
+        LeaderboardEntryV0 {
+            wallet: AccountOwner::Address20([id; 20]),
+            discord_username: format!("player{}", id),
+            score: 100 * id as u64,
+            completion_time_micros: 0,
+            penalty_count: 0,
+            move_count: 3,
+            completed: false,
+        }
+    }
+
+    #[test]
+    fn test_only_end_of_input_counts_as_missing() {
+        // A present but malformed field (`is_suspicious` = 7) is an error.
+        let mut blob = linera_sdk::bcs::to_bytes(&v0_entry(7)).unwrap();
+        blob.extend([0; 16]);
+        blob.push(7);
+        assert!(linera_sdk::bcs::from_bytes::<LeaderboardEntry>(&blob).is_err());
+
+        // Nested, the first old entry would read the second one's bytes as
+        // its new fields; that has to fail rather than decode to garbage.
+        let blob = linera_sdk::bcs::to_bytes(&vec![v0_entry(7), v0_entry(8)]).unwrap();
+        assert!(linera_sdk::bcs::from_bytes::<Vec<LeaderboardEntry>>(&blob).is_err());
+    }
+
+    #[test]
+    fn test_cached_leaderboard_with_old_entries_reads_as_empty() {
+        #[derive(Serialize)]
+        struct CachedLeaderboardV0 {
+            entries: Vec<LeaderboardEntryV0>,
+            tournament_id: u64,
+            is_active: bool,
+            fetched_at_micros: u64,
+        }
+        let old = Some(CachedLeaderboardV0 {
+            entries: vec![v0_entry(7), v0_entry(8)],
+            tournament_id: 4,
+            is_active: true,
+            fetched_at_micros: 99,
+        });
+        let blob = linera_sdk::bcs::to_bytes(&old).unwrap();
+        let decoded: Option<CachedLeaderboard> = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        let decoded = decoded.unwrap();
+        assert!(decoded.entries.is_empty());
+        assert_eq!(decoded.tournament_id, 0);
+
+        // The current layout round-trips exactly.
+        let v0 = linera_sdk::bcs::to_bytes(&v0_entry(7)).unwrap();
+        let entry: LeaderboardEntry = linera_sdk::bcs::from_bytes(&v0).unwrap();
+        let current = Some(CachedLeaderboard {
+            entries: vec![entry.clone(), entry],
+            tournament_id: 4,
+            is_active: true,
+            fetched_at_micros: 99,
+        });
+        let blob = linera_sdk::bcs::to_bytes(&current).unwrap();
+        let decoded: Option<CachedLeaderboard> = linera_sdk::bcs::from_bytes(&blob).unwrap();
+        let decoded = decoded.unwrap();
+        assert_eq!(decoded.entries.len(), 2);
+        assert_eq!(decoded.entries[1].discord_username, "player7");
+        assert_eq!(decoded.tournament_id, 4);
+        assert!(decoded.is_active);
+        assert_eq!(decoded.fetched_at_micros, 99);
+    }
+
+    #[test]
+    fn test_original_views_keep_their_positions() {
+        // `ArenaState` as first deployed; views are keyed by position.
+        const ORIGINAL: [&str; 14] = [
+            "hub_chain_id",
+            "admin_owner",
+            "players",
+            "player_count",
+            "active_tournament",
+            "tournament_counter",
+            "current_puzzle",
+            "player_games",
+            "leaderboard",
+            "leaderboard_log",
+            "event_log",
+            "event_counter",
+            "cached_leaderboard",
+            "past_tournaments",
+        ];
+        let source = include_str!("state.rs");
+        let start = source.find("pub struct ArenaState {").unwrap();
+        let end = start + source[start..].find("\n}").unwrap();
+        let fields: Vec<&str> = source[start..end]
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("pub "))
+            .filter_map(|field| field.split_once(':').map(|(name, _)| name))
+            .collect();
+        assert_eq!(fields[..ORIGINAL.len()], ORIGINAL);
+        assert!(fields.len() > ORIGINAL.len());
+    }
+}
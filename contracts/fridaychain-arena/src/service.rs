@@ -6,11 +6,17 @@ use std::sync::Arc;
 
 use self::state::ArenaState;
 use fridaychain_arena::{
-    sudoku, ArenaParameters, CachedLeaderboard, FridayChainArenaAbi, LeaderboardEntry,
-    MoveInput, Operation, PlayerGameState, PlayerInfo, Tournament, TournamentStats,
-    VerifyResult,
+    count_active, count_online, duplicate_sequences, fastest_finishers, inbox_range,
+    is_valid_date_ymd, log_page, projected_rank, score_percentile, sudoku, ActivityItem,
+    ArenaConfig, ArenaEvent, ArenaParameters, CachedLeaderboard, Capabilities, CellPosition,
+    DuplicateSequence, FridayChainArenaAbi, LeaderboardEntry, LeaderboardRequestStatus, MoveInput,
+    NotificationPage, Operation, PlayerDashboard, PlayerGameState, PlayerInfo, ProgressEntry,
+    PuzzleInfo, PuzzleParams, RegionProgress, ScoreAdjustment, ScoringMode, Season, SeasonStanding,
+    SolveProgress, SpectatorView, SudokuBoard, SudokuVariant, Symmetry, TeamScore, TimedMoveInput,
+    Tournament, TournamentReport, TournamentResult, TournamentStats, VerifyResult,
+    MAX_PLAYER_HISTORY,
 };
-use async_graphql::{EmptySubscription, Object, Request, Response, Schema};
+use async_graphql::{EmptySubscription, Json, Object, Request, Response, Schema};
 use linera_sdk::{
     linera_base_types::WithServiceAbi,
     graphql::GraphQLMutationRoot,
@@ -44,7 +50,10 @@ impl Service for FridayChainArenaService {
 
     async fn handle_query(&self, request: Request) -> Response {
         let schema = Schema::build(
-            QueryRoot { state: self.state.clone() },
+            QueryRoot {
+                state: self.state.clone(),
+                runtime: self.runtime.clone(),
+            },
             Operation::mutation_root(self.runtime.clone()),
             EmptySubscription,
         )
@@ -55,6 +64,7 @@ impl Service for FridayChainArenaService {
 
 struct QueryRoot {
     state: Arc<ArenaState>,
+    runtime: Arc<ServiceRuntime<FridayChainArenaService>>,
 }
 
 #[Object]
@@ -64,6 +74,21 @@ impl QueryRoot {
         self.state.players.get(&owner).await.unwrap_or(None)
     }
 
+    /// `player`, `playerGameState`, `estimatedScore`, `cellsRemaining` and
+    /// the player's cached-leaderboard rank in one round trip. Missing
+    /// pieces are null rather than failing the whole query.
+    async fn player_dashboard(&self, wallet: String) -> Option<PlayerDashboard> {
+        let owner = parse_account_owner(&wallet)?;
+        let player = self.state.players.get(&owner).await.unwrap_or(None);
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None);
+        let cached = self.state.cached_leaderboard.get()
+            .as_ref().map(|c| c.entries.clone()).unwrap_or_default();
+        let now = self.runtime.system_time().micros();
+        Some(PlayerDashboard::build(
+            owner, player, game, self.state.get_active_tournament(), &cached, now,
+        ))
+    }
+
     async fn all_players(&self) -> Vec<PlayerInfo> {
         let mut players = Vec::new();
         self.state.players.for_each_index_value(|_wallet, info| {
@@ -73,20 +98,168 @@ impl QueryRoot {
         players
     }
 
+    /// The Hub chain, the admin, and whether this chain is the Hub, so a
+    /// client can confirm which deployment it's talking to.
+    async fn config(&self) -> ArenaConfig {
+        ArenaConfig::new(
+            &self.runtime.application_parameters(),
+            *self.state.admin_owner.get(),
+            self.runtime.chain_id(),
+        )
+    }
+
+    /// Features and versions of this deployment. Additive: new fields may
+    /// appear, existing ones keep their meaning.
+    async fn capabilities(&self) -> Capabilities {
+        Capabilities::new(
+            *self.state.schema_version.get(),
+            self.state.weekly_schedule.get().is_some(),
+            self.state.current_season.get().as_ref().is_some_and(Season::is_active),
+        )
+    }
+
+    /// The daily puzzle for `dateYmd` (`YYYYMMDD`), the same for everyone.
+    /// Just a puzzle to solve locally: no tournament, no leaderboard. Null
+    /// for an invalid date.
+    async fn daily_puzzle(&self, date_ymd: u32) -> Option<Vec<Vec<u8>>> {
+        if !is_valid_date_ymd(date_ymd) {
+            return None;
+        }
+        let board = sudoku::daily_puzzle(date_ymd)?;
+        Some(board.puzzle.iter().map(|row| row.to_vec()).collect())
+    }
+
+    /// The player's best recorded time (µs) for a daily puzzle.
+    async fn daily_result(&self, wallet: String, date_ymd: u32) -> Option<u64> {
+        let owner = parse_account_owner(&wallet)?;
+        self.state.daily_results.get(&(owner, date_ymd)).await.unwrap_or(None)
+    }
+
     async fn player_count(&self) -> u64 {
         *self.state.player_count.get()
     }
 
+    /// The last username the Hub rejected for this chain's player, if any.
+    async fn username_rejection(&self) -> Option<String> {
+        self.state.username_rejection.get().clone()
+    }
+
+    /// Announced start time of the next tournament, if check-in is open.
+    async fn scheduled_start_micros(&self) -> Option<u64> {
+        *self.state.scheduled_start_micros.get()
+    }
+
+    /// When the next tournament is expected to start: the announced lobby
+    /// start if there is one, otherwise the next weekly slot (Hub). Weekly
+    /// slots activate lazily on the Hub's next operation or message.
+    async fn next_scheduled_start(&self) -> Option<u64> {
+        if let Some(start) = *self.state.scheduled_start_micros.get() {
+            return Some(start);
+        }
+        let schedule = (*self.state.weekly_schedule.get())?;
+        let now = self.runtime.system_time().micros();
+        Some(schedule.next_start_at_or_after(now))
+    }
+
+    /// Number of players checked in for the scheduled tournament (Hub).
+    async fn checkin_count(&self) -> u64 {
+        self.state.checked_in.count().await.unwrap_or(0) as u64
+    }
+
     async fn active_tournament(&self) -> Option<Tournament> {
         self.state.active_tournament.get().clone()
     }
 
+    /// The current puzzle, side × side (9×9 unless a mini board), 0 for
+    /// blanks.
     async fn puzzle_board(&self) -> Option<Vec<Vec<u8>>> {
+        self.state.current_puzzle.get().as_ref().map(SudokuBoard::grid)
+    }
+
+    /// Grid, 81-character string, givens count, variant, and difficulty of
+    /// the current puzzle in one call. `None` when no puzzle is loaded.
+    async fn puzzle_info(&self) -> Option<PuzzleInfo> {
+        self.state.current_puzzle.get().as_ref().map(PuzzleInfo::from_board)
+    }
+
+    /// Which cells of the current puzzle are givens, without their values.
+    async fn givens_mask(&self) -> Option<Vec<Vec<bool>>> {
+        self.state.current_puzzle.get().as_ref().map(SudokuBoard::givens_mask)
+    }
+
+    /// The full solution of the most recent tournament, available only once
+    /// it has ended. Hash it with SHA-256 (81 bytes, row-major) and compare
+    /// against the tournament's `solutionCommitment`.
+    async fn reveal_solution(&self) -> Option<Vec<Vec<u8>>> {
+        let ended = self.state.active_tournament.get()
+            .as_ref().map(|t| !t.active).unwrap_or(false);
+        if !ended {
+            return None;
+        }
         self.state.current_puzzle.get().as_ref().map(|board| {
-            board.puzzle.iter().map(|row| row.to_vec()).collect()
+            board.solution.iter().map(|row| row.to_vec()).collect()
+        })
+    }
+
+    /// Whether `claimed` is the current tournament's solution, checked
+    /// against its `solutionCommitment`. Answers only yes or no, so it can
+    /// be used while the tournament is running.
+    async fn verify_solution_claim(&self, claimed: Vec<Vec<u8>>) -> bool {
+        self.state.active_tournament.get().as_ref()
+            .is_some_and(|t| t.verifies_solution(&claimed))
+    }
+
+    /// Whether the current puzzle has exactly one solution — a fairness check
+    /// for admins before results are announced. Cached on the tournament when
+    /// the puzzle was generated; `None` if no puzzle is loaded.
+    async fn puzzle_is_unique(&self) -> Option<bool> {
+        self.state.current_puzzle.get().as_ref()?;
+        self.state.active_tournament.get().as_ref().map(|t| t.unique_solution)
+    }
+
+    /// Whether a past tournament already started with `seed` (Hub).
+    async fn seed_used(&self, seed: u64) -> bool {
+        self.state.used_seeds.contains_key(&seed).await.unwrap_or(false)
+    }
+
+    /// Symmetry of the current puzzle's blank cells, for display. `None` if
+    /// no puzzle is loaded.
+    async fn puzzle_symmetry(&self) -> Option<Symmetry> {
+        self.state.current_puzzle.get().as_ref()
+            .map(|board| sudoku::blank_symmetry(&board.puzzle, board.size))
+    }
+
+    /// Seed and generator settings for the active tournament's puzzle, so
+    /// third-party tools can regenerate it. `None` outside a live tournament.
+    async fn puzzle_params(&self) -> Option<PuzzleParams> {
+        let tournament = self.state.get_active_tournament()?;
+        let board = self.state.current_puzzle.get().as_ref()?;
+        Some(PuzzleParams {
+            seed: tournament.seed,
+            variant: board.variant,
+            board_size: board.size,
+            difficulty: sudoku::rate_sized(&board.puzzle, board.variant, board.size),
+            cells_removed: board.open_cells(),
         })
     }
 
+    /// The extra regions of `variant` (default: the current tournament's), so
+    /// the frontend can shade diagonals or windows.
+    async fn variant_regions(&self, variant: Option<SudokuVariant>) -> Vec<Vec<CellPosition>> {
+        let variant = variant.unwrap_or_else(|| {
+            self.state.active_tournament.get()
+                .as_ref().map(|t| t.variant).unwrap_or_default()
+        });
+        sudoku::extra_regions(variant)
+            .into_iter()
+            .map(|region| {
+                region.into_iter()
+                    .map(|(r, c)| CellPosition { row: r as u8, col: c as u8 })
+                    .collect()
+            })
+            .collect()
+    }
+
     async fn is_tournament_active(&self) -> bool {
         self.state.active_tournament.get()
             .as_ref().map(|t| t.active).unwrap_or(false)
@@ -94,7 +267,138 @@ impl QueryRoot {
 
     async fn player_game_state(&self, wallet: String) -> Option<PlayerGameState> {
         let owner = parse_account_owner(&wallet)?;
-        self.state.player_games.get(&owner).await.unwrap_or(None)
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?;
+        Some(game.visible(self.state.get_active_tournament()))
+    }
+
+    /// When each of the player's penalties accrued (micros), oldest first.
+    /// Empty in blind mode until the board is done.
+    async fn penalty_timeline(&self, wallet: String) -> Vec<u64> {
+        let Some(owner) = parse_account_owner(&wallet) else {
+            return Vec::new();
+        };
+        let blind = self.state.get_active_tournament().is_some_and(|t| t.blind_mode);
+        if blind {
+            let game = self.state.player_games.get(&owner).await.unwrap_or(None);
+            if !game.is_some_and(|game| game.completed) {
+                return Vec::new();
+            }
+        }
+        self.state.penalty_events.get(&owner).await
+            .unwrap_or(None).unwrap_or_default()
+    }
+
+    /// Safe view of another player's live board for spectators and overlays.
+    /// Intentionally narrower than `playerGameState`.
+    async fn spectate(&self, wallet: String) -> Option<SpectatorView> {
+        let owner = parse_account_owner(&wallet)?;
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?;
+        Some(game.visible(self.state.get_active_tournament()).spectator_view())
+    }
+
+    /// How many of the player's own (non-given) entries currently break no
+    /// rule against the rest of their board. This is a consistency check
+    /// only: it never compares against the solution, so a count equal to
+    /// the filled cells doesn't reveal whether those values are the answer.
+    async fn filled_correct_count(&self, wallet: String) -> u32 {
+        match self.solve_progress(wallet).await {
+            Some(progress) => progress.filled - progress.conflicting,
+            None => 0,
+        }
+    }
+
+    /// Filled, remaining, and rule-breaking counts for the player's own
+    /// cells in one round trip. Checked against the board's rules only,
+    /// never the solution.
+    async fn solve_progress(&self, wallet: String) -> Option<SolveProgress> {
+        let owner = parse_account_owner(&wallet)?;
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?;
+        let (variant, size) = self.state.current_puzzle.get()
+            .as_ref().map(|b| (b.variant, b.size)).unwrap_or_default();
+        let mut progress = game.solve_progress(variant, size);
+        // Blind mode gives no error-checking until the board is done.
+        let blind = self.state.active_tournament.get().as_ref().is_some_and(|t| t.blind_mode);
+        if blind && !game.completed {
+            progress.conflicting = 0;
+        }
+        Some(progress)
+    }
+
+    /// Whether the player's board has an empty cell with no legal value
+    /// left, i.e. an earlier entry must be cleared before it can be solved.
+    /// Checked against the rules only. Always false in blind mode until the
+    /// board is done.
+    async fn is_stuck(&self, wallet: String) -> bool {
+        let Some(owner) = parse_account_owner(&wallet) else {
+            return false;
+        };
+        let Some(game) = self.state.player_games.get(&owner).await.unwrap_or(None) else {
+            return false;
+        };
+        let (variant, size) = self.state.current_puzzle.get()
+            .as_ref().map(|b| (b.variant, b.size)).unwrap_or_default();
+        // Blind mode gives no error-checking until the board is done.
+        let blind = self.state.active_tournament.get().as_ref().is_some_and(|t| t.blind_mode);
+        if blind && !game.completed {
+            return false;
+        }
+        game.is_stuck(variant, size)
+    }
+
+    /// How many of each value 1-9 are on the player's board (index 0 is 1),
+    /// for greying out digits that are used up. Reads only their board.
+    async fn digit_counts(&self, wallet: String) -> Option<[u8; 9]> {
+        let owner = parse_account_owner(&wallet)?;
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?;
+        Some(game.digit_counts())
+    }
+
+    /// Rows, columns, and boxes the player has filled with no repeats, so
+    /// the UI can celebrate a finished box. Never consults the solution.
+    async fn region_progress(&self, wallet: String) -> Option<RegionProgress> {
+        let owner = parse_account_owner(&wallet)?;
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?;
+        let size = self.state.current_puzzle.get().as_ref().map(|b| b.size).unwrap_or_default();
+        Some(game.region_progress(size))
+    }
+
+    /// Non-given cells the player still has to fill, or `None` if they have
+    /// no game yet.
+    async fn cells_remaining(&self, wallet: String) -> Option<u32> {
+        let owner = parse_account_owner(&wallet)?;
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?;
+        Some(game.cells_remaining())
+    }
+
+    /// The player's board as it stood after their first `n` moves (placements
+    /// and clears). `n` beyond the recorded history returns the final board.
+    async fn board_at_move(&self, wallet: String, n: u32) -> Option<Vec<Vec<u8>>> {
+        let owner = parse_account_owner(&wallet)?;
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?;
+        Some(game.board_at_move(n as usize))
+    }
+
+    /// The tournaments `wallet` played, newest first, with its final score
+    /// and rank in each — live ones for the current tournament (Hub).
+    async fn player_history(&self, wallet: String, limit: Option<u32>) -> Vec<TournamentResult> {
+        let Some(owner) = parse_account_owner(&wallet) else {
+            return Vec::new();
+        };
+        let limit = limit.unwrap_or(20).min(MAX_PLAYER_HISTORY as u32) as usize;
+        let ids = self.state.player_tournaments.get(&owner).await
+            .unwrap_or(None).unwrap_or_default();
+        let current = self.state.active_tournament.get().as_ref().map(|t| t.id);
+
+        let mut history = Vec::new();
+        for &id in ids.iter().rev().take(limit) {
+            let rankings = match self.state.final_rankings_by_id.get(&id).await.unwrap_or(None) {
+                Some(rankings) => rankings,
+                None if current == Some(id) => self.state.get_sorted_leaderboard(u32::MAX).await,
+                None => continue,
+            };
+            history.extend(TournamentResult::from_rankings(id, &rankings, &owner));
+        }
+        history
     }
 
     async fn leaderboard(&self, limit: Option<u32>) -> Vec<LeaderboardEntry> {
@@ -102,14 +406,222 @@ impl QueryRoot {
         self.state.get_sorted_leaderboard(limit).await
     }
 
+    /// Percentage (0-100) of leaderboard entries scoring strictly below
+    /// `score` (Hub). 0 on an empty leaderboard.
+    async fn score_percentile(&self, score: u64) -> f64 {
+        let mut scores = Vec::new();
+        self.state.leaderboard
+            .for_each_index_value(|_wallet, entry| {
+                scores.push(entry.score);
+                Ok(())
+            })
+            .await
+            .unwrap_or(());
+        score_percentile(scores, score)
+    }
+
+    /// `scorePercentile` for the player's own leaderboard score (Hub).
+    /// `None` if they have no entry.
+    async fn player_percentile(&self, wallet: String) -> Option<f64> {
+        let owner = parse_account_owner(&wallet)?;
+        let entry = self.state.leaderboard.get(&owner).await.unwrap_or(None)?;
+        Some(self.score_percentile(entry.score).await)
+    }
+
+    /// Consecutive tournaments the player has completed, including the
+    /// active one once finished (Hub). 0 if their last tournament went
+    /// unfinished.
+    async fn streak(&self, wallet: String) -> u32 {
+        let Some(owner) = parse_account_owner(&wallet) else {
+            return 0;
+        };
+        self.state.completion_streaks.get(&owner).await.unwrap_or(None).unwrap_or(0)
+    }
+
+    /// Every admin score override with its reason, oldest first (Hub).
+    async fn score_adjustments(&self) -> Vec<ScoreAdjustment> {
+        let count = self.state.score_adjustments.count();
+        self.state.score_adjustments.read(0..count).await.unwrap_or_default()
+    }
+
+    /// Finishers ranked purely by solving speed (first move → completion),
+    /// regardless of penalties or score (Hub).
+    async fn fastest_finishers(&self, limit: Option<u32>) -> Vec<LeaderboardEntry> {
+        let limit = limit.unwrap_or(10).min(200) as usize;
+        let entries = self.state.get_sorted_leaderboard(u32::MAX).await;
+        let mut finishers = fastest_finishers(&entries);
+        finishers.truncate(limit);
+        finishers
+    }
+
+    /// Groups of finishers who played the exact same moves in the same
+    /// order (Hub). Everyone in a group is also flagged `isSuspicious`.
+    async fn duplicate_sequences(&self) -> Vec<DuplicateSequence> {
+        let entries = self.state.get_sorted_leaderboard(u32::MAX).await;
+        duplicate_sequences(&entries)
+    }
+
+    /// Players whose chain sent a `Heartbeat` in the last `window_secs`
+    /// (Hub). Counts live chains, playing or not.
+    async fn online_players(&self, window_secs: u32) -> u32 {
+        let now = self.runtime.system_time().micros();
+        let mut last_seen = Vec::new();
+        self.state.last_seen
+            .for_each_index_value(|_wallet, seen| {
+                last_seen.push(*seen);
+                Ok(())
+            })
+            .await
+            .unwrap_or(());
+        count_online(last_seen, now, window_secs as u64 * 1_000_000)
+    }
+
+    /// Players currently solving (Hub): unfinished, with a move in the last
+    /// `window_secs`. A lobby "playing now" count.
+    async fn active_now(&self, window_secs: u32) -> u32 {
+        let now = self.runtime.system_time().micros();
+        let entries = self.state.get_sorted_leaderboard(u32::MAX).await;
+        count_active(&entries, now, window_secs as u64 * 1_000_000)
+    }
+
+    /// Every player's cells filled/remaining and penalties, closest to
+    /// finishing first (Hub). Built from the counts each `SyncCellPlacement`
+    /// carries, so it lags the player chains by at most one message.
+    async fn all_progress(&self) -> Vec<ProgressEntry> {
+        let entries = self.state.get_sorted_leaderboard(u32::MAX).await;
+        ProgressEntry::from_entries(&entries)
+    }
+
+    /// Share of the player's placements that were valid (Hub), or `None`
+    /// if they haven't moved yet.
+    async fn accuracy(&self, wallet: String) -> Option<f64> {
+        let owner = parse_account_owner(&wallet)?;
+        let entry = self.state.leaderboard.get(&owner).await.unwrap_or(None)?;
+        entry.placement_accuracy()
+    }
+
+    /// Teams ranked by the sum of their best members' scores (Hub).
+    async fn team_leaderboard(&self, limit: Option<u32>) -> Vec<TeamScore> {
+        let limit = limit.unwrap_or(20).min(100) as usize;
+        let mut teams = self.state.team_leaderboard().await;
+        teams.truncate(limit);
+        teams
+    }
+
+    /// The running season, or the last one if it has ended (Hub).
+    async fn current_season(&self) -> Option<Season> {
+        self.state.current_season.get().clone()
+    }
+
+    /// Players ranked by points summed over the current season's
+    /// tournaments (Hub).
+    async fn season_leaderboard(&self, limit: Option<u32>) -> Vec<SeasonStanding> {
+        let limit = limit.unwrap_or(20).min(100) as usize;
+        let mut standings = self.state.season_leaderboard().await;
+        standings.truncate(limit);
+        standings
+    }
+
     async fn cached_leaderboard(&self) -> Option<CachedLeaderboard> {
         self.state.cached_leaderboard.get().clone()
     }
 
+    /// When the player would finish at their current pace (see
+    /// `PlayerGameState::estimated_finish_micros`). `None` without a game or
+    /// before they've filled enough cells to judge.
+    async fn estimated_finish_micros(&self, wallet: String) -> Option<u64> {
+        let owner = parse_account_owner(&wallet)?;
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?;
+        let now = self.runtime.system_time().micros();
+        game.estimated_finish_micros(now)
+    }
+
+    /// The player's live score estimate as of now — the same figure the Hub
+    /// keeps for in-progress players. `None` without an unfinished game.
+    async fn estimated_score(&self, wallet: String) -> Option<u64> {
+        let owner = parse_account_owner(&wallet)?;
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?;
+        let tournament = self.state.get_active_tournament()?;
+        let now = self.runtime.system_time().micros();
+        game.estimated_score(tournament, now)
+    }
+
+    /// Where the player would rank if they stopped now (player chain).
+    ///
+    /// An approximation: the player's local game is scored as of now with
+    /// the tournament's scoring function and placed into the
+    /// `cachedLeaderboard`, which is only as fresh as the last
+    /// `RequestLeaderboard`. Nothing is written. `None` without a game.
+    async fn projected_rank(&self, wallet: String) -> Option<u32> {
+        let owner = parse_account_owner(&wallet)?;
+        let tournament = self.state.active_tournament.get().clone()?;
+        let game = self.state.player_games.get(&owner).await.unwrap_or(None)?
+            .visible(Some(&tournament));
+        let now = self.runtime.system_time().micros();
+
+        let score = game.estimated_score(&tournament, now).unwrap_or(game.score);
+        let candidate = LeaderboardEntry {
+            wallet: owner,
+            discord_username: String::new(),
+            score,
+            completion_time_micros: game.completion_time_micros.unwrap_or(0),
+            penalty_count: game.penalty_count,
+            move_count: game.move_count,
+            completed: game.completed && !game.failed,
+            first_move_time_micros: game.start_time_micros,
+            last_move_time_micros: game.last_move_time_micros,
+            is_suspicious: false,
+            cells_filled: 0,
+            cells_remaining: game.cells_remaining(),
+            move_sequence_hash: 0,
+            failed: game.failed,
+        };
+
+        let entries = self.state.cached_leaderboard.get()
+            .as_ref().map(|c| c.entries.clone()).unwrap_or_default();
+        Some(projected_rank(&entries, &candidate))
+    }
+
+    /// Whether the last `RequestLeaderboard` is pending, answered, or timed out.
+    async fn leaderboard_request_status(&self) -> LeaderboardRequestStatus {
+        let requested_at = *self.state.leaderboard_request_pending.get();
+        let fetched_at = self.state.cached_leaderboard.get()
+            .as_ref().map(|c| c.fetched_at_micros);
+        let now = self.runtime.system_time().micros();
+        LeaderboardRequestStatus::compute(requested_at, fetched_at, now)
+    }
+
+    async fn is_subscribed(&self) -> bool {
+        *self.state.subscribed.get()
+    }
+
     async fn tournament_stats(&self) -> TournamentStats {
         self.state.compute_tournament_stats().await
     }
 
+    /// Post-event digest: rankings, stats, and flagged players (Hub).
+    /// Tournaments that ended before final rankings were kept return null.
+    async fn tournament_report(&self, tournament_id: u64) -> Option<TournamentReport> {
+        if let Some(rankings) = self.state.final_rankings_by_id.get(&tournament_id).await
+            .unwrap_or(None)
+        {
+            let tournament = self.state.tournaments_by_id.get(&tournament_id).await
+                .unwrap_or(None)?;
+            return Some(TournamentReport::build(tournament, rankings));
+        }
+        let tournament = self.state.active_tournament.get().clone()
+            .filter(|t| t.id == tournament_id)?;
+        let mut entries = Vec::new();
+        self.state.leaderboard
+            .for_each_index_value(|_wallet, entry| {
+                entries.push(entry.into_owned());
+                Ok(())
+            })
+            .await
+            .unwrap_or(());
+        Some(TournamentReport::build(tournament, entries))
+    }
+
     async fn past_tournaments(&self, limit: Option<u32>) -> Vec<Tournament> {
         let limit = limit.unwrap_or(10).min(100) as usize;
         let count = self.state.past_tournaments.count();
@@ -124,12 +636,56 @@ impl QueryRoot {
         tournaments
     }
 
-    async fn verify_game(&self, seed: u64, moves: Vec<MoveInput>) -> VerifyResult {
+    /// A tournament by id: the current one, or an ended one however long
+    /// ago (Hub). For permalinks.
+    async fn tournament_by_id(&self, id: u64) -> Option<Tournament> {
+        if let Some(current) = self.state.active_tournament.get().as_ref().filter(|t| t.id == id) {
+            return Some(current.clone());
+        }
+        self.state.tournaments_by_id.get(&id).await.unwrap_or(None)
+    }
+
+    async fn verify_game(
+        &self,
+        seed: u64,
+        moves: Vec<MoveInput>,
+        variant: Option<SudokuVariant>,
+    ) -> VerifyResult {
         let move_tuples: Vec<(u8, u8, u8)> = moves
             .into_iter()
             .map(|m| (m.row, m.col, m.value))
             .collect();
-        sudoku::verify_game(seed, &move_tuples)
+        sudoku::verify_game(seed, variant.unwrap_or_default(), &move_tuples)
+    }
+
+    /// Replay a game with each move's playing time (from `moveTimes`) and
+    /// score it exactly as the Hub did. `scoringMode` and `freeMistakes`
+    /// should be the tournament's own.
+    async fn verify_game_timed(
+        &self,
+        seed: u64,
+        moves: Vec<TimedMoveInput>,
+        variant: Option<SudokuVariant>,
+        scoring_mode: Option<ScoringMode>,
+        free_mistakes: Option<u32>,
+    ) -> VerifyResult {
+        let move_tuples: Vec<(u8, u8, u8, u64)> = moves
+            .into_iter()
+            .map(|m| (m.row, m.col, m.value, m.elapsed_micros))
+            .collect();
+        sudoku::verify_game_timed(
+            seed,
+            variant.unwrap_or_default(),
+            scoring_mode.unwrap_or_default(),
+            free_mistakes.unwrap_or(0),
+            &move_tuples,
+        )
+    }
+
+    /// Stream events this chain skipped because they came from a newer
+    /// contract version. Non-zero means it's time to upgrade.
+    async fn skipped_events(&self) -> u64 {
+        *self.state.skipped_events.get()
     }
 
     async fn recent_events(&self, limit: Option<u32>) -> Vec<String> {
@@ -146,6 +702,88 @@ impl QueryRoot {
         events
     }
 
+    /// Every leaderboard update the Hub logged, oldest first, including
+    /// intermediate states the current leaderboard has overwritten. Pages
+    /// of at most 200.
+    async fn leaderboard_log(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<LeaderboardEntry> {
+        let count = self.state.leaderboard_log.count();
+        let range = log_page(count, offset.unwrap_or(0), limit.unwrap_or(50));
+        self.state.leaderboard_log.read(range).await.unwrap_or_default()
+    }
+
+    /// Recent Hub activity, newest first: logged events interleaved with
+    /// board completions from `leaderboard_log`. At most 100 items.
+    async fn activity_feed(&self, limit: Option<u32>) -> Vec<ActivityItem> {
+        let limit = limit.unwrap_or(20).min(100) as usize;
+        let mut items = Vec::new();
+
+        // The newest `limit` of the merged feed lie within the tail of each log.
+        let count = self.state.event_log.count();
+        for i in count.saturating_sub(limit)..count {
+            if let Ok(Some(event)) = self.state.event_log.get(i).await {
+                items.extend(ActivityItem::from_event(&event));
+            }
+        }
+        let count = self.state.leaderboard_log.count();
+        for i in count.saturating_sub(limit)..count {
+            if let Ok(Some(entry)) = self.state.leaderboard_log.get(i).await {
+                items.push(ActivityItem::from_completion(&entry));
+            }
+        }
+
+        items.sort_by(|a, b| b.at_micros.cmp(&a.at_micros));
+        items.truncate(limit);
+        items
+    }
+
+    /// Every leaderboard entry in rank order, for archiving results (Hub).
+    /// Unlike `leaderboard` there is no cap, so this reads the whole map —
+    /// potentially expensive. Only answered once the tournament has ended.
+    async fn export_leaderboard(&self) -> Option<Vec<LeaderboardEntry>> {
+        if self.state.get_active_tournament().is_some() {
+            return None;
+        }
+        Some(self.state.get_sorted_leaderboard(u32::MAX).await)
+    }
+
+    /// The whole event log, oldest first, each event as its typed JSON
+    /// (Hub). Reads every entry, so it's meant for archival, not polling.
+    async fn export_event_log(&self) -> Vec<Json<ArenaEvent>> {
+        let count = self.state.event_log.count();
+        self.state.event_log.read(0..count).await
+            .unwrap_or_default()
+            .into_iter()
+            .map(Json)
+            .collect()
+    }
+
+    /// Stream events this player chain received at or after index `since`,
+    /// oldest first, for catching up after being offline. Only the newest
+    /// `MAX_NOTIFICATIONS` are kept, and leaderboard snapshots aren't kept
+    /// at all (see `cachedLeaderboard`).
+    async fn notifications(&self, since: Option<u64>) -> NotificationPage {
+        let total = *self.state.notification_count.get();
+        let held = self.state.notifications.count();
+        let range = inbox_range(total, held, since.unwrap_or(0));
+        let events: Vec<Json<ArenaEvent>> = self.state.notifications.read_front(range.end).await
+            .unwrap_or_default()
+            .into_iter()
+            .skip(range.start)
+            .map(Json)
+            .collect();
+        let first_index = total - held as u64 + range.start as u64;
+        NotificationPage { first_index, next_index: total, events }
+    }
+
+    /// Text of the most recent admin announcement this chain has seen.
+    async fn latest_announcement(&self) -> Option<String> {
+        self.state.latest_announcement.get().clone()
+    }
+
     async fn event_count(&self) -> u64 {
         *self.state.event_counter.get()
     }
@@ -6,14 +6,25 @@
 //! Uses Linera Views (persistent key-value storage) as the sole data layer.
 //! The same state struct is used on both Hub chains and player chains,
 //! with different fields populated depending on the chain's role.
+//!
+//! Views are keyed by their position in `ArenaState`, so the original views
+//! keep their order and every later view is appended after `past_tournaments`
+//! (pinned by a test in `migration`). Changes to stored value types bump
+//! `STATE_SCHEMA_VERSION` and add a step to `ArenaState::migrate`, which
+//! the contract runs on every load.
 
 use fridaychain_arena::{
-    ArenaEvent, CachedLeaderboard, LeaderboardEntry, PlayerGameState, PlayerInfo, SudokuBoard,
-    Tournament, TournamentStats,
+    add_participation, keeps_streak, may_claim_username, normalize_username, rank_order, ArenaEvent,
+    CachedLeaderboard, LeaderboardEntry, PlayerGameState, PlayerInfo, ScoreAdjustment, Season,
+    SeasonStanding, SudokuBoard, TeamScore, Tournament, TournamentStats, WeeklySchedule,
+    MAX_NOTIFICATIONS, STATE_SCHEMA_VERSION,
 };
 use linera_sdk::{
     linera_base_types::{AccountOwner, ChainId},
-    views::{linera_views, LogView, MapView, RegisterView, RootView, ViewStorageContext},
+    views::{
+        linera_views, LogView, MapView, QueueView, RegisterView, RootView, View,
+        ViewStorageContext,
+    },
 };
 
 /// The root state view for the FridayChain Arena application.
@@ -54,7 +65,8 @@ pub struct ArenaState {
 
     /// The current Sudoku puzzle board (puzzle + solution).
     /// Generated deterministically from the tournament seed.
-    /// IMPORTANT: The solution is NEVER exposed through GraphQL queries.
+    /// IMPORTANT: The solution is NEVER exposed through GraphQL while the
+    /// tournament is active (`revealSolution` only answers after it ends).
     pub current_puzzle: RegisterView<Option<SudokuBoard>>,
 
     // ── Per-Player Game State ────────────────────────────────────────────
@@ -88,6 +100,172 @@ pub struct ArenaState {
 
     /// Log of all past tournaments.
     pub past_tournaments: LogView<Tournament>,
+
+    // ── Usernames ────────────────────────────────────────────────────────
+
+    /// Reverse index of normalized Discord usernames to their owner (Hub only).
+    ///
+    /// Registration is optimistic: the player chain stores the name locally and
+    /// syncs it to the Hub. The Hub is authoritative — whichever `SyncPlayer`
+    /// it executes first claims the name, and a later conflicting claim is
+    /// answered with `Message::UsernameRejected` so the player chain rolls back.
+    pub usernames: MapView<String, AccountOwner>,
+
+    /// The most recent username the Hub rejected for the local player
+    /// (player chains only). Cleared on the next successful register/update.
+    pub username_rejection: RegisterView<Option<String>>,
+
+    // ── Lobby & Scheduling ───────────────────────────────────────────────
+
+    /// Announced start time of the next tournament (lobby phase). Set by
+    /// `ScheduleTournament` on the Hub, mirrored to subscribed player chains,
+    /// and cleared when the tournament starts.
+    pub scheduled_start_micros: RegisterView<Option<u64>>,
+
+    /// Wallets that checked in for the scheduled tournament, with the
+    /// check-in time (Hub only). Cleared when the tournament starts.
+    pub checked_in: MapView<AccountOwner, u64>,
+
+    /// Wallets allowed into restricted tournaments. Maintained by
+    /// `AllowPlayer` on the Hub and mirrored to player chains via the stream.
+    pub allowed: MapView<AccountOwner, ()>,
+
+    /// Recurring weekly tournament slot (Hub only), if enabled.
+    pub weekly_schedule: RegisterView<Option<WeeklySchedule>>,
+
+    /// Start of the last weekly slot that auto-started a tournament, so a
+    /// slot is never used twice (Hub only).
+    pub last_auto_slot_micros: RegisterView<Option<u64>>,
+
+    /// Every seed a tournament has started with → that tournament's ID
+    /// (Hub only), so a puzzle is never accidentally repeated.
+    pub used_seeds: MapView<u64, u64>,
+
+    // ── Penalties (Player chains) ────────────────────────────────────────
+
+    /// Timestamps (micros) of each invalid placement in the active tournament,
+    /// keyed by wallet. Only the first 100 are kept (player chains).
+    pub penalty_events: MapView<AccountOwner, Vec<u64>>,
+
+    // ── Leaderboard Sync ─────────────────────────────────────────────────
+
+    /// Timestamp (micros) of an outstanding `RequestLeaderboard`, cleared
+    /// when the Hub's `LeaderboardResponse` arrives.
+    pub leaderboard_request_pending: RegisterView<Option<u64>>,
+
+    /// Hub: time (micros) of the last `LeaderboardUpdated` emission, used to
+    /// throttle snapshots triggered by in-progress cell placements.
+    pub last_broadcast_micros: RegisterView<u64>,
+
+    /// Whether this chain is subscribed to the Hub's tournament stream.
+    /// Makes `SubscribeToHub` / `UnsubscribeFromHub` idempotent.
+    pub subscribed: RegisterView<bool>,
+
+    // ── Schema ───────────────────────────────────────────────────────────
+
+    /// `STATE_SCHEMA_VERSION` the stored data was last migrated to. Chains
+    /// created before versioning read 0.
+    pub schema_version: RegisterView<u32>,
+
+    // ── Audit (Hub chain only) ───────────────────────────────────────────
+
+    /// Every admin score override (`SetPlayerScore`), oldest first.
+    pub score_adjustments: LogView<ScoreAdjustment>,
+
+    // ── Historical Data, indexed (Hub chain only) ────────────────────────
+
+    /// Ended tournaments by id; the same entries as `past_tournaments`.
+    pub tournaments_by_id: MapView<u64, Tournament>,
+
+    // ── Notifications (Player chains only) ───────────────────────────────
+
+    /// The newest `MAX_NOTIFICATIONS` stream events received, oldest first,
+    /// so a client that was offline can catch up.
+    pub notifications: QueueView<ArenaEvent>,
+
+    /// Stream events ever added to `notifications`, including dropped ones.
+    pub notification_count: RegisterView<u64>,
+
+    // ── Sync Authentication (Hub chain only) ─────────────────────────────
+
+    /// The chain each wallet's first accepted sync message came from. Later
+    /// syncs about the wallet must come from the same chain. The binding is
+    /// permanent; only `reset()` (via `ResetArena`) clears it.
+    pub home_chains: MapView<AccountOwner, ChainId>,
+
+    // ── Presence (Hub chain only) ────────────────────────────────────────
+
+    /// Time (µs) of each wallet's latest `Heartbeat`.
+    pub last_seen: MapView<AccountOwner, u64>,
+
+    // ── Box Bonus (Hub chain only) ───────────────────────────────────────
+
+    /// The first wallet to correctly fill each 3×3 box (0-8) in the active
+    /// tournament. Cleared when a tournament starts.
+    pub box_first_completers: MapView<u8, AccountOwner>,
+
+    /// Each player's cells whose latest synced placement matches the
+    /// solution, as a bitset (see `SudokuBoard::mark_correct`), for deriving
+    /// box completions. Cleared when a tournament starts.
+    pub correct_cells: MapView<AccountOwner, u128>,
+
+    // ── Player History (Hub chain only) ──────────────────────────────────
+
+    /// Tournaments each wallet played, oldest first, at most
+    /// `MAX_PLAYER_HISTORY`.
+    pub player_tournaments: MapView<AccountOwner, Vec<u64>>,
+
+    /// Every entry of each ended tournament's final leaderboard, in rank
+    /// order, by tournament id.
+    pub final_rankings_by_id: MapView<u64, Vec<LeaderboardEntry>>,
+
+    // ── Stream Compatibility (Player chains only) ────────────────────────
+
+    /// Stream events skipped because this build couldn't decode them.
+    pub skipped_events: RegisterView<u64>,
+
+    // ── Seasons (Hub chain only) ─────────────────────────────────────────
+
+    /// The running season, or the last one after `EndSeason`.
+    pub current_season: RegisterView<Option<Season>>,
+
+    /// Points each wallet has earned in the current season.
+    pub season_points: MapView<AccountOwner, u64>,
+
+    // ── Daily Challenge (Player chains) ──────────────────────────────────
+
+    /// Best daily-puzzle time (µs) by wallet and `YYYYMMDD` date.
+    pub daily_results: MapView<(AccountOwner, u32), u64>,
+
+    // ── Rewards (Hub chain only) ─────────────────────────────────────────
+
+    /// Prize amount claimed, by tournament id and wallet.
+    pub reward_claims: MapView<(u64, AccountOwner), u64>,
+
+    // ── Announcements ────────────────────────────────────────────────────
+
+    /// Text of the most recent `Announcement` (Hub: sent; player chains:
+    /// received on the stream).
+    pub latest_announcement: RegisterView<Option<String>>,
+
+    // ── Move sync (Player chains) ────────────────────────────────────────
+
+    /// Placements per `SyncCellBatch` (see `SetSyncBatchSize`); 0 means 1.
+    pub sync_batch_size: RegisterView<u32>,
+
+    // ── Streaks (Hub chain only) ─────────────────────────────────────────
+
+    /// Consecutive tournaments each player has completed, counting the
+    /// active one once they finish it. Players without a streak are absent.
+    pub completion_streaks: MapView<AccountOwner, u32>,
+
+    // ── Completion Tracking (Hub chain only) ─────────────────────────────
+
+    /// The latest tournament each wallet's completion was counted for (see
+    /// `is_first_completion`). Kept through `ResetPlayerGame`, so a player
+    /// who finishes the same tournament again earns no season points twice
+    /// and doesn't extend their streak twice.
+    pub completion_counted_for: MapView<AccountOwner, u64>,
 }
 
 impl ArenaState {
@@ -98,6 +276,220 @@ impl ArenaState {
         false // Will be checked by the contract using runtime.chain_id()
     }
 
+    /// Upgrade state written by an older contract to `STATE_SCHEMA_VERSION`.
+    /// A no-op once current, so it's safe to run on every load.
+    pub async fn migrate(&mut self) {
+        let version = *self.schema_version.get();
+        if version >= STATE_SCHEMA_VERSION {
+            return;
+        }
+
+        if version < 5 {
+            // v0 → v1 added the pace fields to leaderboard entries, v1 → v2
+            // the cell counts, v2 → v3 the move sequence hash, v4 → v5 the
+            // failed flag. Map values are whole blobs, so old ones decode
+            // with those fields zeroed; rewrite them in the current layout.
+            // Entries nested in a larger value don't, and are handled below.
+            let mut entries = Vec::new();
+            self.leaderboard
+                .for_each_index_value(|wallet, entry| {
+                    entries.push((wallet, entry.into_owned()));
+                    Ok(())
+                })
+                .await
+                .expect("Failed to read leaderboard for migration");
+            for (wallet, entry) in entries {
+                self.leaderboard.insert(&wallet, entry)
+                    .expect("Failed to migrate leaderboard entry");
+            }
+        }
+
+        if version < 4 {
+            // v3 → v4 added the `tournaments_by_id` index.
+            let count = self.past_tournaments.count();
+            let tournaments = self.past_tournaments.read(0..count).await
+                .expect("Failed to read past tournaments for migration");
+            for tournament in tournaments {
+                self.tournaments_by_id.insert(&tournament.id, tournament)
+                    .expect("Failed to index past tournament");
+            }
+        }
+
+        if version < 6 {
+            // The cache may hold entries in any layout before v5, which
+            // decode to garbage inside a vector. It is only a copy of the
+            // Hub's leaderboard, so drop it; the next update refills it.
+            // `final_rankings_by_id` arrived after v5 and has only ever held
+            // the current layout, so it needs no rewrite.
+            self.cached_leaderboard.set(None);
+        }
+
+        if version < 7 {
+            // Tournaments gained their rule fields, profiles the team and
+            // preferences, puzzles the variant and size. Old values decode
+            // with those defaulted; rewrite them in the current layout so
+            // the tail decoding is only ever needed once.
+            let mut profiles = Vec::new();
+            self.players
+                .for_each_index_value(|wallet, info| {
+                    profiles.push((wallet, info.into_owned()));
+                    Ok(())
+                })
+                .await
+                .expect("Failed to read players for migration");
+            for (wallet, info) in profiles {
+                self.players.insert(&wallet, info)
+                    .expect("Failed to migrate player profile");
+            }
+
+            let mut indexed = Vec::new();
+            self.tournaments_by_id
+                .for_each_index_value(|id, tournament| {
+                    indexed.push((id, tournament.into_owned()));
+                    Ok(())
+                })
+                .await
+                .expect("Failed to read tournament index for migration");
+            for (id, tournament) in indexed {
+                self.tournaments_by_id.insert(&id, tournament)
+                    .expect("Failed to migrate indexed tournament");
+            }
+
+            let count = self.past_tournaments.count();
+            let past = self.past_tournaments.read(0..count).await
+                .expect("Failed to read past tournaments for migration");
+            self.past_tournaments.clear();
+            for tournament in past {
+                self.past_tournaments.push(tournament);
+            }
+
+            let active = self.active_tournament.get().clone();
+            self.active_tournament.set(active);
+            let puzzle = self.current_puzzle.get().clone();
+            self.current_puzzle.set(puzzle);
+        }
+
+        if version < 8 {
+            // Games were stored with nested boards and without the fields
+            // after `score` (`last_move_time_micros` sits before it). Both
+            // layouts decode; rewrite them compactly.
+            let mut games = Vec::new();
+            self.player_games
+                .for_each_index_value(|wallet, game| {
+                    games.push((wallet, game.into_owned()));
+                    Ok(())
+                })
+                .await
+                .expect("Failed to read player games for migration");
+            for (wallet, game) in games {
+                self.player_games.insert(&wallet, game)
+                    .expect("Failed to migrate player game");
+            }
+        }
+
+        self.schema_version.set(STATE_SCHEMA_VERSION);
+    }
+
+    /// Completed leaderboard entries, other than `wallet`'s, whose move
+    /// sequence hash is `hash`.
+    pub async fn finishers_with_sequence(
+        &self,
+        hash: u64,
+        wallet: &AccountOwner,
+    ) -> Vec<LeaderboardEntry> {
+        let mut twins = Vec::new();
+        self.leaderboard
+            .for_each_index_value(|other, entry| {
+                if other != *wallet && entry.completed && entry.move_sequence_hash == hash {
+                    twins.push(entry.into_owned());
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or(());
+        twins
+    }
+
+    /// Add `tournament_id` to `wallet`'s history.
+    pub async fn record_participation(&mut self, wallet: &AccountOwner, tournament_id: u64) {
+        let mut ids = self.player_tournaments.get(wallet).await
+            .unwrap_or(None).unwrap_or_default();
+        if ids.last() == Some(&tournament_id) {
+            return;
+        }
+        add_participation(&mut ids, tournament_id);
+        self.player_tournaments.insert(wallet, ids)
+            .expect("Failed to record participation");
+    }
+
+    /// How many boxes `wallet` was first to fill in the active tournament.
+    pub async fn boxes_won(&self, wallet: &AccountOwner) -> u32 {
+        let mut won = 0;
+        self.box_first_completers
+            .for_each_index_value(|_box_index, first| {
+                if *first == *wallet {
+                    won += 1;
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or(());
+        won
+    }
+
+    /// Add a stream event to the notification inbox, dropping the oldest
+    /// beyond `MAX_NOTIFICATIONS`.
+    pub fn push_notification(&mut self, event: ArenaEvent) {
+        self.notifications.push_back(event);
+        if self.notifications.count() > MAX_NOTIFICATIONS {
+            self.notifications.delete_front();
+        }
+        let count = *self.notification_count.get() + 1;
+        self.notification_count.set(count);
+    }
+
+    /// Wipe everything a season accumulates (`ResetArena`): players,
+    /// tournaments, games, leaderboards, logs, and their counters. Keeps the
+    /// Hub/admin configuration, the weekly schedule, the stream subscription
+    /// and notification inbox, daily puzzle times, and `schema_version`.
+    pub fn reset(&mut self) {
+        self.players.clear();
+        self.player_count.set(0);
+        self.usernames.clear();
+        self.username_rejection.set(None);
+        self.active_tournament.set(None);
+        self.scheduled_start_micros.set(None);
+        self.checked_in.clear();
+        self.allowed.clear();
+        self.used_seeds.clear();
+        self.tournament_counter.set(0);
+        self.current_puzzle.set(None);
+        self.player_games.clear();
+        self.penalty_events.clear();
+        self.leaderboard.clear();
+        self.leaderboard_log.clear();
+        self.event_log.clear();
+        self.event_counter.set(0);
+        self.cached_leaderboard.set(None);
+        self.leaderboard_request_pending.set(None);
+        self.last_broadcast_micros.set(0);
+        self.past_tournaments.clear();
+        self.score_adjustments.clear();
+        self.tournaments_by_id.clear();
+        self.home_chains.clear();
+        self.last_seen.clear();
+        self.box_first_completers.clear();
+        self.correct_cells.clear();
+        self.player_tournaments.clear();
+        self.final_rankings_by_id.clear();
+        self.current_season.set(None);
+        self.season_points.clear();
+        self.reward_claims.clear();
+        self.latest_announcement.set(None);
+        self.completion_streaks.clear();
+        self.completion_counted_for.clear();
+    }
+
     /// Get the current tournament if it exists and is active.
     pub fn get_active_tournament(&self) -> Option<&Tournament> {
         self.active_tournament
@@ -106,6 +498,39 @@ impl ArenaState {
             .filter(|t| t.active)
     }
 
+    /// Whether `wallet` may play in `tournament`: always, unless the
+    /// tournament is restricted and the wallet isn't allowlisted.
+    pub async fn may_play(&self, tournament: &Tournament, wallet: &AccountOwner) -> bool {
+        !tournament.restricted || self.allowed.contains_key(wallet).await.unwrap_or(false)
+    }
+
+    /// Claim `username` for `wallet` in the reverse index, releasing `previous`.
+    /// Returns `false` (and changes nothing) if another wallet holds the name.
+    pub async fn claim_username(
+        &mut self,
+        wallet: AccountOwner,
+        previous: Option<&str>,
+        username: &str,
+    ) -> bool {
+        let key = normalize_username(username);
+        let holder = self.usernames.get(&key).await.unwrap_or(None);
+        if !may_claim_username(holder, &wallet) {
+            return false;
+        }
+
+        if let Some(previous) = previous {
+            let previous_key = normalize_username(previous);
+            if previous_key != key {
+                self.usernames.remove(&previous_key)
+                    .expect("Failed to release username");
+            }
+        }
+
+        self.usernames.insert(&key, wallet)
+            .expect("Failed to claim username");
+        true
+    }
+
     /// Compute tournament statistics from the leaderboard.
     pub async fn compute_tournament_stats(&self) -> TournamentStats {
         let tournament = match self.active_tournament.get() {
@@ -115,44 +540,85 @@ impl ArenaState {
             }
         };
 
-        let mut total_players = 0u32;
-        let mut total_completions = 0u32;
-        let mut total_score = 0u64;
-        let mut best_score = 0u64;
+        let mut entries = Vec::new();
+        self.leaderboard
+            .for_each_index_value(|_wallet, entry| {
+                entries.push(entry.into_owned());
+                Ok(())
+            })
+            .await
+            .unwrap_or(());
+
+        TournamentStats::from_entries(&tournament, &entries)
+    }
 
-        // Iterate over all leaderboard entries
+    /// Team standings over the current leaderboard. Players without a team
+    /// are left out.
+    pub async fn team_leaderboard(&self) -> Vec<TeamScore> {
+        let mut entries = Vec::new();
         self.leaderboard
             .for_each_index_value(|_wallet, entry| {
-                total_players += 1;
-                if entry.completed {
-                    total_completions += 1;
-                }
-                total_score += entry.score;
-                if entry.score > best_score {
-                    best_score = entry.score;
-                }
+                entries.push(entry.into_owned());
                 Ok(())
             })
             .await
             .unwrap_or(());
 
-        let average_score = if total_players > 0 {
-            total_score / total_players as u64
-        } else {
-            0
-        };
+        let mut members = Vec::new();
+        for entry in entries {
+            let team = self.players.get(&entry.wallet).await
+                .unwrap_or(None)
+                .and_then(|p| p.team);
+            if let Some(team) = team {
+                members.push((team, entry));
+            }
+        }
+        TeamScore::rank(&members)
+    }
 
-        TournamentStats {
-            tournament_id: tournament.id,
-            total_players,
-            total_completions,
-            average_score,
-            best_score,
-            is_active: tournament.active,
+    /// Season standings, most points first.
+    pub async fn season_leaderboard(&self) -> Vec<SeasonStanding> {
+        let mut points = Vec::new();
+        self.season_points
+            .for_each_index_value(|wallet, value| {
+                points.push((wallet, *value));
+                Ok(())
+            })
+            .await
+            .unwrap_or(());
+
+        let mut standings = Vec::with_capacity(points.len());
+        for (wallet, points) in points {
+            let discord_username = self.players.get(&wallet).await
+                .unwrap_or(None)
+                .map(|p| p.discord_username)
+                .unwrap_or_else(|| "Unknown".to_string());
+            standings.push(SeasonStanding { wallet, discord_username, points });
+        }
+        SeasonStanding::rank(standings)
+    }
+
+    /// Drop the completion streak of everyone who didn't finish the
+    /// tournament that ended with `rankings` (see `keeps_streak`).
+    pub async fn break_streaks(&mut self, rankings: &[LeaderboardEntry]) {
+        let mut broken = Vec::new();
+        self.completion_streaks
+            .for_each_index(|wallet| {
+                if !keeps_streak(rankings, &wallet) {
+                    broken.push(wallet);
+                }
+                Ok(())
+            })
+            .await
+            .unwrap_or(());
+
+        for wallet in broken {
+            self.completion_streaks.remove(&wallet)
+                .expect("Failed to reset completion streak");
         }
     }
 
-    /// Collect leaderboard entries sorted by score descending.
+    /// Collect leaderboard entries in rank order (see `LeaderboardEntry::rank_cmp`).
     pub async fn get_sorted_leaderboard(&self, limit: u32) -> Vec<LeaderboardEntry> {
         let mut entries = Vec::new();
 
@@ -164,24 +630,7 @@ impl ArenaState {
             .await
             .unwrap_or(());
 
-        // Sort: completed first (by score desc, then completion time asc),
-        // then in-progress (by estimated score desc, then fewer penalties, then more moves)
-        entries.sort_by(|a, b| {
-            match (a.completed, b.completed) {
-                (true, false) => std::cmp::Ordering::Less,
-                (false, true) => std::cmp::Ordering::Greater,
-                (true, true) => {
-                    b.score.cmp(&a.score)
-                        .then(a.completion_time_micros.cmp(&b.completion_time_micros))
-                }
-                (false, false) => {
-                    b.score.cmp(&a.score)
-                        .then(a.penalty_count.cmp(&b.penalty_count))
-                        .then(b.move_count.cmp(&a.move_count))
-                }
-            }
-        });
-
+        let mut entries = rank_order(entries);
         entries.truncate(limit as usize);
         entries
     }
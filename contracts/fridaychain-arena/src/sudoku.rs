@@ -6,14 +6,42 @@
 //! Uses `ChaCha8Rng` seeded with a `u64` so that the same seed always produces
 //! the exact same puzzle across every WASM runtime and every chain.
 
-use crate::SudokuBoard;
+use crate::{
+    compute_score, validate_board_arg, BoardSize, Difficulty, PuzzleValidation, ScoringMode,
+    SudokuBoard, SudokuVariant, Symmetry,
+};
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
+use sha2::{Digest, Sha256};
 
 /// Number of cells to remove from the complete grid to form the puzzle.
 /// ~46 removed → ~35 givens → challenging but solvable tournament difficulty.
 const CELLS_TO_REMOVE: usize = 46;
 
+/// Hyper-Sudoku removes fewer cells: every removal needs a uniqueness check
+/// against four extra regions, and the shaded windows are already demanding.
+const HYPER_CELLS_TO_REMOVE: usize = 42;
+
+/// Cells removed from a 6×6 grid (16 givens left).
+const SIX_CELLS_TO_REMOVE: usize = 20;
+
+/// Cells removed from a 4×4 grid (6 givens left).
+const FOUR_CELLS_TO_REMOVE: usize = 10;
+
+/// Fewest givens any row, column, or 3×3 box may be left with after removal.
+/// Mini boards only need 1 per unit.
+const MIN_GIVENS_PER_UNIT: usize = 2;
+
+/// Search steps `generate_puzzle` may take (cell fills tried while building
+/// the grid plus solver nodes visited by uniqueness checks) before giving
+/// up. Generation runs inside a contract operation, so a pathological seed
+/// must fail rather than exhaust the block's fuel. Hyper-Sudoku's uniqueness
+/// checks use up to a few hundred thousand steps on ordinary seeds.
+pub const MAX_GENERATION_STEPS: u64 = 1_000_000;
+
+/// Top-left corners of the four Hyper-Sudoku ("windoku") 3×3 windows.
+const HYPER_WINDOW_ORIGINS: [(usize, usize); 4] = [(1, 1), (1, 5), (5, 1), (5, 5)];
+
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
@@ -24,22 +52,292 @@ const CELLS_TO_REMOVE: usize = 46;
 /// 1. Build a complete valid 9×9 grid via backtracking with shuffled candidates.
 /// 2. Remove `CELLS_TO_REMOVE` cells symmetrically to create the puzzle.
 ///
-/// Returns `None` only if the internal generation fails (should never happen
-/// with a valid RNG).
-pub fn generate_puzzle(seed: u64) -> Option<SudokuBoard> {
+/// Removals that would leave any row, column, or box with fewer than
+/// `MIN_GIVENS_PER_UNIT` givens are skipped. For non-classic variants every
+/// removal is also checked with `count_solutions` and undone if it would
+/// admit a second solution; `Classic` skips that check to stay cheap.
+///
+/// Returns `None` if generation takes more than `MAX_GENERATION_STEPS`.
+pub fn generate_puzzle(seed: u64, variant: SudokuVariant) -> Option<SudokuBoard> {
+    generate_puzzle_within(seed, variant, MAX_GENERATION_STEPS)
+}
+
+/// `generate_puzzle` for any `size`. A mini board fills the top-left
+/// `size.side()` rows and columns of the 9×9 arrays and leaves the rest 0;
+/// only `Classic` is supported there (`None` otherwise). Mini puzzles are
+/// always checked for a unique solution, which is cheap at that size.
+pub fn generate_puzzle_sized(
+    seed: u64,
+    variant: SudokuVariant,
+    size: BoardSize,
+) -> Option<SudokuBoard> {
+    if !size.supports(variant) {
+        return None;
+    }
+    generate_sized_within(seed, variant, size, MAX_GENERATION_STEPS)
+}
+
+/// Seed of the daily puzzle for `date_ymd` (e.g. `20261016`). Hashed with a
+/// domain tag so it never lines up with a tournament seed by accident.
+pub fn daily_seed(date_ymd: u32) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(b"fridaychain-arena/daily");
+    hasher.update(date_ymd.to_le_bytes());
+    let digest = hasher.finalize();
+    let mut seed = [0u8; 8];
+    seed.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(seed)
+}
+
+/// The classic puzzle everyone gets for `date_ymd`, or `None` if generation
+/// fails. No tournament state is involved.
+pub fn daily_puzzle(date_ymd: u32) -> Option<SudokuBoard> {
+    generate_puzzle(daily_seed(date_ymd), SudokuVariant::Classic)
+}
+
+/// `generate_puzzle` with an explicit step budget. The budget never changes
+/// which puzzle a seed produces, only whether generation finishes.
+pub fn generate_puzzle_within(
+    seed: u64,
+    variant: SudokuVariant,
+    max_steps: u64,
+) -> Option<SudokuBoard> {
+    generate_sized_within(seed, variant, BoardSize::Nine, max_steps)
+}
+
+fn generate_sized_within(
+    seed: u64,
+    variant: SudokuVariant,
+    size: BoardSize,
+    max_steps: u64,
+) -> Option<SudokuBoard> {
     let mut rng = ChaCha8Rng::seed_from_u64(seed);
     let mut grid = [[0u8; 9]; 9];
+    let mut steps = Steps(max_steps);
 
-    if !fill_grid(&mut grid, &mut rng) {
+    if !fill_grid(&mut grid, &mut rng, variant, size, &mut steps) {
         return None;
     }
 
     let solution = grid;
     let mut puzzle = grid;
 
-    remove_cells(&mut puzzle, &mut rng);
+    remove_cells(&mut puzzle, &mut rng, variant, size, &mut steps);
+    if steps.exhausted() {
+        return None;
+    }
+
+    Some(SudokuBoard { puzzle, solution, variant, size })
+}
+
+/// How many cells the generator removes for `variant`.
+pub fn cells_to_remove(variant: SudokuVariant) -> usize {
+    match variant {
+        SudokuVariant::Classic | SudokuVariant::XSudoku => CELLS_TO_REMOVE,
+        SudokuVariant::HyperSudoku => HYPER_CELLS_TO_REMOVE,
+    }
+}
 
-    Some(SudokuBoard { puzzle, solution })
+/// How many cells the generator removes for `variant` on a `size` board.
+fn cells_to_remove_sized(variant: SudokuVariant, size: BoardSize) -> usize {
+    match size {
+        BoardSize::Nine => cells_to_remove(variant),
+        BoardSize::Six => SIX_CELLS_TO_REMOVE,
+        BoardSize::Four => FOUR_CELLS_TO_REMOVE,
+    }
+}
+
+/// The constraint regions `variant` adds on top of rows, columns and boxes,
+/// as lists of `(row, col)` cells. Each must contain 1-9 exactly once.
+pub fn extra_regions(variant: SudokuVariant) -> Vec<Vec<(usize, usize)>> {
+    match variant {
+        SudokuVariant::Classic => Vec::new(),
+        SudokuVariant::XSudoku => vec![
+            (0..9).map(|i| (i, i)).collect(),
+            (0..9).map(|i| (i, 8 - i)).collect(),
+        ],
+        SudokuVariant::HyperSudoku => HYPER_WINDOW_ORIGINS
+            .iter()
+            .map(|&(or, oc)| (0..9).map(|i| (or + i / 3, oc + i % 3)).collect())
+            .collect(),
+    }
+}
+
+/// Rate a puzzle by the simplest technique that solves it:
+/// naked singles only → `Easy`, hidden singles needed → `Medium`,
+/// anything beyond singles → `Hard`.
+pub fn rate(puzzle: &[[u8; 9]; 9], variant: SudokuVariant) -> Difficulty {
+    rate_sized(puzzle, variant, BoardSize::Nine)
+}
+
+/// `rate` for a `size` board.
+pub fn rate_sized(puzzle: &[[u8; 9]; 9], variant: SudokuVariant, size: BoardSize) -> Difficulty {
+    let mut grid = *puzzle;
+    let mut needed_hidden = false;
+    while find_empty(&grid, size).is_some() {
+        if let Some((r, c, v)) = find_naked_single(&grid, variant, size) {
+            grid[r][c] = v;
+        } else if let Some((r, c, v)) = find_hidden_single(&grid, variant, size) {
+            grid[r][c] = v;
+            needed_hidden = true;
+        } else {
+            return Difficulty::Hard;
+        }
+    }
+    if needed_hidden {
+        Difficulty::Medium
+    } else {
+        Difficulty::Easy
+    }
+}
+
+/// Share of blanks (in percent) whose mirror image must also be blank for
+/// `blank_symmetry` to report that symmetry. `remove_cells` can leave the
+/// odd cell unpaired, so an exact match would be too strict.
+const SYMMETRY_MIN_PERCENT: usize = 90;
+
+/// The symmetry of the blank cells in `puzzle`: `Rotational` if blanks
+/// mirror through the centre (what `remove_cells` produces), else
+/// `Diagonal` if they mirror across the main diagonal, else `Asymmetric`.
+pub fn blank_symmetry(puzzle: &[[u8; 9]; 9], size: BoardSize) -> Symmetry {
+    let side = size.side();
+    let blanks: Vec<(usize, usize)> = (0..side)
+        .flat_map(|r| (0..side).map(move |c| (r, c)))
+        .filter(|&(r, c)| puzzle[r][c] == 0)
+        .collect();
+    let holds = |mirror: fn(usize, usize, usize) -> (usize, usize)| {
+        let mirrored = blanks.iter().filter(|&&(r, c)| {
+            let (mr, mc) = mirror(r, c, side);
+            puzzle[mr][mc] == 0
+        }).count();
+        mirrored * 100 >= blanks.len() * SYMMETRY_MIN_PERCENT
+    };
+    if holds(|r, c, side| (side - 1 - r, side - 1 - c)) {
+        Symmetry::Rotational
+    } else if holds(|r, c, _| (c, r)) {
+        Symmetry::Diagonal
+    } else {
+        Symmetry::Asymmetric
+    }
+}
+
+/// Find an empty cell with exactly one legal candidate, returning
+/// `(row, col, value)`.
+pub fn find_naked_single(
+    grid: &[[u8; 9]; 9],
+    variant: SudokuVariant,
+    size: BoardSize,
+) -> Option<(usize, usize, u8)> {
+    let side = size.side();
+    for r in 0..side {
+        for c in 0..side {
+            if grid[r][c] == 0 {
+                let mask = candidate_mask(grid, r, c, variant, size);
+                if mask.count_ones() == 1 {
+                    return Some((r, c, mask.trailing_zeros() as u8));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Find an empty cell with no legal value left under `variant`'s rules,
+/// returning `(row, col)`. The board can't be finished without clearing
+/// something first.
+pub fn find_dead_cell(
+    grid: &[[u8; 9]; 9],
+    variant: SudokuVariant,
+    size: BoardSize,
+) -> Option<(usize, usize)> {
+    let side = size.side();
+    (0..side)
+        .flat_map(|r| (0..side).map(move |c| (r, c)))
+        .find(|&(r, c)| grid[r][c] == 0 && candidate_mask(grid, r, c, variant, size) == 0)
+}
+
+/// Find a value that fits in only one empty cell of some unit (row, column,
+/// box or variant region), returning `(row, col, value)`.
+fn find_hidden_single(
+    grid: &[[u8; 9]; 9],
+    variant: SudokuVariant,
+    size: BoardSize,
+) -> Option<(usize, usize, u8)> {
+    for unit in all_units(variant, size) {
+        for v in 1..=size.side() as u8 {
+            if unit.iter().any(|&(r, c)| grid[r][c] == v) {
+                continue;
+            }
+            let mut spots = unit.iter().filter(|&&(r, c)| {
+                grid[r][c] == 0 && candidate_mask(grid, r, c, variant, size) & (1 << v) != 0
+            });
+            if let (Some(&(r, c)), None) = (spots.next(), spots.next()) {
+                return Some((r, c, v));
+            }
+        }
+    }
+    None
+}
+
+/// Every unit that must contain each value once: rows, columns, boxes, then
+/// variant regions.
+fn all_units(variant: SudokuVariant, size: BoardSize) -> Vec<Vec<(usize, usize)>> {
+    let side = size.side();
+    let (box_rows, box_cols) = size.box_shape();
+    let boxes_across = side / box_cols;
+    let mut units: Vec<Vec<(usize, usize)>> = Vec::with_capacity(3 * side);
+    for i in 0..side {
+        units.push((0..side).map(|c| (i, c)).collect());
+        units.push((0..side).map(|r| (r, i)).collect());
+        let (top, left) = (i / boxes_across * box_rows, i % boxes_across * box_cols);
+        units.push((0..side).map(|k| (top + k / box_cols, left + k % box_cols)).collect());
+    }
+    units.extend(extra_regions(variant));
+    units
+}
+
+/// Count the solutions of `puzzle` under `variant`, stopping at `limit`.
+///
+/// `count_solutions(puzzle, variant, 2) == 1` is the uniqueness test.
+pub fn count_solutions(puzzle: &[[u8; 9]; 9], variant: SudokuVariant, limit: usize) -> usize {
+    count_solutions_sized(puzzle, variant, BoardSize::Nine, limit)
+}
+
+/// `count_solutions` for a `size` board.
+pub fn count_solutions_sized(
+    puzzle: &[[u8; 9]; 9],
+    variant: SudokuVariant,
+    size: BoardSize,
+    limit: usize,
+) -> usize {
+    let mut grid = *puzzle;
+    let mut count = 0;
+    count_solutions_rec(&mut grid, variant, size, limit, &mut count, &mut Steps(u64::MAX));
+    count
+}
+
+/// Whether `puzzle` has exactly one solution, searching at most
+/// `MAX_GENERATION_STEPS` so it's safe to run in a block. A search that
+/// runs out of steps before finding a second solution answers `false`:
+/// uniqueness wasn't shown.
+pub fn has_unique_solution(puzzle: &[[u8; 9]; 9], variant: SudokuVariant, size: BoardSize) -> bool {
+    let mut grid = *puzzle;
+    let mut count = 0;
+    let mut steps = Steps(MAX_GENERATION_STEPS);
+    count_solutions_rec(&mut grid, variant, size, 2, &mut count, &mut steps);
+    count == 1 && steps.0 > 0
+}
+
+/// SHA-256 commitment to a grid: the 81 cell values hashed row-major.
+///
+/// Published with the tournament so players can check, once the solution is
+/// revealed, that the Hub didn't swap puzzles mid-event.
+pub fn grid_commitment(grid: &[[u8; 9]; 9]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for row in grid {
+        hasher.update(row);
+    }
+    hasher.finalize().into()
 }
 
 /// Validate whether placing `value` at `(row, col)` is legal per Sudoku rules.
@@ -50,45 +348,240 @@ pub fn generate_puzzle(seed: u64) -> Option<SudokuBoard> {
 /// - No duplicate in the same row
 /// - No duplicate in the same column
 /// - No duplicate in the same 3×3 box
+/// - No duplicate in any extra region of `variant` (e.g. X-Sudoku diagonals)
 ///
 /// Does NOT check whether the cell is a given — the caller must do that.
-pub fn validate_placement(board: &[Vec<u8>], row: usize, col: usize, value: u8) -> bool {
-    if value < 1 || value > 9 || row > 8 || col > 8 {
+pub fn validate_placement(
+    board: &[Vec<u8>],
+    row: usize,
+    col: usize,
+    value: u8,
+    variant: SudokuVariant,
+) -> bool {
+    validate_placement_sized(board, row, col, value, variant, BoardSize::Nine)
+}
+
+/// `validate_placement` on a `size` board: values run 1 to `size.side()`,
+/// cells stay inside the top-left `side`×`side` area, and boxes have
+/// `size.box_shape()`.
+pub fn validate_placement_sized(
+    board: &[Vec<u8>],
+    row: usize,
+    col: usize,
+    value: u8,
+    variant: SudokuVariant,
+    size: BoardSize,
+) -> bool {
+    let side = size.side();
+    if value < 1 || value as usize > side || row >= side || col >= side {
         return false;
     }
 
     // Row check
-    for c in 0..9 {
+    for c in 0..side {
         if c != col && board[row][c] == value {
             return false;
         }
     }
 
     // Column check
-    for r in 0..9 {
+    for r in 0..side {
         if r != row && board[r][col] == value {
             return false;
         }
     }
 
-    // 3×3 box check
-    let box_r = (row / 3) * 3;
-    let box_c = (col / 3) * 3;
-    for r in box_r..box_r + 3 {
-        for c in box_c..box_c + 3 {
+    // Box check
+    let (box_r, box_c, box_rows, box_cols) = box_of(size, row, col);
+    for r in box_r..box_r + box_rows {
+        for c in box_c..box_c + box_cols {
             if (r != row || c != col) && board[r][c] == value {
                 return false;
             }
         }
     }
 
-    true
+    !violates_extra_regions(variant, row, col, value, |r, c| board[r][c])
+}
+
+/// `(top, left, rows, cols)` of the box holding `(row, col)` on a `size` board.
+fn box_of(size: BoardSize, row: usize, col: usize) -> (usize, usize, usize, usize) {
+    let (rows, cols) = size.box_shape();
+    (row / rows * rows, col / cols * cols, rows, cols)
+}
+
+/// Every filled cell that duplicates its value in some row, column, box,
+/// or extra region of `variant`. Only checks the rules against the board
+/// itself — the solution is never consulted.
+pub fn board_conflicts(board: &[Vec<u8>], variant: SudokuVariant) -> Vec<(usize, usize)> {
+    board_conflicts_sized(board, variant, BoardSize::Nine)
+}
+
+/// `board_conflicts` on a `size` board.
+pub fn board_conflicts_sized(
+    board: &[Vec<u8>],
+    variant: SudokuVariant,
+    size: BoardSize,
+) -> Vec<(usize, usize)> {
+    let side = size.side();
+    let mut conflicts = Vec::new();
+    for r in 0..side {
+        for c in 0..side {
+            let value = board[r][c];
+            if value != 0 && !validate_placement_sized(board, r, c, value, variant, size) {
+                conflicts.push((r, c));
+            }
+        }
+    }
+    conflicts
+}
+
+/// Per-unit value counts for a board, so a placement is checked against
+/// its row, column, box, and the variant's extra regions in O(1) instead of
+/// rescanning them. Agrees with `validate_placement` as long as every cell
+/// change goes through `set`.
+#[derive(Clone, Debug)]
+pub struct ConstraintTracker {
+    variant: SudokuVariant,
+    size: BoardSize,
+    cells: [[u8; 9]; 9],
+    /// How many times each value (index 1-9) appears in each unit: rows
+    /// 0-8, columns 9-17, boxes 18-26, then the variant's extra regions.
+    counts: Vec<[u8; 10]>,
+    /// Extra-region units each cell (row-major) belongs to.
+    extra_units: Vec<Vec<usize>>,
+}
+
+impl ConstraintTracker {
+    pub fn new(board: &[Vec<u8>], variant: SudokuVariant) -> Self {
+        Self::new_sized(board, variant, BoardSize::Nine)
+    }
+
+    pub fn new_sized(board: &[Vec<u8>], variant: SudokuVariant, size: BoardSize) -> Self {
+        let regions = extra_regions(variant);
+        let mut extra_units = vec![Vec::new(); 81];
+        for (i, region) in regions.iter().enumerate() {
+            for &(r, c) in region {
+                extra_units[r * 9 + c].push(27 + i);
+            }
+        }
+
+        let mut tracker = ConstraintTracker {
+            variant,
+            size,
+            cells: [[0; 9]; 9],
+            counts: vec![[0; 10]; 27 + regions.len()],
+            extra_units,
+        };
+        for (r, row) in board.iter().enumerate().take(9) {
+            for (c, &value) in row.iter().enumerate().take(9) {
+                tracker.set(r, c, value);
+            }
+        }
+        tracker
+    }
+
+    pub fn variant(&self) -> SudokuVariant {
+        self.variant
+    }
+
+    pub fn size(&self) -> BoardSize {
+        self.size
+    }
+
+    /// Units containing `(row, col)`.
+    fn units(&self, row: usize, col: usize) -> impl Iterator<Item = usize> + '_ {
+        let (box_rows, box_cols) = self.size.box_shape();
+        let boxes_across = self.size.side() / box_cols;
+        [row, 9 + col, 18 + row / box_rows * boxes_across + col / box_cols]
+            .into_iter()
+            .chain(self.extra_units[row * 9 + col].iter().copied())
+    }
+
+    /// Same answer as `validate_placement_sized` on the tracked board.
+    pub fn allows(&self, row: usize, col: usize, value: u8) -> bool {
+        let side = self.size.side();
+        if value < 1 || value as usize > side || row >= side || col >= side {
+            return false;
+        }
+        let own = (self.cells[row][col] == value) as u8;
+        self.units(row, col).all(|u| self.counts[u][value as usize] == own)
+    }
+
+    /// Record that `(row, col)` now holds `value` (0 clears it). Values
+    /// outside 0-9 and out-of-range cells are ignored.
+    pub fn set(&mut self, row: usize, col: usize, value: u8) {
+        if row > 8 || col > 8 || value > 9 {
+            return;
+        }
+        let old = self.cells[row][col];
+        let units: Vec<usize> = self.units(row, col).collect();
+        for u in units {
+            if old != 0 {
+                self.counts[u][old as usize] -= 1;
+            }
+            if value != 0 {
+                self.counts[u][value as usize] += 1;
+            }
+        }
+        self.cells[row][col] = value;
+    }
+}
+
+/// Whether `board` is a finished solve of `puzzle`: nine rows of nine,
+/// givens untouched, and every other cell matching the solution.
+pub fn is_solved_by(puzzle: &SudokuBoard, board: &[Vec<u8>]) -> bool {
+    validate_board_arg(board).is_ok_and(|grid| check_submission(puzzle, &grid) == Ok(0))
+}
+
+/// Check a whole submitted board (already shaped by `validate_board_arg`)
+/// against `puzzle`. Returns how many non-given cells differ from the
+/// solution (empty cells count as wrong), or a reason if it alters a given.
+pub fn check_submission(puzzle: &SudokuBoard, board: &[[u8; 9]; 9]) -> Result<u32, String> {
+    let side = puzzle.size.side();
+    let mut wrong = 0;
+    for r in 0..9 {
+        for c in 0..9 {
+            let value = board[r][c];
+            if value as usize > side {
+                return Err(format!("Value {} at ({}, {}) is outside 1-{}", value, r, c, side));
+            }
+            if puzzle.puzzle[r][c] != 0 {
+                if value != puzzle.puzzle[r][c] {
+                    return Err(format!("Given cell ({}, {}) was changed", r, c));
+                }
+            } else if value != puzzle.solution[r][c] {
+                wrong += 1;
+            }
+        }
+    }
+    Ok(wrong)
 }
 
 /// Verify a complete game replay: given a seed and a list of (row, col, value)
 /// moves, deterministically replay them and return the result.
-pub fn verify_game(seed: u64, moves: &[(u8, u8, u8)]) -> crate::VerifyResult {
-    let board_opt = generate_puzzle(seed);
+/// Moves carry no timing, so a completed board is scored as if solved instantly.
+pub fn verify_game(
+    seed: u64,
+    variant: SudokuVariant,
+    moves: &[(u8, u8, u8)],
+) -> crate::VerifyResult {
+    let timed: Vec<(u8, u8, u8, u64)> = moves.iter().map(|&(r, c, v)| (r, c, v, 0)).collect();
+    verify_game_timed(seed, variant, ScoringMode::Balanced, 0, &timed)
+}
+
+/// Like `verify_game`, but each move also carries its playing time (µs since
+/// the tournament started, pauses excluded — as stored in `move_times`), so a
+/// completed board gets the same score the Hub computed. `value == 0` is a
+/// clear, as in `move_history`.
+pub fn verify_game_timed(
+    seed: u64,
+    variant: SudokuVariant,
+    mode: ScoringMode,
+    free_mistakes: u32,
+    moves: &[(u8, u8, u8, u64)],
+) -> crate::VerifyResult {
+    let board_opt = generate_puzzle(seed, variant);
     let board = match board_opt {
         Some(b) => b,
         None => {
@@ -103,24 +596,18 @@ pub fn verify_game(seed: u64, moves: &[(u8, u8, u8)]) -> crate::VerifyResult {
     };
 
     let mut state = crate::PlayerGameState::new(&board.puzzle);
-    let mut penalty_count: u32 = 0;
 
-    for &(row, col, value) in moves {
+    for &(row, col, value, _) in moves {
         let r = row as usize;
         let c = col as usize;
 
-        if r > 8 || c > 8 || value < 1 || value > 9 {
-            penalty_count = penalty_count.saturating_add(1);
+        if r > 8 || c > 8 || value > 9 || state.given_mask[r][c] {
+            state.record_mistake(free_mistakes);
             continue;
         }
 
-        if state.given_mask[r][c] {
-            penalty_count = penalty_count.saturating_add(1);
-            continue;
-        }
-
-        if !validate_placement(&state.board, r, c, value) {
-            penalty_count = penalty_count.saturating_add(1);
+        if value != 0 && !validate_placement(&state.board, r, c, value, variant) {
+            state.record_mistake(free_mistakes);
         }
 
         // Place regardless (we record the move even if invalid for replay fidelity)
@@ -128,9 +615,10 @@ pub fn verify_game(seed: u64, moves: &[(u8, u8, u8)]) -> crate::VerifyResult {
     }
 
     let board_complete = state.check_complete(&board.solution);
-    // Assume a hypothetical 1-hour window for scoring during verification
+    // The board is complete as of the last move, so that's when the clock stopped
     let score = if board_complete {
-        10_000u64.saturating_sub((penalty_count as u64).saturating_mul(100))
+        let elapsed = moves.last().map_or(0, |m| m.3);
+        compute_score(elapsed, state.penalty_count, mode)
     } else {
         0
     };
@@ -138,26 +626,119 @@ pub fn verify_game(seed: u64, moves: &[(u8, u8, u8)]) -> crate::VerifyResult {
     crate::VerifyResult {
         valid: true,
         total_moves: moves.len() as u32,
-        penalty_count,
+        penalty_count: state.penalty_count,
         final_score: score,
         board_complete,
     }
 }
 
+/// Parse an 81-cell puzzle string, row by row. Digits `1`-`9` are givens,
+/// `0` or `.` are empty cells; whitespace is ignored.
+pub fn from_string(puzzle_string: &str) -> Result<[[u8; 9]; 9], String> {
+    let cells: Vec<char> = puzzle_string.chars().filter(|ch| !ch.is_whitespace()).collect();
+    if cells.len() != 81 {
+        return Err(format!("Expected 81 cells, got {}", cells.len()));
+    }
+
+    let mut grid = [[0u8; 9]; 9];
+    for (i, &ch) in cells.iter().enumerate() {
+        grid[i / 9][i % 9] = match ch {
+            '.' => 0,
+            '0'..='9' => ch as u8 - b'0',
+            _ => return Err(format!("Invalid character '{}' at cell {}", ch, i)),
+        };
+    }
+    Ok(grid)
+}
+
+/// Vet a puzzle string for `variant` without starting anything: does it
+/// parse, are its givens consistent, does it have exactly one solution, and
+/// how hard is it. The solution search stops after `MAX_GENERATION_STEPS`.
+pub fn validate_puzzle_string(puzzle_string: &str, variant: SudokuVariant) -> PuzzleValidation {
+    let mut validation = PuzzleValidation::default();
+    let puzzle = match from_string(puzzle_string) {
+        Ok(grid) => grid,
+        Err(error) => {
+            validation.error = Some(error);
+            return validation;
+        }
+    };
+    validation.parses = true;
+    validation.givens = puzzle.iter().flatten().filter(|&&v| v != 0).count() as u32;
+
+    let rows: Vec<Vec<u8>> = puzzle.iter().map(|row| row.to_vec()).collect();
+    if let Some(&(r, c)) = board_conflicts(&rows, variant).first() {
+        validation.error = Some(format!("Given at row {}, column {} breaks the rules", r, c));
+        return validation;
+    }
+
+    let mut grid = puzzle;
+    let mut count = 0;
+    let mut steps = Steps(MAX_GENERATION_STEPS);
+    count_solutions_rec(&mut grid, variant, BoardSize::Nine, 2, &mut count, &mut steps);
+    if steps.0 == 0 && count < 2 {
+        validation.error = Some("Solution search ran out of steps".to_string());
+        return validation;
+    }
+
+    validation.solution_count = count as u32;
+    validation.unique = count == 1;
+    if validation.unique {
+        validation.difficulty = Some(rate(&puzzle, variant));
+    }
+    validation
+}
+
 // ---------------------------------------------------------------------------
 // Internal: grid generation via backtracking
 // ---------------------------------------------------------------------------
 
+/// Remaining search steps for one generation.
+struct Steps(u64);
+
+impl Steps {
+    /// Spend one step; `false` once the budget is gone.
+    fn take(&mut self) -> bool {
+        match self.0.checked_sub(1) {
+            Some(left) => {
+                self.0 = left;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn exhausted(&self) -> bool {
+        self.0 == 0
+    }
+}
+
 /// Fill the entire 9×9 grid with valid numbers using randomised backtracking.
-fn fill_grid(grid: &mut [[u8; 9]; 9], rng: &mut ChaCha8Rng) -> bool {
-    if let Some((row, col)) = find_empty(grid) {
-        let mut candidates: Vec<u8> = (1..=9).collect();
+///
+/// Variants with extra regions fill the most constrained cell first; plain
+/// row-major order thrashes badly once the diagonals are involved.
+fn fill_grid(
+    grid: &mut [[u8; 9]; 9],
+    rng: &mut ChaCha8Rng,
+    variant: SudokuVariant,
+    size: BoardSize,
+    steps: &mut Steps,
+) -> bool {
+    let next = match variant {
+        SudokuVariant::Classic => find_empty(grid, size),
+        _ => find_most_constrained(grid, variant, size),
+    };
+    if let Some((row, col)) = next {
+        let mut candidates: Vec<u8> = (1..=size.side() as u8).collect();
         candidates.shuffle(rng);
 
         for &val in &candidates {
-            if is_safe(grid, row, col, val) {
+            if !steps.take() {
+                return false;
+            }
+            if is_safe(grid, row, col, val, variant, size) {
                 grid[row][col] = val;
-                if fill_grid(grid, rng) {
+                if fill_grid(grid, rng, variant, size, steps) {
                     return true;
                 }
                 grid[row][col] = 0;
@@ -170,10 +751,12 @@ fn fill_grid(grid: &mut [[u8; 9]; 9], rng: &mut ChaCha8Rng) -> bool {
     }
 }
 
-/// Find the first empty cell (value == 0), scanning row-by-row.
-fn find_empty(grid: &[[u8; 9]; 9]) -> Option<(usize, usize)> {
-    for r in 0..9 {
-        for c in 0..9 {
+/// Find the first empty cell (value == 0) of a `size` board, scanning
+/// row-by-row.
+fn find_empty(grid: &[[u8; 9]; 9], size: BoardSize) -> Option<(usize, usize)> {
+    let side = size.side();
+    for r in 0..side {
+        for c in 0..side {
             if grid[r][c] == 0 {
                 return Some((r, c));
             }
@@ -182,9 +765,37 @@ fn find_empty(grid: &[[u8; 9]; 9]) -> Option<(usize, usize)> {
     None
 }
 
+/// Find the empty cell with the fewest legal candidates (first one on ties).
+fn find_most_constrained(
+    grid: &[[u8; 9]; 9],
+    variant: SudokuVariant,
+    size: BoardSize,
+) -> Option<(usize, usize)> {
+    let side = size.side();
+    let mut best: Option<(usize, usize, u32)> = None;
+    for r in 0..side {
+        for c in 0..side {
+            if grid[r][c] == 0 {
+                let options = candidate_mask(grid, r, c, variant, size).count_ones();
+                if best.map_or(true, |(_, _, b)| options < b) {
+                    best = Some((r, c, options));
+                }
+            }
+        }
+    }
+    best.map(|(r, c, _)| (r, c))
+}
+
 /// Check if placing `val` at `(row, col)` is safe in the fixed-size grid.
-fn is_safe(grid: &[[u8; 9]; 9], row: usize, col: usize, val: u8) -> bool {
-    // Row
+fn is_safe(
+    grid: &[[u8; 9]; 9],
+    row: usize,
+    col: usize,
+    val: u8,
+    variant: SudokuVariant,
+    size: BoardSize,
+) -> bool {
+    // Row (cells outside a mini board are always 0)
     for c in 0..9 {
         if grid[row][c] == val {
             return false;
@@ -198,27 +809,158 @@ fn is_safe(grid: &[[u8; 9]; 9], row: usize, col: usize, val: u8) -> bool {
         }
     }
 
-    // 3×3 box
-    let box_r = (row / 3) * 3;
-    let box_c = (col / 3) * 3;
-    for r in box_r..box_r + 3 {
-        for c in box_c..box_c + 3 {
+    // Box
+    let (box_r, box_c, box_rows, box_cols) = box_of(size, row, col);
+    for r in box_r..box_r + box_rows {
+        for c in box_c..box_c + box_cols {
             if grid[r][c] == val {
                 return false;
             }
         }
     }
 
-    true
+    !violates_extra_regions(variant, row, col, val, |r, c| grid[r][c])
+}
+
+/// Check the variant-specific regions (beyond rows, columns and boxes) that
+/// contain `(row, col)` for another cell already holding `val`.
+fn violates_extra_regions<F: Fn(usize, usize) -> u8>(
+    variant: SudokuVariant,
+    row: usize,
+    col: usize,
+    val: u8,
+    cell: F,
+) -> bool {
+    extra_region_values(variant, row, col, cell) & (1 << val) != 0
+}
+
+/// Bitmask (bit `v` set for value `v`) of the values held by other cells in
+/// the variant-specific regions containing `(row, col)`.
+fn extra_region_values<F: Fn(usize, usize) -> u8>(
+    variant: SudokuVariant,
+    row: usize,
+    col: usize,
+    cell: F,
+) -> u16 {
+    let mut used = 0u16;
+    match variant {
+        SudokuVariant::Classic => {}
+        SudokuVariant::XSudoku => {
+            for i in (0..9).filter(|&i| i != row) {
+                if row == col {
+                    used |= 1 << cell(i, i);
+                }
+                if row + col == 8 {
+                    used |= 1 << cell(i, 8 - i);
+                }
+            }
+        }
+        SudokuVariant::HyperSudoku => {
+            let window = HYPER_WINDOW_ORIGINS.iter().find(|&&(or, oc)| {
+                (or..or + 3).contains(&row) && (oc..oc + 3).contains(&col)
+            });
+            if let Some(&(or, oc)) = window {
+                for r in or..or + 3 {
+                    for c in oc..oc + 3 {
+                        if r != row || c != col {
+                            used |= 1 << cell(r, c);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    used & !1
+}
+
+/// Bitmask of the values (bits 1 to `size.side()`) that can legally go in
+/// empty cell `(row, col)`.
+fn candidate_mask(
+    grid: &[[u8; 9]; 9],
+    row: usize,
+    col: usize,
+    variant: SudokuVariant,
+    size: BoardSize,
+) -> u16 {
+    let mut used = extra_region_values(variant, row, col, |r, c| grid[r][c]);
+    for i in 0..9 {
+        used |= 1 << grid[row][i];
+        used |= 1 << grid[i][col];
+    }
+    let (box_r, box_c, box_rows, box_cols) = box_of(size, row, col);
+    for r in box_r..box_r + box_rows {
+        for c in box_c..box_c + box_cols {
+            used |= 1 << grid[r][c];
+        }
+    }
+    let all_values = (1u16 << (size.side() + 1)) - 2;
+    !used & all_values
+}
+
+/// Backtracking solution counter. Picks the empty cell with the fewest
+/// candidates first so uniqueness checks stay cheap.
+fn count_solutions_rec(
+    grid: &mut [[u8; 9]; 9],
+    variant: SudokuVariant,
+    size: BoardSize,
+    limit: usize,
+    count: &mut usize,
+    steps: &mut Steps,
+) {
+    if *count >= limit || !steps.take() {
+        return;
+    }
+
+    let side = size.side();
+    let mut best: Option<(usize, usize, u16)> = None;
+    for r in 0..side {
+        for c in 0..side {
+            if grid[r][c] != 0 {
+                continue;
+            }
+            let mask = candidate_mask(grid, r, c, variant, size);
+            if mask == 0 {
+                return;
+            }
+            if best.map_or(true, |(_, _, b)| mask.count_ones() < b.count_ones()) {
+                best = Some((r, c, mask));
+            }
+        }
+    }
+
+    match best {
+        None => *count += 1,
+        Some((r, c, mask)) => {
+            for val in (1..=9u8).filter(|v| mask & (1 << v) != 0) {
+                grid[r][c] = val;
+                count_solutions_rec(grid, variant, size, limit, count, steps);
+                grid[r][c] = 0;
+                if *count >= limit || steps.exhausted() {
+                    return;
+                }
+            }
+        }
+    }
 }
 
 /// Remove cells from a completed grid to create the puzzle.
-/// Uses diagonal symmetry for aesthetic appeal.
-fn remove_cells(grid: &mut [[u8; 9]; 9], rng: &mut ChaCha8Rng) {
+/// Removes cells in pairs mirrored through the centre (180° rotational
+/// symmetry) for aesthetic appeal. Stops early, leaving
+/// `steps` exhausted, if the uniqueness checks run out of budget.
+fn remove_cells(
+    grid: &mut [[u8; 9]; 9],
+    rng: &mut ChaCha8Rng,
+    variant: SudokuVariant,
+    size: BoardSize,
+    steps: &mut Steps,
+) {
+    let target = cells_to_remove_sized(variant, size);
+    let side = size.side();
+
     // Build list of all cell positions, shuffle them
-    let mut positions: Vec<(usize, usize)> = Vec::with_capacity(81);
-    for r in 0..9 {
-        for c in 0..9 {
+    let mut positions: Vec<(usize, usize)> = Vec::with_capacity(side * side);
+    for r in 0..side {
+        for c in 0..side {
             positions.push((r, c));
         }
     }
@@ -226,27 +968,61 @@ fn remove_cells(grid: &mut [[u8; 9]; 9], rng: &mut ChaCha8Rng) {
 
     let mut removed = 0;
     for (r, c) in positions {
-        if removed >= CELLS_TO_REMOVE {
+        if removed >= target {
             break;
         }
 
         if grid[r][c] != 0 {
+            let saved = *grid;
+
             // Remove this cell
             grid[r][c] = 0;
-            removed += 1;
+            let mut pair_removed = 1;
 
-            // Also remove symmetric cell if possible (diagonal symmetry)
-            let sym_r = 8 - r;
-            let sym_c = 8 - c;
-            if removed < CELLS_TO_REMOVE && grid[sym_r][sym_c] != 0 && (sym_r != r || sym_c != c)
+            // Also remove the cell mirrored through the centre if possible
+            let sym_r = side - 1 - r;
+            let sym_c = side - 1 - c;
+            if removed + pair_removed < target
+                && grid[sym_r][sym_c] != 0
+                && (sym_r != r || sym_c != c)
             {
                 grid[sym_r][sym_c] = 0;
-                removed += 1;
+                pair_removed += 1;
+            }
+
+            if !keeps_min_givens(grid, size) {
+                *grid = saved;
+                continue;
             }
+
+            // Classic 9×9 keeps its historical behaviour; variants and mini
+            // boards must stay unique.
+            if variant != SudokuVariant::Classic || size != BoardSize::Nine {
+                let mut scratch = *grid;
+                let mut solutions = 0;
+                count_solutions_rec(&mut scratch, variant, size, 2, &mut solutions, steps);
+                if steps.exhausted() {
+                    return;
+                }
+                if solutions != 1 {
+                    *grid = saved;
+                    continue;
+                }
+            }
+            removed += pair_removed;
         }
     }
 }
 
+/// Whether every row, column, and box still holds at least
+/// `MIN_GIVENS_PER_UNIT` givens (1 on mini boards).
+fn keeps_min_givens(grid: &[[u8; 9]; 9], size: BoardSize) -> bool {
+    let min = if size == BoardSize::Nine { MIN_GIVENS_PER_UNIT } else { 1 };
+    all_units(SudokuVariant::Classic, size).iter().all(|unit| {
+        unit.iter().filter(|&&(r, c)| grid[r][c] != 0).count() >= min
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -257,22 +1033,22 @@ mod tests {
 
     #[test]
     fn test_deterministic_generation() {
-        let board1 = generate_puzzle(42).expect("should generate");
-        let board2 = generate_puzzle(42).expect("should generate");
+        let board1 = generate_puzzle(42, SudokuVariant::Classic).expect("should generate");
+        let board2 = generate_puzzle(42, SudokuVariant::Classic).expect("should generate");
         assert_eq!(board1.puzzle, board2.puzzle);
         assert_eq!(board1.solution, board2.solution);
     }
 
     #[test]
     fn test_different_seeds_different_puzzles() {
-        let board1 = generate_puzzle(1).expect("should generate");
-        let board2 = generate_puzzle(2).expect("should generate");
+        let board1 = generate_puzzle(1, SudokuVariant::Classic).expect("should generate");
+        let board2 = generate_puzzle(2, SudokuVariant::Classic).expect("should generate");
         assert_ne!(board1.puzzle, board2.puzzle);
     }
 
     #[test]
     fn test_solution_is_valid() {
-        let board = generate_puzzle(12345).expect("should generate");
+        let board = generate_puzzle(12345, SudokuVariant::Classic).expect("should generate");
         for r in 0..9 {
             let mut seen = [false; 10];
             for c in 0..9 {
@@ -294,7 +1070,7 @@ mod tests {
 
     #[test]
     fn test_puzzle_has_givens_and_blanks() {
-        let board = generate_puzzle(999).expect("should generate");
+        let board = generate_puzzle(999, SudokuVariant::Classic).expect("should generate");
         let mut givens = 0;
         let mut blanks = 0;
         for r in 0..9 {
@@ -314,7 +1090,7 @@ mod tests {
 
     #[test]
     fn test_validate_placement() {
-        let board = generate_puzzle(7777).expect("should generate");
+        let board = generate_puzzle(7777, SudokuVariant::Classic).expect("should generate");
         let state = crate::PlayerGameState::new(&board.puzzle);
 
         // Valid: placing the solution value in an empty cell
@@ -323,7 +1099,7 @@ mod tests {
                 if !state.given_mask[r][c] {
                     let correct_val = board.solution[r][c];
                     assert!(
-                        validate_placement(&state.board, r, c, correct_val),
+                        validate_placement(&state.board, r, c, correct_val, SudokuVariant::Classic),
                         "Should be valid at ({}, {}) with value {}",
                         r, c, correct_val
                     );
@@ -336,7 +1112,7 @@ mod tests {
     #[test]
     fn test_verify_game_complete() {
         let seed = 55555;
-        let board = generate_puzzle(seed).expect("should generate");
+        let board = generate_puzzle(seed, SudokuVariant::Classic).expect("should generate");
         let state = crate::PlayerGameState::new(&board.puzzle);
 
         // Build the list of moves needed to complete the puzzle
@@ -349,10 +1125,349 @@ mod tests {
             }
         }
 
-        let result = verify_game(seed, &moves);
+        let result = verify_game(seed, SudokuVariant::Classic, &moves);
         assert!(result.valid);
         assert!(result.board_complete);
         assert_eq!(result.penalty_count, 0);
         assert!(result.final_score > 0);
     }
+
+    #[test]
+    fn test_grid_commitment_binds_solution() {
+        let board = generate_puzzle(31337, SudokuVariant::Classic).expect("should generate");
+        let commitment = grid_commitment(&board.solution);
+        assert_eq!(commitment, grid_commitment(&board.solution));
+
+        let mut tampered = board.solution;
+        tampered[0].swap(0, 1);
+        assert_ne!(commitment, grid_commitment(&tampered));
+    }
+
+    #[test]
+    fn test_x_sudoku_solution_satisfies_diagonals() {
+        let board = generate_puzzle(2024, SudokuVariant::XSudoku).expect("should generate");
+        for diagonal in [
+            (0..9).map(|i| board.solution[i][i]).collect::<Vec<_>>(),
+            (0..9).map(|i| board.solution[i][8 - i]).collect::<Vec<_>>(),
+        ] {
+            let mut seen = [false; 10];
+            for v in diagonal {
+                assert!(!seen[v as usize], "Duplicate {} on a diagonal", v);
+                seen[v as usize] = true;
+            }
+        }
+        assert_eq!(count_solutions(&board.puzzle, SudokuVariant::XSudoku, 2), 1);
+    }
+
+    #[test]
+    fn test_hyper_sudoku_solution_satisfies_windows() {
+        let variant = SudokuVariant::HyperSudoku;
+        let board = generate_puzzle(4242, variant).expect("should generate");
+        let regions = extra_regions(variant);
+        assert_eq!(regions.len(), 4);
+        for region in regions {
+            let mut seen = [false; 10];
+            for (r, c) in region {
+                let v = board.solution[r][c] as usize;
+                assert!(!seen[v], "Duplicate {} in window containing ({}, {})", v, r, c);
+                seen[v] = true;
+            }
+        }
+
+        let blanks = board.puzzle.iter().flatten().filter(|&&v| v == 0).count();
+        assert_eq!(blanks, cells_to_remove(variant));
+        assert_eq!(count_solutions(&board.puzzle, variant, 2), 1);
+    }
+
+    #[test]
+    fn test_has_unique_solution_within_budget() {
+        let board = generate_puzzle(4242, SudokuVariant::Classic).expect("should generate");
+        let counted = count_solutions(&board.puzzle, SudokuVariant::Classic, 2) == 1;
+        let bounded = has_unique_solution(&board.puzzle, SudokuVariant::Classic, BoardSize::Nine);
+        assert_eq!(bounded, counted);
+
+        let mut one_blank = board.solution;
+        one_blank[4][4] = 0;
+        assert!(has_unique_solution(&one_blank, SudokuVariant::Classic, BoardSize::Nine));
+        // An empty grid has many; the bounded search stops early either way.
+        assert!(!has_unique_solution(&[[0; 9]; 9], SudokuVariant::Classic, BoardSize::Nine));
+    }
+
+    #[test]
+    fn test_rate_by_technique() {
+        let board = generate_puzzle(8080, SudokuVariant::Classic).expect("should generate");
+
+        let mut one_blank = board.solution;
+        one_blank[4][4] = 0;
+        assert_eq!(rate(&one_blank, SudokuVariant::Classic), Difficulty::Easy);
+
+        // An empty grid can't be progressed by singles alone.
+        assert_eq!(rate(&[[0u8; 9]; 9], SudokuVariant::Classic), Difficulty::Hard);
+
+        let rating = rate(&board.puzzle, SudokuVariant::Classic);
+        assert_eq!(rating, rate(&board.puzzle, SudokuVariant::Classic), "rating is deterministic");
+    }
+
+    #[test]
+    fn test_min_givens_per_unit() {
+        for seed in 0..50u64 {
+            for variant in [SudokuVariant::Classic, SudokuVariant::XSudoku] {
+                let board = generate_puzzle(seed, variant).expect("should generate");
+                for unit in all_units(SudokuVariant::Classic, BoardSize::Nine) {
+                    let givens = unit.iter().filter(|&&(r, c)| board.puzzle[r][c] != 0).count();
+                    assert!(givens >= MIN_GIVENS_PER_UNIT, "seed {} unit {:?} has {} givens", seed, unit, givens);
+                }
+                let blanks = board.puzzle.iter().flatten().filter(|&&v| v == 0).count();
+                assert_eq!(blanks, cells_to_remove(variant));
+            }
+        }
+    }
+
+    #[test]
+    fn test_board_conflicts() {
+        let board = generate_puzzle(777, SudokuVariant::Classic).expect("should generate");
+        let mut grid: Vec<Vec<u8>> = board.puzzle.iter().map(|r| r.to_vec()).collect();
+        assert!(board_conflicts(&grid, SudokuVariant::Classic).is_empty());
+
+        // Duplicate a given into an empty cell of the same row.
+        let c = (0..9).find(|&c| grid[0][c] == 0).unwrap();
+        let given_c = (0..9).find(|&c| grid[0][c] != 0).unwrap();
+        grid[0][c] = grid[0][given_c];
+        let conflicts = board_conflicts(&grid, SudokuVariant::Classic);
+        assert!(conflicts.contains(&(0, c)));
+        assert!(conflicts.contains(&(0, given_c)));
+    }
+
+    #[test]
+    fn test_x_sudoku_diagonal_conflicts() {
+        let mut board = vec![vec![0u8; 9]; 9];
+        board[0][0] = 4;
+        board[0][8] = 6;
+
+        // Same main diagonal, different row/col/box as (0,0).
+        assert!(validate_placement(&board, 4, 4, 4, SudokuVariant::Classic));
+        assert!(!validate_placement(&board, 4, 4, 4, SudokuVariant::XSudoku));
+        // Centre sits on both diagonals.
+        assert!(!validate_placement(&board, 4, 4, 6, SudokuVariant::XSudoku));
+        // Anti-diagonal only.
+        assert!(!validate_placement(&board, 8, 0, 6, SudokuVariant::XSudoku));
+        // Off-diagonal cells are unaffected.
+        assert!(validate_placement(&board, 4, 5, 4, SudokuVariant::XSudoku));
+    }
+
+    #[test]
+    fn test_generation_step_budget() {
+        for variant in [SudokuVariant::Classic, SudokuVariant::XSudoku, SudokuVariant::HyperSudoku] {
+            let board = generate_puzzle_within(4242, variant, MAX_GENERATION_STEPS)
+                .expect("should generate within the default budget");
+            // The budget doesn't change the puzzle a seed produces.
+            let unbounded = generate_puzzle_within(4242, variant, u64::MAX).unwrap();
+            assert_eq!((unbounded.puzzle, unbounded.solution), (board.puzzle, board.solution));
+            assert!(generate_puzzle_within(4242, variant, 50).is_none());
+        }
+    }
+
+    #[test]
+    fn test_check_submission() {
+        let board = generate_puzzle(42, SudokuVariant::Classic).unwrap();
+        let solved = board.solution;
+        assert_eq!(check_submission(&board, &solved), Ok(0));
+
+        // Swap two non-given cells in a row: two wrong cells.
+        let mut wrong = solved;
+        let r = (0..9).find(|&r| (0..9).filter(|&c| board.puzzle[r][c] == 0).count() >= 2).unwrap();
+        let empty: Vec<usize> = (0..9).filter(|&c| board.puzzle[r][c] == 0).take(2).collect();
+        wrong[r].swap(empty[0], empty[1]);
+        assert_eq!(check_submission(&board, &wrong), Ok(2));
+
+        let (gr, gc) = (0..81).map(|i| (i / 9, i % 9)).find(|&(r, c)| board.puzzle[r][c] != 0).unwrap();
+        let mut altered = solved;
+        altered[gr][gc] = board.puzzle[gr][gc] % 9 + 1;
+        assert!(check_submission(&board, &altered).is_err());
+    }
+
+    #[test]
+    fn test_validate_puzzle_string() {
+        // A well-known puzzle with a unique solution, whitespace and all.
+        let puzzle = "530070000 600195000 098000060 800060003 400803001 \
+                      700020006 060000280 000419005 000080079";
+        let valid = validate_puzzle_string(puzzle, SudokuVariant::Classic);
+        assert!(valid.parses && valid.unique, "{:?}", valid);
+        assert_eq!(valid.solution_count, 1);
+        assert_eq!(valid.givens, 30);
+        let grid = from_string(puzzle).unwrap();
+        assert_eq!(grid[0], [5, 3, 0, 0, 7, 0, 0, 0, 0]);
+        assert_eq!(valid.difficulty, Some(rate(&grid, SudokuVariant::Classic)));
+        assert_eq!(valid.error, None);
+
+        let as_string = |grid: &[[u8; 9]; 9]| -> String {
+            grid.iter().flatten().map(|&v| if v == 0 { '.' } else { (b'0' + v) as char }).collect()
+        };
+        // Only the first row given: countless completions.
+        let mut sparse = [[0u8; 9]; 9];
+        sparse[0] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let ambiguous = validate_puzzle_string(&as_string(&sparse), SudokuVariant::Classic);
+        assert!(ambiguous.parses && !ambiguous.unique);
+        assert_eq!(ambiguous.solution_count, 2);
+        assert_eq!(ambiguous.givens, 9);
+        assert_eq!(ambiguous.difficulty, None);
+
+        assert!(!validate_puzzle_string("123", SudokuVariant::Classic).parses);
+        assert!(from_string(&"x".repeat(81)).is_err());
+
+        let mut clash = sparse;
+        clash[1][0] = clash[0][0];
+        let clash = validate_puzzle_string(&as_string(&clash), SudokuVariant::Classic);
+        assert!(clash.parses && clash.error.is_some() && !clash.unique);
+    }
+
+
+    #[test]
+    fn test_daily_puzzle_is_stable_per_date() {
+        let today = daily_puzzle(20261016).expect("should generate");
+        let again = daily_puzzle(20261016).expect("should generate");
+        assert_eq!(today.puzzle, again.puzzle);
+        assert_eq!(today.solution, again.solution);
+
+        let tomorrow = daily_puzzle(20261017).expect("should generate");
+        assert_ne!(today.puzzle, tomorrow.puzzle);
+        assert_ne!(daily_seed(20261016), 20261016);
+    }
+
+
+    #[test]
+    fn test_constraint_tracker_matches_scan() {
+        for (seed, variant) in [
+            (1, SudokuVariant::Classic),
+            (2, SudokuVariant::XSudoku),
+            (3, SudokuVariant::HyperSudoku),
+        ] {
+            let board = generate_puzzle(seed, variant).expect("should generate");
+            let mut grid: Vec<Vec<u8>> = board.puzzle.iter().map(|row| row.to_vec()).collect();
+            let mut tracker = ConstraintTracker::new(&grid, variant);
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+            // Random placements and clears, duplicates included.
+            for _ in 0..300 {
+                let (r, c) = (rng.gen_range(0..9), rng.gen_range(0..9));
+                let value = rng.gen_range(0..=9);
+                grid[r][c] = value;
+                tracker.set(r, c, value);
+
+                for r in 0..9 {
+                    for c in 0..9 {
+                        for v in 0..=10 {
+                            assert_eq!(
+                                tracker.allows(r, c, v),
+                                validate_placement(&grid, r, c, v, variant),
+                                "{:?} ({}, {}) = {}", variant, r, c, v,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mini_boards_generate_valid_unique_puzzles() {
+        for size in [BoardSize::Four, BoardSize::Six] {
+            let side = size.side();
+            for seed in [1u64, 42, 9_999] {
+                let board = generate_puzzle_sized(seed, SudokuVariant::Classic, size).unwrap();
+                assert_eq!(board.size, size);
+                let solved: Vec<Vec<u8>> = board.solution.iter().map(|r| r.to_vec()).collect();
+                for r in 0..9 {
+                    for c in 0..9 {
+                        if r < side && c < side {
+                            let v = board.solution[r][c];
+                            assert!((1..=side as u8).contains(&v));
+                            let mut probe = solved.clone();
+                            probe[r][c] = 0;
+                            assert!(validate_placement_sized(&probe, r, c, v, SudokuVariant::Classic, size));
+                        } else {
+                            assert_eq!(board.puzzle[r][c], 0);
+                            assert_eq!(board.solution[r][c], 0);
+                        }
+                    }
+                }
+                assert!(board_conflicts_sized(&solved, SudokuVariant::Classic, size).is_empty());
+                assert_eq!(count_solutions_sized(&board.puzzle, SudokuVariant::Classic, size, 2), 1);
+                assert!(board.open_cells() > 0);
+            }
+        }
+        // Variant regions don't fit a mini board.
+        assert!(generate_puzzle_sized(1, SudokuVariant::XSudoku, BoardSize::Four).is_none());
+    }
+
+    #[test]
+    fn test_six_by_six_boxes_are_two_by_three() {
+        let mut grid = vec![vec![0u8; 9]; 9];
+        grid[0][0] = 1;
+        // Same 2×3 box as (0, 0).
+        assert!(!validate_placement_sized(&grid, 1, 2, 1, SudokuVariant::Classic, BoardSize::Six));
+        // Next box down, different row and column.
+        assert!(validate_placement_sized(&grid, 2, 1, 1, SudokuVariant::Classic, BoardSize::Six));
+        // Values above the side don't fit.
+        assert!(!validate_placement_sized(&grid, 5, 5, 7, SudokuVariant::Classic, BoardSize::Six));
+    }
+
+    #[test]
+    fn test_mini_boards_rate_and_check_within_their_side() {
+        let mut board = generate_puzzle_sized(42, SudokuVariant::Classic, BoardSize::Six).unwrap();
+        // One blank left: a naked single, so Easy rather than the Hard the
+        // 9×9 rules gave for the padding cells.
+        board.puzzle = board.solution;
+        board.puzzle[3][4] = 0;
+        let difficulty = rate_sized(&board.puzzle, SudokuVariant::Classic, BoardSize::Six);
+        assert_eq!(difficulty, Difficulty::Easy);
+
+        let mut submitted = board.solution;
+        assert_eq!(check_submission(&board, &submitted), Ok(0));
+        submitted[3][4] = 7;
+        assert!(check_submission(&board, &submitted).is_err());
+    }
+
+    #[test]
+    fn test_blank_symmetry() {
+        for seed in [3u64, 77, 2024] {
+            let board = generate_puzzle(seed, SudokuVariant::Classic).unwrap();
+            // remove_cells pairs each blank with its mirror through the centre.
+            let blanks: Vec<(usize, usize)> = (0..9)
+                .flat_map(|r| (0..9).map(move |c| (r, c)))
+                .filter(|&(r, c)| board.puzzle[r][c] == 0)
+                .collect();
+            let paired = blanks.iter().filter(|&&(r, c)| board.puzzle[8 - r][8 - c] == 0).count();
+            assert!(paired * 10 >= blanks.len() * 9, "seed {}: {}/{}", seed, paired, blanks.len());
+            assert_eq!(blank_symmetry(&board.puzzle, BoardSize::Nine), Symmetry::Rotational);
+        }
+
+        let mut grid = [[5u8; 9]; 9];
+        grid[0][3] = 0;
+        grid[3][0] = 0;
+        assert_eq!(blank_symmetry(&grid, BoardSize::Nine), Symmetry::Diagonal);
+        grid[3][0] = 5;
+        assert_eq!(blank_symmetry(&grid, BoardSize::Nine), Symmetry::Asymmetric);
+    }
+
+    #[test]
+    fn test_is_solved_by_rejects_forged_completion() {
+        let board = generate_puzzle(31, SudokuVariant::Classic).unwrap();
+        let solved: Vec<Vec<u8>> = board.solution.iter().map(|r| r.to_vec()).collect();
+        assert!(is_solved_by(&board, &solved));
+
+        // The untouched puzzle, one wrong cell, and a malformed board all fail.
+        let puzzle: Vec<Vec<u8>> = board.puzzle.iter().map(|r| r.to_vec()).collect();
+        assert!(!is_solved_by(&board, &puzzle));
+        let (r, c) = (0..81).map(|i| (i / 9, i % 9)).find(|&(r, c)| board.puzzle[r][c] == 0).unwrap();
+        let mut forged = solved.clone();
+        forged[r][c] = forged[r][c] % 9 + 1;
+        assert!(!is_solved_by(&board, &forged));
+        assert!(!is_solved_by(&board, &solved[..8]));
+
+        // Another seed's solution doesn't count either.
+        let other = generate_puzzle(32, SudokuVariant::Classic).unwrap();
+        let other: Vec<Vec<u8>> = other.solution.iter().map(|r| r.to_vec()).collect();
+        assert!(!is_solved_by(&board, &other));
+    }
 }